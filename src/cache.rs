@@ -0,0 +1,212 @@
+//! Short-lived cache of filtered output for read-only commands.
+//!
+//! Agents often re-run the same `rtk git status` or `rtk gh pr list` within
+//! seconds. With `RTK_CACHE_TTL` set, commands listed in [`READ_ONLY_COMMANDS`]
+//! reuse the previous filtered output instead of invoking the tool again:
+//! - `RTK_CACHE_TTL=<secs>` sets the TTL; `0` or unset disables the cache
+//! - a non-numeric value such as `on` uses [`DEFAULT_TTL_SECS`]
+//!
+//! Entries are keyed by working directory, the full rtk command line and the
+//! settings that shape filtered output ([`OUTPUT_ENV_VARS`] and the config's
+//! `[defaults]`), so changing a limit never replays output made under another.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// TTL used when `RTK_CACHE_TTL` is set but not a number
+const DEFAULT_TTL_SECS: u64 = 5;
+
+/// Commands whose output depends only on repository/remote state, never on
+/// side effects of running them. Only these are looked up or stored.
+pub const READ_ONLY_COMMANDS: &[&str] = &[
+    "git status",
+    "git log",
+    "gh pr list",
+    "gh issue list",
+    "gh run list",
+];
+
+/// Env vars that change what a cached command prints
+const OUTPUT_ENV_VARS: &[&str] = &[
+    "RTK_LOG_LIMIT",
+    "RTK_ASCII",
+    "RTK_DIFF_MAX_LINES",
+    "RTK_MAX_OUTPUT_LINES",
+    "RTK_MAX_OUTPUT",
+    "NO_COLOR",
+];
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    stored_at: u64,
+    output: String,
+}
+
+/// TTL in seconds from `RTK_CACHE_TTL`; None when caching is off
+fn ttl_secs() -> Option<u64> {
+    let value = std::env::var("RTK_CACHE_TTL").ok()?;
+    parse_ttl(&value)
+}
+
+fn parse_ttl(value: &str) -> Option<u64> {
+    match value.trim().parse::<u64>() {
+        Ok(0) => None,
+        Ok(secs) => Some(secs),
+        Err(_) if value.trim().is_empty() => None,
+        Err(_) => Some(DEFAULT_TTL_SECS),
+    }
+}
+
+/// Stable 64-bit FNV-1a hash of the working directory, command line and
+/// output settings, hex encoded. Parts are NUL-separated so `["a b"]` and
+/// `["a", "b"]` differ.
+fn cache_key(cwd: &Path, argv: &[String], settings: &[String]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let cwd = cwd.to_string_lossy();
+    let parts = std::iter::once(cwd.as_ref())
+        .chain(argv.iter().map(|a| a.as_str()))
+        .chain(settings.iter().map(|s| s.as_str()));
+    for part in parts {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+fn is_fresh(stored_at: u64, now: u64, ttl: u64) -> bool {
+    now >= stored_at && now - stored_at < ttl
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// "NAME=value" for each set [`OUTPUT_ENV_VARS`] entry, then the effective
+/// config `[defaults]` (including any `--profile`)
+fn output_settings() -> Vec<String> {
+    let mut settings: Vec<String> = OUTPUT_ENV_VARS
+        .iter()
+        .filter_map(|name| {
+            let value = std::env::var(name).ok()?;
+            Some(format!("{}={}", name, value))
+        })
+        .collect();
+    settings.push(toml::to_string(crate::config::defaults()).unwrap_or_default());
+    settings
+}
+
+fn entry_path() -> Option<PathBuf> {
+    let cwd = std::env::current_dir().ok()?;
+    let argv: Vec<String> = std::env::args().collect();
+    let settings = output_settings();
+    let data_dir = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    Some(
+        data_dir
+            .join("rtk")
+            .join("cache")
+            .join(format!("{}.json", cache_key(&cwd, &argv, &settings))),
+    )
+}
+
+/// Filtered output of an identical `command` run within the TTL
+pub fn lookup(command: &str) -> Option<String> {
+    if !READ_ONLY_COMMANDS.contains(&command) {
+        return None;
+    }
+    let ttl = ttl_secs()?;
+    let path = entry_path()?;
+    let entry: CacheEntry = serde_json::from_str(&std::fs::read_to_string(&path).ok()?).ok()?;
+    if is_fresh(entry.stored_at, now_secs(), ttl) {
+        Some(entry.output)
+    } else {
+        let _ = std::fs::remove_file(&path);
+        None
+    }
+}
+
+/// Remember the filtered output of a successful `command` run
+pub fn store(command: &str, output: &str) {
+    if !READ_ONLY_COMMANDS.contains(&command) || ttl_secs().is_none() {
+        return;
+    }
+    let Some(path) = entry_path() else {
+        return;
+    };
+    let entry = CacheEntry {
+        stored_at: now_secs(),
+        output: output.to_string(),
+    };
+    // Best effort: a read-only data dir just means no cache hits
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    if let Ok(json) = serde_json::to_string(&entry) {
+        let _ = std::fs::write(&path, json);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_key_stable_and_distinct() {
+        let cwd = Path::new("/repo");
+        let argv = |a: &[&str]| a.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let key = cache_key(cwd, &argv(&["rtk", "git", "status"]), &[]);
+        assert_eq!(key.len(), 16);
+        assert_eq!(key, cache_key(cwd, &argv(&["rtk", "git", "status"]), &[]));
+        assert_ne!(
+            key,
+            cache_key(Path::new("/other"), &argv(&["rtk", "git", "status"]), &[])
+        );
+        assert_ne!(
+            key,
+            cache_key(cwd, &argv(&["rtk", "git", "status", "-s"]), &[])
+        );
+        assert_ne!(
+            cache_key(cwd, &argv(&["rtk", "git status"]), &[]),
+            cache_key(cwd, &argv(&["rtk", "git", "status"]), &[])
+        );
+    }
+
+    #[test]
+    fn test_cache_key_includes_output_settings() {
+        let cwd = Path::new("/repo");
+        let argv: Vec<String> = ["rtk", "git", "log"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let key = cache_key(cwd, &argv, &["RTK_LOG_LIMIT=10".to_string()]);
+        assert_ne!(
+            key,
+            cache_key(cwd, &argv, &["RTK_LOG_LIMIT=50".to_string()])
+        );
+        assert_ne!(key, cache_key(cwd, &argv, &["RTK_ASCII=1".to_string()]));
+        // Config [defaults] is part of the settings too
+        assert_ne!(
+            cache_key(cwd, &argv, &["log_limit = 20\n".to_string()]),
+            cache_key(cwd, &argv, &["log_limit = 30\n".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_ttl_expiry() {
+        assert!(is_fresh(100, 100, 5));
+        assert!(is_fresh(100, 104, 5));
+        assert!(!is_fresh(100, 105, 5));
+        // Clock went backwards: don't trust the entry
+        assert!(!is_fresh(100, 99, 5));
+
+        assert_eq!(parse_ttl("10"), Some(10));
+        assert_eq!(parse_ttl("on"), Some(DEFAULT_TTL_SECS));
+        assert_eq!(parse_ttl("0"), None);
+        assert_eq!(parse_ttl(""), None);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::tracking;
-use crate::utils::truncate;
+use crate::utils::{emit, truncate};
 use anyhow::{Context, Result};
 use std::collections::HashMap;
 use std::ffi::OsString;
@@ -50,7 +50,7 @@ where
     let raw = format!("{}\n{}", stdout, stderr);
 
     let filtered = filter_fn(&raw);
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track(
         &format!("cargo {} {}", subcommand, args.join(" ")),
@@ -9,13 +9,38 @@ use serde::Serialize;
 use std::collections::HashMap;
 
 use crate::ccusage::{self, CcusagePeriod, Granularity};
+use crate::config;
 use crate::tracking::{DayStats, MonthStats, Tracker, WeekStats};
-use crate::utils::{format_cpt, format_tokens, format_usd};
+use crate::utils::{format_cpt, format_tokens, format_usd, sparkline};
 
 // ── Constants ──
 
 const BILLION: f64 = 1e9;
 
+/// Days covered by the savings sparkline in the summary view
+const SPARKLINE_DAYS: usize = 14;
+
+/// Cells in the monthly savings-goal progress bar
+const GOAL_BAR_WIDTH: usize = 10;
+
+/// Savings as a share of the raw command output: `saved / input * 100`.
+///
+/// `input` is what the unfiltered command would have printed, so this is the
+/// same basis `rtk gain` reports. 0 when nothing was recorded.
+fn savings_pct(saved: usize, input: usize) -> f64 {
+    if input > 0 {
+        saved as f64 / input as f64 * 100.0
+    } else {
+        0.0
+    }
+}
+
+/// Dollars of estimated savings per dollar spent; `None` when nothing was
+/// spent or savings are unknown.
+fn savings_per_dollar(savings: Option<f64>, cost: f64) -> Option<f64> {
+    savings.filter(|_| cost > 0.0).map(|s| s / cost)
+}
+
 // API pricing ratios (verified Feb 2026, consistent across Claude models <=200K context)
 // Source: https://docs.anthropic.com/en/docs/about-claude/models
 const WEIGHT_OUTPUT: f64 = 5.0; // Output = 5x input
@@ -24,9 +49,20 @@ const WEIGHT_CACHE_READ: f64 = 0.1; // Cache read = 0.1x input
 
 // ── Types ──
 
+/// Which sources contributed to a period. Savings for a partial period are
+/// an estimate: a missing ccusage side means no spend-derived CPT, a missing
+/// rtk side means nothing was saved that we know of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Coverage {
+    Both,
+    CcusageOnly,
+    RtkOnly,
+}
+
 #[derive(Debug, Serialize)]
 pub struct PeriodEconomics {
     pub label: String,
+    pub coverage: Coverage,
     // ccusage metrics (Option for graceful degradation)
     pub cc_cost: Option<f64>,
     pub cc_total_tokens: Option<u64>,
@@ -54,6 +90,7 @@ impl PeriodEconomics {
     fn new(label: &str) -> Self {
         Self {
             label: label.to_string(),
+            coverage: Coverage::Both,
             cc_cost: None,
             cc_total_tokens: None,
             cc_active_tokens: None,
@@ -73,6 +110,24 @@ impl PeriodEconomics {
         }
     }
 
+    /// Derive `coverage` from which sources were merged in
+    fn set_coverage(&mut self) {
+        self.coverage = match (self.cc_cost.is_some(), self.rtk_commands.is_some()) {
+            (true, false) => Coverage::CcusageOnly,
+            (false, true) => Coverage::RtkOnly,
+            _ => Coverage::Both,
+        };
+    }
+
+    /// Table label, with `*` when the period's savings come from partial data
+    fn table_label(&self) -> String {
+        if self.coverage == Coverage::Both {
+            self.label.clone()
+        } else {
+            format!("{}*", self.label)
+        }
+    }
+
     fn set_ccusage(&mut self, metrics: &ccusage::CcusageMetrics) {
         self.cc_cost = Some(metrics.total_cost);
         self.cc_total_tokens = Some(metrics.total_tokens);
@@ -89,27 +144,23 @@ impl PeriodEconomics {
     }
 
     fn set_rtk_from_day(&mut self, stats: &DayStats) {
-        self.rtk_commands = Some(stats.commands);
-        self.rtk_saved_tokens = Some(stats.saved_tokens);
-        self.rtk_savings_pct = Some(stats.savings_pct);
+        self.set_rtk(stats.commands, stats.saved_tokens, stats.input_tokens);
     }
 
     fn set_rtk_from_week(&mut self, stats: &WeekStats) {
-        self.rtk_commands = Some(stats.commands);
-        self.rtk_saved_tokens = Some(stats.saved_tokens);
-        self.rtk_savings_pct = Some(stats.savings_pct);
+        self.set_rtk(stats.commands, stats.saved_tokens, stats.input_tokens);
     }
 
     fn set_rtk_from_month(&mut self, stats: &MonthStats) {
-        self.rtk_commands = Some(stats.commands);
-        self.rtk_saved_tokens = Some(stats.saved_tokens);
-        self.rtk_savings_pct = Some(if stats.input_tokens + stats.output_tokens > 0 {
-            stats.saved_tokens as f64
-                / (stats.saved_tokens + stats.input_tokens + stats.output_tokens) as f64
-                * 100.0
-        } else {
-            0.0
-        });
+        self.set_rtk(stats.commands, stats.saved_tokens, stats.input_tokens);
+    }
+
+    /// Recompute the pct from token totals instead of trusting each period's
+    /// stored value, so day/week/month rows share one basis
+    fn set_rtk(&mut self, commands: usize, saved: usize, input: usize) {
+        self.rtk_commands = Some(commands);
+        self.rtk_saved_tokens = Some(saved);
+        self.rtk_savings_pct = Some(savings_pct(saved, input));
     }
 
     fn compute_weighted_metrics(&mut self) {
@@ -138,7 +189,17 @@ impl PeriodEconomics {
         }
     }
 
-    fn compute_dual_metrics(&mut self) {
+    fn compute_dual_metrics(&mut self, cpt_override: Option<f64>) {
+        // Manual CPT fallback for periods without ccusage data
+        if let (None, Some(cpt), Some(saved)) = (self.cc_cost, cpt_override, self.rtk_saved_tokens)
+        {
+            self.blended_cpt = Some(cpt);
+            self.active_cpt = Some(cpt);
+            self.savings_blended = Some(saved as f64 * cpt);
+            self.savings_active = Some(saved as f64 * cpt);
+            return;
+        }
+
         if let (Some(cost), Some(saved)) = (self.cc_cost, self.rtk_saved_tokens) {
             // Blended CPT (cost / total_tokens including cache)
             if let Some(total) = self.cc_total_tokens {
@@ -177,30 +238,98 @@ struct Totals {
     active_cpt: Option<f64>,
     savings_blended: Option<f64>,
     savings_active: Option<f64>,
+    savings_per_dollar: Option<f64>,
 }
 
 // ── Public API ──
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     daily: bool,
     weekly: bool,
     monthly: bool,
     all: bool,
     format: &str,
+    cpt_override: Option<f64>,
+    goal: Option<f64>,
     verbose: u8,
 ) -> Result<()> {
     let tracker = Tracker::new().context("Failed to initialize tracking database")?;
+    let goal = goal
+        .or(config::defaults().savings_goal)
+        .filter(|g| *g > 0.0);
 
     match format {
-        "json" => export_json(&tracker, daily, weekly, monthly, all),
-        "csv" => export_csv(&tracker, daily, weekly, monthly, all),
-        _ => display_text(&tracker, daily, weekly, monthly, all, verbose),
+        "json" => export_json(&tracker, daily, weekly, monthly, all, cpt_override),
+        "csv" => export_csv(&tracker, daily, weekly, monthly, all, cpt_override),
+        "tsv" => export_tsv(&tracker, daily, weekly, monthly, all, cpt_override),
+        // Default: summary view
+        _ if !daily && !weekly && !monthly && !all => {
+            display_summary(&tracker, cpt_override, goal, verbose)
+        }
+        _ => display_text(&tracker, daily, weekly, monthly, all, cpt_override, verbose),
+    }
+}
+
+// ── ccusage Fetch ──
+
+type CcusageResult = Result<Option<Vec<CcusagePeriod>>>;
+
+/// ccusage data for the granularities a report needs (unrequested ones stay `None`)
+#[derive(Debug)]
+struct CcusageData {
+    daily: Option<Vec<CcusagePeriod>>,
+    weekly: Option<Vec<CcusagePeriod>>,
+    monthly: Option<Vec<CcusagePeriod>>,
+}
+
+impl CcusageData {
+    /// Each granularity is its own ccusage subprocess: run them concurrently
+    fn fetch(daily: bool, weekly: bool, monthly: bool) -> Result<Self> {
+        Self::fetch_with(daily, weekly, monthly, ccusage::fetch)
+    }
+
+    /// Spawn one thread per requested granularity and join them in a fixed
+    /// order, so results and errors don't depend on which fetch finishes first
+    fn fetch_with<F>(daily: bool, weekly: bool, monthly: bool, fetch: F) -> Result<Self>
+    where
+        F: Fn(Granularity) -> CcusageResult + Sync,
+    {
+        let fetch = &fetch;
+        std::thread::scope(|scope| {
+            let spawn = |wanted: bool, granularity: Granularity| {
+                wanted.then(|| scope.spawn(move || fetch(granularity)))
+            };
+            let handles = [
+                (spawn(daily, Granularity::Daily), "daily"),
+                (spawn(weekly, Granularity::Weekly), "weekly"),
+                (spawn(monthly, Granularity::Monthly), "monthly"),
+            ];
+
+            let mut results = handles.into_iter().map(|(handle, label)| match handle {
+                None => Ok(None),
+                Some(handle) => handle
+                    .join()
+                    .map_err(|_| anyhow::anyhow!("ccusage {} fetch panicked", label))?
+                    .with_context(|| format!("Failed to fetch ccusage {} data", label)),
+            });
+            let mut next = || results.next().unwrap_or(Ok(None));
+            Ok(Self {
+                daily: next()?,
+                weekly: next()?,
+                monthly: next()?,
+            })
+        })
     }
 }
 
 // ── Merge Logic ──
 
-fn merge_daily(cc: Option<Vec<CcusagePeriod>>, rtk: Vec<DayStats>) -> Vec<PeriodEconomics> {
+fn merge_daily(
+    cc: Option<Vec<CcusagePeriod>>,
+    rtk: Vec<DayStats>,
+    cpt_override: Option<f64>,
+) -> Vec<PeriodEconomics> {
     let mut map: HashMap<String, PeriodEconomics> = HashMap::new();
 
     // Insert ccusage data
@@ -223,14 +352,19 @@ fn merge_daily(cc: Option<Vec<CcusagePeriod>>, rtk: Vec<DayStats>) -> Vec<Period
     // Compute dual metrics and sort
     let mut result: Vec<_> = map.into_values().collect();
     for period in &mut result {
+        period.set_coverage();
         period.compute_weighted_metrics();
-        period.compute_dual_metrics();
+        period.compute_dual_metrics(cpt_override);
     }
     result.sort_by(|a, b| a.label.cmp(&b.label));
     result
 }
 
-fn merge_weekly(cc: Option<Vec<CcusagePeriod>>, rtk: Vec<WeekStats>) -> Vec<PeriodEconomics> {
+fn merge_weekly(
+    cc: Option<Vec<CcusagePeriod>>,
+    rtk: Vec<WeekStats>,
+    cpt_override: Option<f64>,
+) -> Vec<PeriodEconomics> {
     let mut map: HashMap<String, PeriodEconomics> = HashMap::new();
 
     // Insert ccusage data (key = ISO Monday "2026-01-20")
@@ -261,14 +395,19 @@ fn merge_weekly(cc: Option<Vec<CcusagePeriod>>, rtk: Vec<WeekStats>) -> Vec<Peri
 
     let mut result: Vec<_> = map.into_values().collect();
     for period in &mut result {
+        period.set_coverage();
         period.compute_weighted_metrics();
-        period.compute_dual_metrics();
+        period.compute_dual_metrics(cpt_override);
     }
     result.sort_by(|a, b| a.label.cmp(&b.label));
     result
 }
 
-fn merge_monthly(cc: Option<Vec<CcusagePeriod>>, rtk: Vec<MonthStats>) -> Vec<PeriodEconomics> {
+fn merge_monthly(
+    cc: Option<Vec<CcusagePeriod>>,
+    rtk: Vec<MonthStats>,
+    cpt_override: Option<f64>,
+) -> Vec<PeriodEconomics> {
     let mut map: HashMap<String, PeriodEconomics> = HashMap::new();
 
     // Insert ccusage data
@@ -290,8 +429,9 @@ fn merge_monthly(cc: Option<Vec<CcusagePeriod>>, rtk: Vec<MonthStats>) -> Vec<Pe
 
     let mut result: Vec<_> = map.into_values().collect();
     for period in &mut result {
+        period.set_coverage();
         period.compute_weighted_metrics();
-        period.compute_dual_metrics();
+        period.compute_dual_metrics(cpt_override);
     }
     result.sort_by(|a, b| a.label.cmp(&b.label));
     result
@@ -311,7 +451,7 @@ fn convert_saturday_to_monday(saturday: &str) -> Option<String> {
     Some(monday.format("%Y-%m-%d").to_string())
 }
 
-fn compute_totals(periods: &[PeriodEconomics]) -> Totals {
+fn compute_totals(periods: &[PeriodEconomics], cpt_override: Option<f64>) -> Totals {
     let mut totals = Totals {
         cc_cost: 0.0,
         cc_total_tokens: 0,
@@ -329,6 +469,7 @@ fn compute_totals(periods: &[PeriodEconomics]) -> Totals {
         active_cpt: None,
         savings_blended: None,
         savings_active: None,
+        savings_per_dollar: None,
     };
 
     let mut pct_sum = 0.0;
@@ -394,6 +535,17 @@ fn compute_totals(periods: &[PeriodEconomics]) -> Totals {
         totals.savings_active = Some(totals.rtk_saved_tokens as f64 * totals.active_cpt.unwrap());
     }
 
+    // Manual CPT fallback when no period has ccusage data
+    if let (None, Some(cpt)) = (totals.active_cpt, cpt_override) {
+        let savings = totals.rtk_saved_tokens as f64 * cpt;
+        totals.blended_cpt = Some(cpt);
+        totals.active_cpt = Some(cpt);
+        totals.savings_blended = Some(savings);
+        totals.savings_active = Some(savings);
+    }
+
+    totals.savings_per_dollar = savings_per_dollar(totals.savings_active, totals.cc_cost);
+
     totals
 }
 
@@ -405,41 +557,49 @@ fn display_text(
     weekly: bool,
     monthly: bool,
     all: bool,
+    cpt_override: Option<f64>,
     verbose: u8,
 ) -> Result<()> {
-    // Default: summary view
-    if !daily && !weekly && !monthly && !all {
-        display_summary(tracker, verbose)?;
-        return Ok(());
-    }
-
+    let cc = CcusageData::fetch(all || daily, all || weekly, all || monthly)?;
     if all || daily {
-        display_daily(tracker, verbose)?;
+        display_daily(tracker, cc.daily, cpt_override, verbose)?;
     }
     if all || weekly {
-        display_weekly(tracker, verbose)?;
+        display_weekly(tracker, cc.weekly, cpt_override, verbose)?;
     }
     if all || monthly {
-        display_monthly(tracker, verbose)?;
+        display_monthly(tracker, cc.monthly, cpt_override, verbose)?;
     }
 
     Ok(())
 }
 
-fn display_summary(tracker: &Tracker, verbose: u8) -> Result<()> {
-    let cc_monthly =
-        ccusage::fetch(Granularity::Monthly).context("Failed to fetch ccusage monthly data")?;
+fn display_summary(
+    tracker: &Tracker,
+    cpt_override: Option<f64>,
+    goal: Option<f64>,
+    verbose: u8,
+) -> Result<()> {
+    // Both ccusage calls up front and concurrently; daily only feeds the
+    // sparkline, so its failure just drops that line
+    let (cc_monthly, cc_daily) = std::thread::scope(|scope| {
+        let daily = scope.spawn(|| ccusage::fetch(Granularity::Daily));
+        let monthly = ccusage::fetch(Granularity::Monthly);
+        let daily = daily.join().ok().and_then(|r| r.ok()).flatten();
+        (monthly, daily)
+    });
+    let cc_monthly = cc_monthly.context("Failed to fetch ccusage monthly data")?;
     let rtk_monthly = tracker
         .get_by_month()
         .context("Failed to load monthly token savings from database")?;
-    let periods = merge_monthly(cc_monthly, rtk_monthly);
+    let periods = merge_monthly(cc_monthly, rtk_monthly, cpt_override);
 
     if periods.is_empty() {
         println!("No data available. Run some rtk commands to start tracking.");
         return Ok(());
     }
 
-    let totals = compute_totals(&periods);
+    let totals = compute_totals(&periods, cpt_override);
 
     println!("💰 Claude Code Economics");
     println!("════════════════════════════════════════════════════");
@@ -473,6 +633,21 @@ fn display_summary(tracker: &Tracker, verbose: u8) -> Result<()> {
         "  Tokens saved:                 {}",
         format_tokens(totals.rtk_saved_tokens)
     );
+    if let Some(trend) = daily_savings_sparkline(tracker, cc_daily, cpt_override)
+        .ok()
+        .flatten()
+    {
+        println!("  Daily savings ({}d):          {}", SPARKLINE_DAYS, trend);
+    }
+    match totals.savings_per_dollar {
+        Some(ratio) => println!("  Every $1 spent, rtk saved ${:.2} back", ratio),
+        None => println!("  Every $1 spent, rtk saved —"),
+    }
+    if let Some(goal) = goal {
+        let month = chrono::Local::now().format("%Y-%m").to_string();
+        let savings = month_savings(&periods, &month);
+        println!("  {}", format_goal_progress(savings, goal));
+    }
     println!();
 
     println!("  Estimated Savings:");
@@ -502,6 +677,17 @@ fn display_summary(tracker: &Tracker, verbose: u8) -> Result<()> {
     println!("  └─────────────────────────────────────────────────┘");
     println!();
 
+    let top = tracker
+        .top_commands(5)
+        .context("Failed to load per-command token savings")?;
+    if !top.is_empty() {
+        println!("  Top savers:");
+        for (cmd, saved) in &top {
+            println!("    {}: {} tokens saved", cmd, format_tokens(*saved));
+        }
+        println!();
+    }
+
     println!("  How it works:");
     println!("  RTK compresses CLI outputs before they enter Claude's context.");
     println!("  Savings derived using API price ratios (out=5x, cache_w=1.25x, cache_r=0.1x).");
@@ -541,13 +727,85 @@ fn display_summary(tracker: &Tracker, verbose: u8) -> Result<()> {
     Ok(())
 }
 
-fn display_daily(tracker: &Tracker, verbose: u8) -> Result<()> {
-    let cc_daily =
-        ccusage::fetch(Granularity::Daily).context("Failed to fetch ccusage daily data")?;
+/// `savings_active` of the monthly period labelled `month` ("2026-03"), 0 if absent
+fn month_savings(periods: &[PeriodEconomics], month: &str) -> f64 {
+    periods
+        .iter()
+        .find(|p| p.label == month)
+        .and_then(|p| p.savings_active)
+        .unwrap_or(0.0)
+}
+
+/// "Savings: $47.00 / $100.00 goal (47%) [█████░░░░░]", with
+/// "✓ goal exceeded" past 100%
+fn format_goal_progress(savings: f64, goal: f64) -> String {
+    let pct = savings / goal * 100.0;
+    let filled = ((pct.clamp(0.0, 100.0) / 100.0) * GOAL_BAR_WIDTH as f64).round() as usize;
+    let bar = format!(
+        "[{}{}]",
+        "█".repeat(filled),
+        "░".repeat(GOAL_BAR_WIDTH - filled)
+    );
+    let mut line = format!(
+        "Savings: {} / {} goal ({:.0}%) {}",
+        format_usd(savings),
+        format_usd(goal),
+        pct,
+        bar
+    );
+    if pct > 100.0 {
+        line.push_str(" ✓ goal exceeded");
+    }
+    line
+}
+
+/// Sparkline of daily `savings_active` over the last `SPARKLINE_DAYS` days
+fn daily_savings_sparkline(
+    tracker: &Tracker,
+    cc_daily: Option<Vec<CcusagePeriod>>,
+    cpt_override: Option<f64>,
+) -> Result<Option<String>> {
+    let rtk_daily = tracker
+        .get_all_days()
+        .context("Failed to load daily token savings from database")?;
+    let periods = merge_daily(cc_daily, rtk_daily, cpt_override);
+    let today = chrono::Local::now().date_naive();
+    Ok(recent_daily_savings(&periods, today).map(|values| sparkline(&values)))
+}
+
+/// `savings_active` for each of the `SPARKLINE_DAYS` calendar days ending
+/// `today`, oldest first; days without data count as zero. `None` when no day
+/// in the window has savings.
+fn recent_daily_savings(periods: &[PeriodEconomics], today: NaiveDate) -> Option<Vec<f64>> {
+    let by_day: HashMap<&str, Option<f64>> = periods
+        .iter()
+        .map(|p| (p.label.as_str(), p.savings_active))
+        .collect();
+    let window: Vec<Option<f64>> = (0..SPARKLINE_DAYS as i64)
+        .rev()
+        .map(|back| {
+            let day = (today - chrono::Duration::days(back))
+                .format("%Y-%m-%d")
+                .to_string();
+            by_day.get(day.as_str()).copied().flatten()
+        })
+        .collect();
+    if window.iter().all(|v| v.is_none()) {
+        return None;
+    }
+    Some(window.into_iter().map(|v| v.unwrap_or(0.0)).collect())
+}
+
+fn display_daily(
+    tracker: &Tracker,
+    cc_daily: Option<Vec<CcusagePeriod>>,
+    cpt_override: Option<f64>,
+    verbose: u8,
+) -> Result<()> {
     let rtk_daily = tracker
         .get_all_days()
         .context("Failed to load daily token savings from database")?;
-    let periods = merge_daily(cc_daily, rtk_daily);
+    let periods = merge_daily(cc_daily, rtk_daily, cpt_override);
 
     println!("📅 Daily Economics");
     println!("════════════════════════════════════════════════════");
@@ -555,13 +813,16 @@ fn display_daily(tracker: &Tracker, verbose: u8) -> Result<()> {
     Ok(())
 }
 
-fn display_weekly(tracker: &Tracker, verbose: u8) -> Result<()> {
-    let cc_weekly =
-        ccusage::fetch(Granularity::Weekly).context("Failed to fetch ccusage weekly data")?;
+fn display_weekly(
+    tracker: &Tracker,
+    cc_weekly: Option<Vec<CcusagePeriod>>,
+    cpt_override: Option<f64>,
+    verbose: u8,
+) -> Result<()> {
     let rtk_weekly = tracker
         .get_by_week()
         .context("Failed to load weekly token savings from database")?;
-    let periods = merge_weekly(cc_weekly, rtk_weekly);
+    let periods = merge_weekly(cc_weekly, rtk_weekly, cpt_override);
 
     println!("📅 Weekly Economics");
     println!("════════════════════════════════════════════════════");
@@ -569,13 +830,16 @@ fn display_weekly(tracker: &Tracker, verbose: u8) -> Result<()> {
     Ok(())
 }
 
-fn display_monthly(tracker: &Tracker, verbose: u8) -> Result<()> {
-    let cc_monthly =
-        ccusage::fetch(Granularity::Monthly).context("Failed to fetch ccusage monthly data")?;
+fn display_monthly(
+    tracker: &Tracker,
+    cc_monthly: Option<Vec<CcusagePeriod>>,
+    cpt_override: Option<f64>,
+    verbose: u8,
+) -> Result<()> {
     let rtk_monthly = tracker
         .get_by_month()
         .context("Failed to load monthly token savings from database")?;
-    let periods = merge_monthly(cc_monthly, rtk_monthly);
+    let periods = merge_monthly(cc_monthly, rtk_monthly, cpt_override);
 
     println!("📅 Monthly Economics");
     println!("════════════════════════════════════════════════════");
@@ -583,21 +847,41 @@ fn display_monthly(tracker: &Tracker, verbose: u8) -> Result<()> {
     Ok(())
 }
 
+/// Trend of `savings_active` vs the previous period: ↑/↓/→, blank when unknown
+fn trend_arrows(periods: &[PeriodEconomics]) -> Vec<&'static str> {
+    let mut prev: Option<f64> = None;
+    periods
+        .iter()
+        .map(|p| {
+            let arrow = match (p.savings_active, prev) {
+                (None, _) => "",
+                (Some(_), None) => "→",
+                (Some(cur), Some(before)) if (cur - before).abs() < 0.005 => "→",
+                (Some(cur), Some(before)) if cur > before => "↑",
+                (Some(_), Some(_)) => "↓",
+            };
+            prev = p.savings_active;
+            arrow
+        })
+        .collect()
+}
+
 fn print_period_table(periods: &[PeriodEconomics], verbose: u8) {
     println!();
+    let trends = trend_arrows(periods);
 
     if verbose > 0 {
         // Verbose: include legacy metrics
         println!(
-            "{:<12} {:>10} {:>10} {:>10} {:>10} {:>12} {:>12}",
-            "Period", "Spent", "Saved", "Savings", "Active$", "Blended$", "RTK Cmds"
+            "{:<12} {:>10} {:>10} {:>10} {:>10} {:>12} {:>12} {:>6}",
+            "Period", "Spent", "Saved", "Savings", "Active$", "Blended$", "RTK Cmds", "Trend"
         );
         println!(
-            "{:-<12} {:-<10} {:-<10} {:-<10} {:-<10} {:-<12} {:-<12}",
-            "", "", "", "", "", "", ""
+            "{:-<12} {:-<10} {:-<10} {:-<10} {:-<10} {:-<12} {:-<12} {:-<6}",
+            "", "", "", "", "", "", "", ""
         );
 
-        for p in periods {
+        for (p, trend) in periods.iter().zip(&trends) {
             let spent = p.cc_cost.map(format_usd).unwrap_or_else(|| "—".to_string());
             let saved = p
                 .rtk_saved_tokens
@@ -621,22 +905,29 @@ fn print_period_table(periods: &[PeriodEconomics], verbose: u8) {
                 .unwrap_or_else(|| "—".to_string());
 
             println!(
-                "{:<12} {:>10} {:>10} {:>10} {:>10} {:>12} {:>12}",
-                p.label, spent, saved, weighted, active, blended, cmds
+                "{:<12} {:>10} {:>10} {:>10} {:>10} {:>12} {:>12} {:>6}",
+                p.table_label(),
+                spent,
+                saved,
+                weighted,
+                active,
+                blended,
+                cmds,
+                trend
             );
         }
     } else {
         // Default: single Savings column
         println!(
-            "{:<12} {:>10} {:>10} {:>10} {:>12}",
-            "Period", "Spent", "Saved", "Savings", "RTK Cmds"
+            "{:<12} {:>10} {:>10} {:>10} {:>12} {:>6}",
+            "Period", "Spent", "Saved", "Savings", "RTK Cmds", "Trend"
         );
         println!(
-            "{:-<12} {:-<10} {:-<10} {:-<10} {:-<12}",
-            "", "", "", "", ""
+            "{:-<12} {:-<10} {:-<10} {:-<10} {:-<12} {:-<6}",
+            "", "", "", "", "", ""
         );
 
-        for p in periods {
+        for (p, trend) in periods.iter().zip(&trends) {
             let spent = p.cc_cost.map(format_usd).unwrap_or_else(|| "—".to_string());
             let saved = p
                 .rtk_saved_tokens
@@ -652,11 +943,19 @@ fn print_period_table(periods: &[PeriodEconomics], verbose: u8) {
                 .unwrap_or_else(|| "—".to_string());
 
             println!(
-                "{:<12} {:>10} {:>10} {:>10} {:>12}",
-                p.label, spent, saved, weighted, cmds
+                "{:<12} {:>10} {:>10} {:>10} {:>12} {:>6}",
+                p.table_label(),
+                spent,
+                saved,
+                weighted,
+                cmds,
+                trend
             );
         }
     }
+    if periods.iter().any(|p| p.coverage != Coverage::Both) {
+        println!("* estimate from partial data (ccusage or rtk missing for the period)");
+    }
     println!();
 }
 
@@ -668,6 +967,7 @@ fn export_json(
     weekly: bool,
     monthly: bool,
     all: bool,
+    cpt_override: Option<f64>,
 ) -> Result<()> {
     #[derive(Serialize)]
     struct Export {
@@ -684,32 +984,29 @@ fn export_json(
         totals: None,
     };
 
+    let cc = CcusageData::fetch(all || daily, all || weekly, all || monthly)
+        .context("Failed to fetch ccusage data for JSON export")?;
+
     if all || daily {
-        let cc = ccusage::fetch(Granularity::Daily)
-            .context("Failed to fetch ccusage daily data for JSON export")?;
         let rtk = tracker
             .get_all_days()
             .context("Failed to load daily token savings for JSON export")?;
-        export.daily = Some(merge_daily(cc, rtk));
+        export.daily = Some(merge_daily(cc.daily, rtk, cpt_override));
     }
 
     if all || weekly {
-        let cc = ccusage::fetch(Granularity::Weekly)
-            .context("Failed to fetch ccusage weekly data for export")?;
         let rtk = tracker
             .get_by_week()
             .context("Failed to load weekly token savings for export")?;
-        export.weekly = Some(merge_weekly(cc, rtk));
+        export.weekly = Some(merge_weekly(cc.weekly, rtk, cpt_override));
     }
 
     if all || monthly {
-        let cc = ccusage::fetch(Granularity::Monthly)
-            .context("Failed to fetch ccusage monthly data for export")?;
         let rtk = tracker
             .get_by_month()
             .context("Failed to load monthly token savings for export")?;
-        let periods = merge_monthly(cc, rtk);
-        export.totals = Some(compute_totals(&periods));
+        let periods = merge_monthly(cc.monthly, rtk, cpt_override);
+        export.totals = Some(compute_totals(&periods, cpt_override));
         export.monthly = Some(periods);
     }
 
@@ -727,41 +1024,39 @@ fn export_csv(
     weekly: bool,
     monthly: bool,
     all: bool,
+    cpt_override: Option<f64>,
 ) -> Result<()> {
     // Header (new columns: input_tokens, output_tokens, cache_create, cache_read, weighted_savings)
-    println!("period,spent,input_tokens,output_tokens,cache_create,cache_read,active_tokens,total_tokens,saved_tokens,weighted_savings,active_savings,blended_savings,rtk_commands");
+    println!("period,spent,input_tokens,output_tokens,cache_create,cache_read,active_tokens,total_tokens,saved_tokens,weighted_savings,active_savings,blended_savings,rtk_commands,savings_per_dollar");
+
+    let cc = CcusageData::fetch(all || daily, all || weekly, all || monthly)
+        .context("Failed to fetch ccusage data for CSV export")?;
 
     if all || daily {
-        let cc = ccusage::fetch(Granularity::Daily)
-            .context("Failed to fetch ccusage daily data for JSON export")?;
         let rtk = tracker
             .get_all_days()
-            .context("Failed to load daily token savings for JSON export")?;
-        let periods = merge_daily(cc, rtk);
+            .context("Failed to load daily token savings for CSV export")?;
+        let periods = merge_daily(cc.daily, rtk, cpt_override);
         for p in periods {
             print_csv_row(&p);
         }
     }
 
     if all || weekly {
-        let cc = ccusage::fetch(Granularity::Weekly)
-            .context("Failed to fetch ccusage weekly data for export")?;
         let rtk = tracker
             .get_by_week()
             .context("Failed to load weekly token savings for export")?;
-        let periods = merge_weekly(cc, rtk);
+        let periods = merge_weekly(cc.weekly, rtk, cpt_override);
         for p in periods {
             print_csv_row(&p);
         }
     }
 
     if all || monthly {
-        let cc = ccusage::fetch(Granularity::Monthly)
-            .context("Failed to fetch ccusage monthly data for export")?;
         let rtk = tracker
             .get_by_month()
             .context("Failed to load monthly token savings for export")?;
-        let periods = merge_monthly(cc, rtk);
+        let periods = merge_monthly(cc.monthly, rtk, cpt_override);
         for p in periods {
             print_csv_row(&p);
         }
@@ -770,7 +1065,94 @@ fn export_csv(
     Ok(())
 }
 
+/// Same columns as CSV plus a trailing granularity column (`daily`,
+/// `weekly`, `monthly`, so `--all` rows can be told apart), tab-separated,
+/// no header, trailing `TOTAL` row
+fn export_tsv(
+    tracker: &Tracker,
+    daily: bool,
+    weekly: bool,
+    monthly: bool,
+    all: bool,
+    cpt_override: Option<f64>,
+) -> Result<()> {
+    let mut groups: Vec<(&str, Vec<PeriodEconomics>)> = Vec::new();
+
+    let cc = CcusageData::fetch(all || daily, all || weekly, all || monthly)
+        .context("Failed to fetch ccusage data for TSV export")?;
+
+    if all || daily {
+        let rtk = tracker
+            .get_all_days()
+            .context("Failed to load daily token savings for export")?;
+        groups.push(("daily", merge_daily(cc.daily, rtk, cpt_override)));
+    }
+
+    if all || weekly {
+        let rtk = tracker
+            .get_by_week()
+            .context("Failed to load weekly token savings for export")?;
+        groups.push(("weekly", merge_weekly(cc.weekly, rtk, cpt_override)));
+    }
+
+    if all || monthly {
+        let rtk = tracker
+            .get_by_month()
+            .context("Failed to load monthly token savings for export")?;
+        groups.push(("monthly", merge_monthly(cc.monthly, rtk, cpt_override)));
+    }
+
+    for (granularity, periods) in &groups {
+        for p in periods {
+            println!("{}", tsv_row(p, granularity));
+        }
+    }
+    // Every granularity covers the same history: total over the first one only
+    if let Some((granularity, periods)) = groups.first() {
+        println!("{}", tsv_total_row(periods, granularity));
+    }
+
+    Ok(())
+}
+
+fn tsv_row(p: &PeriodEconomics, granularity: &str) -> String {
+    let mut fields = csv_fields(p);
+    fields.push(granularity.to_string());
+    fields.join("\t")
+}
+
+fn tsv_total_row(periods: &[PeriodEconomics], granularity: &str) -> String {
+    let mut total = PeriodEconomics::new("TOTAL");
+    for p in periods {
+        total.cc_cost = sum_opt(total.cc_cost, p.cc_cost);
+        total.cc_input_tokens = sum_opt(total.cc_input_tokens, p.cc_input_tokens);
+        total.cc_output_tokens = sum_opt(total.cc_output_tokens, p.cc_output_tokens);
+        total.cc_cache_create_tokens =
+            sum_opt(total.cc_cache_create_tokens, p.cc_cache_create_tokens);
+        total.cc_cache_read_tokens = sum_opt(total.cc_cache_read_tokens, p.cc_cache_read_tokens);
+        total.cc_active_tokens = sum_opt(total.cc_active_tokens, p.cc_active_tokens);
+        total.cc_total_tokens = sum_opt(total.cc_total_tokens, p.cc_total_tokens);
+        total.rtk_saved_tokens = sum_opt(total.rtk_saved_tokens, p.rtk_saved_tokens);
+        total.savings_weighted = sum_opt(total.savings_weighted, p.savings_weighted);
+        total.savings_active = sum_opt(total.savings_active, p.savings_active);
+        total.savings_blended = sum_opt(total.savings_blended, p.savings_blended);
+        total.rtk_commands = sum_opt(total.rtk_commands, p.rtk_commands);
+    }
+    tsv_row(&total, granularity)
+}
+
+fn sum_opt<T: std::ops::Add<Output = T>>(acc: Option<T>, value: Option<T>) -> Option<T> {
+    match (acc, value) {
+        (Some(a), Some(v)) => Some(a + v),
+        (a, v) => a.or(v),
+    }
+}
+
 fn print_csv_row(p: &PeriodEconomics) {
+    println!("{}", csv_fields(p).join(","));
+}
+
+fn csv_fields(p: &PeriodEconomics) -> Vec<String> {
     let spent = p.cc_cost.map(|c| format!("{:.4}", c)).unwrap_or_default();
     let input_tokens = p.cc_input_tokens.map(|t| t.to_string()).unwrap_or_default();
     let output_tokens = p
@@ -807,10 +1189,12 @@ fn print_csv_row(p: &PeriodEconomics) {
         .map(|s| format!("{:.4}", s))
         .unwrap_or_default();
     let cmds = p.rtk_commands.map(|c| c.to_string()).unwrap_or_default();
+    let per_dollar = savings_per_dollar(p.savings_active, p.cc_cost.unwrap_or(0.0))
+        .map(|r| format!("{:.4}", r))
+        .unwrap_or_default();
 
-    println!(
-        "{},{},{},{},{},{},{},{},{},{},{},{},{}",
-        p.label,
+    vec![
+        p.label.clone(),
         spent,
         input_tokens,
         output_tokens,
@@ -822,14 +1206,63 @@ fn print_csv_row(p: &PeriodEconomics) {
         weighted_savings,
         active_savings,
         blended_savings,
-        cmds
-    );
+        cmds,
+        per_dollar,
+    ]
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn period(granularity: Granularity) -> CcusageResult {
+        Ok(Some(vec![CcusagePeriod {
+            key: format!("{:?}", granularity),
+            metrics: ccusage::CcusageMetrics {
+                input_tokens: 0,
+                output_tokens: 0,
+                cache_creation_tokens: 0,
+                cache_read_tokens: 0,
+                total_tokens: 0,
+                total_cost: 0.0,
+            },
+        }]))
+    }
+
+    #[test]
+    fn test_ccusage_fetches_run_concurrently() {
+        // Every fetch waits for the other two: run one after another this
+        // would never return
+        let barrier = std::sync::Barrier::new(3);
+        let fetch = |granularity: Granularity| -> CcusageResult {
+            barrier.wait();
+            period(granularity)
+        };
+
+        let data = CcusageData::fetch_with(true, true, true, fetch).unwrap();
+        // Each result lands in its own slot regardless of finish order
+        assert_eq!(data.daily.unwrap()[0].key, "Daily");
+        assert_eq!(data.weekly.unwrap()[0].key, "Weekly");
+        assert_eq!(data.monthly.unwrap()[0].key, "Monthly");
+
+        // Unrequested granularities are never fetched
+        let data = CcusageData::fetch_with(false, true, false, period).unwrap();
+        assert!(data.daily.is_none() && data.monthly.is_none());
+        assert!(data.weekly.is_some());
+    }
+
+    #[test]
+    fn test_ccusage_fetch_error_names_granularity() {
+        let fetch = |granularity: Granularity| -> CcusageResult {
+            match granularity {
+                Granularity::Weekly => Err(anyhow::anyhow!("boom")),
+                _ => Ok(None),
+            }
+        };
+        let err = CcusageData::fetch_with(true, true, true, fetch).unwrap_err();
+        assert_eq!(err.to_string(), "Failed to fetch ccusage weekly data");
+    }
+
     #[test]
     fn test_convert_saturday_to_monday() {
         // Saturday Jan 18 -> Monday Jan 20
@@ -850,6 +1283,54 @@ mod tests {
         assert!(p.rtk_commands.is_none());
     }
 
+    #[test]
+    fn test_set_rtk_same_pct_for_every_period() {
+        // Stored pcts deliberately disagree: the setters must recompute
+        let day = DayStats {
+            date: "2026-01-05".to_string(),
+            commands: 10,
+            input_tokens: 4000,
+            output_tokens: 1000,
+            saved_tokens: 3000,
+            savings_pct: 12.0,
+            total_time_ms: 0,
+            avg_time_ms: 0,
+        };
+        let week = WeekStats {
+            week_start: "2026-01-05".to_string(),
+            week_end: "2026-01-11".to_string(),
+            commands: 10,
+            input_tokens: 4000,
+            output_tokens: 1000,
+            saved_tokens: 3000,
+            savings_pct: 34.0,
+            total_time_ms: 0,
+            avg_time_ms: 0,
+        };
+        let month = MonthStats {
+            month: "2026-01".to_string(),
+            commands: 10,
+            input_tokens: 4000,
+            output_tokens: 1000,
+            saved_tokens: 3000,
+            savings_pct: 56.0,
+            total_time_ms: 0,
+            avg_time_ms: 0,
+        };
+
+        let mut d = PeriodEconomics::new("d");
+        d.set_rtk_from_day(&day);
+        let mut w = PeriodEconomics::new("w");
+        w.set_rtk_from_week(&week);
+        let mut m = PeriodEconomics::new("m");
+        m.set_rtk_from_month(&month);
+
+        assert_eq!(d.rtk_savings_pct, Some(75.0));
+        assert_eq!(w.rtk_savings_pct, Some(75.0));
+        assert_eq!(m.rtk_savings_pct, Some(75.0));
+        assert_eq!(savings_pct(0, 0), 0.0);
+    }
+
     #[test]
     fn test_compute_dual_metrics_with_data() {
         let mut p = PeriodEconomics {
@@ -861,7 +1342,7 @@ mod tests {
             ..PeriodEconomics::new("2026-01")
         };
 
-        p.compute_dual_metrics();
+        p.compute_dual_metrics(None);
 
         assert!(p.blended_cpt.is_some());
         assert_eq!(p.blended_cpt.unwrap(), 100.0 / 1_000_000.0);
@@ -884,7 +1365,7 @@ mod tests {
             ..PeriodEconomics::new("2026-01")
         };
 
-        p.compute_dual_metrics();
+        p.compute_dual_metrics(None);
 
         assert!(p.blended_cpt.is_none());
         assert!(p.active_cpt.is_none());
@@ -900,12 +1381,65 @@ mod tests {
             ..PeriodEconomics::new("2026-01")
         };
 
-        p.compute_dual_metrics();
+        p.compute_dual_metrics(None);
 
         assert!(p.blended_cpt.is_none());
         assert!(p.active_cpt.is_none());
     }
 
+    #[test]
+    fn test_compute_dual_metrics_cpt_override_without_ccusage() {
+        let mut p = PeriodEconomics {
+            label: "2026-01".to_string(),
+            rtk_saved_tokens: Some(5_000),
+            ..PeriodEconomics::new("2026-01")
+        };
+
+        p.compute_dual_metrics(Some(0.00001));
+
+        assert_eq!(p.active_cpt, Some(0.00001));
+        assert_eq!(p.blended_cpt, Some(0.00001));
+        assert!((p.savings_active.unwrap() - 0.05).abs() < 1e-9);
+        assert!((p.savings_blended.unwrap() - 0.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compute_dual_metrics_cpt_override_ignored_with_ccusage() {
+        let mut p = PeriodEconomics {
+            label: "2026-01".to_string(),
+            cc_cost: Some(100.0),
+            cc_total_tokens: Some(1_000_000),
+            cc_active_tokens: Some(10_000),
+            rtk_saved_tokens: Some(5_000),
+            ..PeriodEconomics::new("2026-01")
+        };
+
+        p.compute_dual_metrics(Some(0.5));
+
+        assert_eq!(p.blended_cpt, Some(100.0 / 1_000_000.0));
+        assert_eq!(p.active_cpt, Some(100.0 / 10_000.0));
+    }
+
+    #[test]
+    fn test_merge_monthly_cpt_override_only_rtk() {
+        let rtk = vec![MonthStats {
+            month: "2026-01".to_string(),
+            commands: 10,
+            input_tokens: 800,
+            output_tokens: 400,
+            saved_tokens: 1_000,
+            savings_pct: 50.0,
+            total_time_ms: 0,
+            avg_time_ms: 0,
+        }];
+
+        let merged = merge_monthly(None, rtk, Some(0.001));
+        assert!((merged[0].savings_active.unwrap() - 1.0).abs() < 1e-9);
+
+        let totals = compute_totals(&merged, Some(0.001));
+        assert!((totals.savings_blended.unwrap() - 1.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_merge_monthly_both_present() {
         let cc = vec![CcusagePeriod {
@@ -931,11 +1465,13 @@ mod tests {
             avg_time_ms: 0,
         }];
 
-        let merged = merge_monthly(Some(cc), rtk);
+        let merged = merge_monthly(Some(cc), rtk, None);
         assert_eq!(merged.len(), 1);
         assert_eq!(merged[0].label, "2026-01");
         assert_eq!(merged[0].cc_cost, Some(12.34));
         assert_eq!(merged[0].rtk_commands, Some(10));
+        assert_eq!(merged[0].coverage, Coverage::Both);
+        assert_eq!(merged[0].table_label(), "2026-01");
     }
 
     #[test]
@@ -952,10 +1488,12 @@ mod tests {
             },
         }];
 
-        let merged = merge_monthly(Some(cc), vec![]);
+        let merged = merge_monthly(Some(cc), vec![], None);
         assert_eq!(merged.len(), 1);
         assert_eq!(merged[0].cc_cost, Some(12.34));
         assert!(merged[0].rtk_commands.is_none());
+        assert_eq!(merged[0].coverage, Coverage::CcusageOnly);
+        assert_eq!(merged[0].table_label(), "2026-01*");
     }
 
     #[test]
@@ -971,10 +1509,11 @@ mod tests {
             avg_time_ms: 0,
         }];
 
-        let merged = merge_monthly(None, rtk);
+        let merged = merge_monthly(None, rtk, None);
         assert_eq!(merged.len(), 1);
         assert!(merged[0].cc_cost.is_none());
         assert_eq!(merged[0].rtk_commands, Some(10));
+        assert_eq!(merged[0].coverage, Coverage::RtkOnly);
     }
 
     #[test]
@@ -1002,7 +1541,7 @@ mod tests {
             },
         ];
 
-        let merged = merge_monthly(None, rtk);
+        let merged = merge_monthly(None, rtk, None);
         assert_eq!(merged.len(), 2);
         assert_eq!(merged[0].label, "2026-01");
         assert_eq!(merged[1].label, "2026-03");
@@ -1101,6 +1640,7 @@ mod tests {
         let periods = vec![
             PeriodEconomics {
                 label: "2026-01".to_string(),
+                coverage: Coverage::Both,
                 cc_cost: Some(100.0),
                 cc_total_tokens: Some(1_000_000),
                 cc_active_tokens: Some(10_000),
@@ -1120,6 +1660,7 @@ mod tests {
             },
             PeriodEconomics {
                 label: "2026-02".to_string(),
+                coverage: Coverage::Both,
                 cc_cost: Some(200.0),
                 cc_total_tokens: Some(2_000_000),
                 cc_active_tokens: Some(20_000),
@@ -1139,7 +1680,7 @@ mod tests {
             },
         ];
 
-        let totals = compute_totals(&periods);
+        let totals = compute_totals(&periods, None);
         assert_eq!(totals.cc_cost, 300.0);
         assert_eq!(totals.cc_total_tokens, 3_000_000);
         assert_eq!(totals.cc_active_tokens, 30_000);
@@ -1153,5 +1694,122 @@ mod tests {
         assert!(totals.savings_weighted.is_some());
         assert!(totals.blended_cpt.is_some());
         assert!(totals.active_cpt.is_some());
+        // 5000 saved * ($300 / 30k active tokens) = $50 on $300 spent
+        assert!((totals.savings_per_dollar.unwrap() - 50.0 / 300.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_savings_per_dollar_zero_cost_is_none() {
+        let periods = vec![PeriodEconomics {
+            cc_cost: Some(0.0),
+            rtk_saved_tokens: Some(1000),
+            ..PeriodEconomics::new("2026-01")
+        }];
+        let totals = compute_totals(&periods, Some(0.001));
+        assert!(totals.savings_active.is_some());
+        assert_eq!(totals.savings_per_dollar, None);
+        assert_eq!(savings_per_dollar(Some(2.0), 0.0), None);
+        assert_eq!(savings_per_dollar(Some(2.0), 4.0), Some(0.5));
+    }
+
+    #[test]
+    fn test_format_goal_progress() {
+        assert_eq!(
+            format_goal_progress(0.0, 100.0),
+            "Savings: $0.0000 / $100.00 goal (0%) [░░░░░░░░░░]"
+        );
+        assert_eq!(
+            format_goal_progress(50.0, 100.0),
+            "Savings: $50.00 / $100.00 goal (50%) [█████░░░░░]"
+        );
+        assert_eq!(
+            format_goal_progress(120.0, 100.0),
+            "Savings: $120.00 / $100.00 goal (120%) [██████████] ✓ goal exceeded"
+        );
+    }
+
+    #[test]
+    fn test_trend_arrows() {
+        let period = |label: &str, savings: Option<f64>| PeriodEconomics {
+            savings_active: savings,
+            ..PeriodEconomics::new(label)
+        };
+        let periods = vec![
+            period("2026-01", Some(10.0)),
+            period("2026-02", Some(25.0)),
+            period("2026-03", Some(5.0)),
+        ];
+        assert_eq!(trend_arrows(&periods), vec!["→", "↑", "↓"]);
+
+        let periods = vec![
+            period("2026-01", Some(10.0)),
+            period("2026-02", None),
+            period("2026-03", Some(10.0)),
+        ];
+        assert_eq!(trend_arrows(&periods), vec!["→", "", "→"]);
+    }
+
+    #[test]
+    fn test_recent_daily_savings_uses_calendar_days() {
+        let period = |label: &str, savings: Option<f64>| PeriodEconomics {
+            savings_active: savings,
+            ..PeriodEconomics::new(label)
+        };
+        let today = NaiveDate::from_ymd_opt(2026, 3, 20).unwrap();
+        // Sparse history: an old entry outside the window, gaps inside it
+        let periods = vec![
+            period("2026-02-01", Some(9.0)),
+            period("2026-03-07", Some(1.0)),
+            period("2026-03-10", Some(2.0)),
+            period("2026-03-20", Some(3.0)),
+        ];
+        let values = recent_daily_savings(&periods, today).unwrap();
+        assert_eq!(values.len(), SPARKLINE_DAYS);
+        assert_eq!(values[0], 1.0); // 2026-03-07, 13 days back
+        assert_eq!(values[3], 2.0);
+        assert_eq!(values[SPARKLINE_DAYS - 1], 3.0);
+        assert_eq!(values.iter().sum::<f64>(), 6.0);
+
+        // Nothing recent: no sparkline at all
+        let stale = vec![period("2026-02-01", Some(9.0))];
+        assert!(recent_daily_savings(&stale, today).is_none());
+    }
+
+    #[test]
+    fn test_tsv_total_row() {
+        let periods = vec![
+            PeriodEconomics {
+                cc_cost: Some(1.5),
+                rtk_saved_tokens: Some(1000),
+                rtk_commands: Some(4),
+                ..PeriodEconomics::new("2026-01-01")
+            },
+            PeriodEconomics {
+                cc_cost: Some(2.5),
+                rtk_saved_tokens: Some(500),
+                rtk_commands: Some(2),
+                ..PeriodEconomics::new("2026-01-02")
+            },
+        ];
+        // The CSV columns, then the granularity
+        let header_cols = 14;
+        for p in &periods {
+            let row = tsv_row(p, "daily");
+            let cols: Vec<&str> = row.split('\t').collect();
+            assert_eq!(cols.len(), header_cols + 1);
+            assert_eq!(cols[..header_cols].join(","), csv_fields(p).join(","));
+            assert_eq!(cols[header_cols], "daily");
+        }
+
+        let total = tsv_total_row(&periods, "daily");
+        let cols: Vec<&str> = total.split('\t').collect();
+        assert_eq!(cols.len(), header_cols + 1);
+        assert_eq!(cols[header_cols], "daily");
+        assert_eq!(cols[0], "TOTAL");
+        assert_eq!(cols[1], "4.0000");
+        assert_eq!(cols[8], "1500");
+        assert_eq!(cols[12], "6");
+        // Unknown metrics stay empty rather than 0
+        assert_eq!(cols[2], "");
     }
 }
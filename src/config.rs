@@ -1,6 +1,8 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Config {
@@ -10,6 +12,11 @@ pub struct Config {
     pub display: DisplayConfig,
     #[serde(default)]
     pub filters: FilterConfig,
+    #[serde(default)]
+    pub defaults: DefaultsConfig,
+    /// Named `[profiles.<name>]` overlays on `[defaults]`, picked with `--profile`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, DefaultsConfig>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -51,6 +58,10 @@ impl Default for DisplayConfig {
 pub struct FilterConfig {
     pub ignore_dirs: Vec<String>,
     pub ignore_files: Vec<String>,
+    /// Extra globs (on top of the built-in lockfile/minified/snapshot list)
+    /// whose diffs are reduced to a +/- total
+    #[serde(default)]
+    pub noisy_diff_paths: Vec<String>,
 }
 
 impl Default for FilterConfig {
@@ -65,10 +76,108 @@ impl Default for FilterConfig {
                 "vendor".into(),
             ],
             ignore_files: vec!["*.lock".into(), "*.min.js".into(), "*.min.css".into()],
+            noisy_diff_paths: Vec::new(),
+        }
+    }
+}
+
+/// `[defaults]`: replaces rtk's built-in limits. Flags and env vars still win;
+/// unset keys keep the built-in value.
+#[derive(Debug, Serialize, Deserialize, Default, Clone, PartialEq)]
+pub struct DefaultsConfig {
+    /// Compacted diff/show line budget (`--max-lines`, built-in 100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_lines: Option<usize>,
+    /// Commits shown by `git log` (`-N` / `RTK_LOG_LIMIT`, built-in 10)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_limit: Option<usize>,
+    /// Rows shown by `gh pr|issue|run list` (`--limit`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub list_limit: Option<usize>,
+    /// Plain ASCII glyphs (`--ascii` / `RTK_ASCII`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ascii: Option<bool>,
+    /// Monthly savings goal in USD for `cc-economics` (`--goal`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub savings_goal: Option<f64>,
+    /// Files listed per `git status` section (`--max-files`, built-in 5/5/3)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_files: Option<usize>,
+    /// Global output line cap (`--max-output` / `RTK_MAX_OUTPUT_LINES`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_lines: Option<usize>,
+    /// Global output byte guard (`RTK_MAX_OUTPUT`, built-in 50 KB, 0 = off)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_bytes: Option<usize>,
+}
+
+impl DefaultsConfig {
+    /// `self` with every key set in `profile` replaced by the profile's value
+    fn overlay(&self, profile: &DefaultsConfig) -> Self {
+        Self {
+            max_lines: profile.max_lines.or(self.max_lines),
+            log_limit: profile.log_limit.or(self.log_limit),
+            list_limit: profile.list_limit.or(self.list_limit),
+            ascii: profile.ascii.or(self.ascii),
+            savings_goal: profile.savings_goal.or(self.savings_goal),
+            max_files: profile.max_files.or(self.max_files),
+            max_output_lines: profile.max_output_lines.or(self.max_output_lines),
+            max_output_bytes: profile.max_output_bytes.or(self.max_output_bytes),
         }
     }
 }
 
+/// The config file, loaded once per process. A missing file means built-in
+/// defaults; an unreadable or malformed one too, with a warning on stderr.
+pub fn loaded() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| {
+        Config::load().unwrap_or_else(|err| {
+            let path = get_config_path().unwrap_or_default();
+            eprintln!("rtk: ignoring {}: {}", path.display(), one_line(&err));
+            Config::default()
+        })
+    })
+}
+
+/// The config file's `[defaults]`, overlaid with the `--profile` selected
+/// through `RTK_PROFILE` when there is one
+pub fn defaults() -> &'static DefaultsConfig {
+    static DEFAULTS: OnceLock<DefaultsConfig> = OnceLock::new();
+    DEFAULTS.get_or_init(|| {
+        let profile = std::env::var("RTK_PROFILE").ok().filter(|p| !p.is_empty());
+        match select_profile(loaded(), profile.as_deref()) {
+            Ok(defaults) => defaults,
+            Err(name) => {
+                eprintln!("rtk: no [profiles.{}] in config, using [defaults]", name);
+                loaded().defaults.clone()
+            }
+        }
+    })
+}
+
+/// `[defaults]` overlaid with `[profiles.<profile>]`; Err(name) if it's missing
+fn select_profile(config: &Config, profile: Option<&str>) -> Result<DefaultsConfig, String> {
+    match profile {
+        None => Ok(config.defaults.clone()),
+        Some(name) => match config.profiles.get(name) {
+            Some(overlay) => Ok(config.defaults.overlay(overlay)),
+            None => Err(name.to_string()),
+        },
+    }
+}
+
+/// "TOML parse error at line 3, column 13: invalid type: ..." from toml's
+/// multi-line report (location line, source snippet, message)
+fn one_line(err: &anyhow::Error) -> String {
+    let text = err.to_string();
+    let lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+    match (lines.first(), lines.last()) {
+        (Some(first), Some(last)) if lines.len() > 1 => format!("{}: {}", first, last.trim()),
+        _ => text.trim().to_string(),
+    }
+}
+
 impl Config {
     pub fn load() -> Result<Self> {
         let path = get_config_path()?;
@@ -123,3 +232,74 @@ pub fn show_config() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_defaults_section() {
+        let config: Config = toml::from_str(
+            r#"
+[defaults]
+max_lines = 40
+list_limit = 50
+ascii = true
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.defaults,
+            DefaultsConfig {
+                max_lines: Some(40),
+                log_limit: None,
+                list_limit: Some(50),
+                ascii: Some(true),
+                ..Default::default()
+            }
+        );
+        // Other sections keep their defaults
+        assert!(config.tracking.enabled);
+
+        let empty: Config = toml::from_str("").unwrap();
+        assert_eq!(empty.defaults, DefaultsConfig::default());
+    }
+
+    #[test]
+    fn test_profile_overlays_defaults() {
+        let config: Config = toml::from_str(
+            r#"
+[defaults]
+max_lines = 40
+log_limit = 20
+
+[profiles.ci]
+max_lines = 400
+max_output_bytes = 0
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(select_profile(&config, None).unwrap(), config.defaults);
+        let ci = select_profile(&config, Some("ci")).unwrap();
+        assert_eq!(ci.max_lines, Some(400));
+        // Keys the profile leaves out come from [defaults]
+        assert_eq!(ci.log_limit, Some(20));
+        assert_eq!(ci.max_output_bytes, Some(0));
+        assert_eq!(
+            select_profile(&config, Some("nope")),
+            Err("nope".to_string())
+        );
+    }
+
+    #[test]
+    fn test_malformed_config_warning_is_one_line() {
+        let err: anyhow::Error = toml::from_str::<Config>("[defaults]\nmax_lines = \"many\"\n")
+            .unwrap_err()
+            .into();
+        let line = one_line(&err);
+        assert!(!line.contains('\n'));
+        assert!(line.starts_with("TOML parse error at line 2"));
+        assert!(line.contains("invalid type: string \"many\""));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::tracking;
+use crate::utils::emit;
 use anyhow::{Context, Result};
 use std::ffi::OsString;
 use std::process::Command;
@@ -47,7 +48,7 @@ fn docker_ps(_verbose: u8) -> Result<()> {
 
     if stdout.trim().is_empty() {
         rtk.push_str("🐳 0 containers");
-        println!("{}", rtk);
+        emit(&rtk);
         timer.track("docker ps", "rtk docker ps", &raw, &rtk);
         return Ok(());
     }
@@ -101,7 +102,7 @@ fn docker_images(_verbose: u8) -> Result<()> {
 
     if lines.is_empty() {
         rtk.push_str("🐳 0 images");
-        println!("{}", rtk);
+        emit(&rtk);
         timer.track("docker images", "rtk docker images", &raw, &rtk);
         return Ok(());
     }
@@ -171,7 +172,7 @@ fn docker_logs(args: &[String], _verbose: u8) -> Result<()> {
 
     let analyzed = crate::log_cmd::run_stdin_str(&raw);
     let rtk = format!("🐳 Logs for {}:\n{}", container, analyzed);
-    println!("{}", rtk);
+    emit(&rtk);
     timer.track(
         &format!("docker logs {}", container),
         "rtk docker logs",
@@ -198,7 +199,7 @@ fn kubectl_pods(args: &[String], _verbose: u8) -> Result<()> {
         Ok(v) => v,
         Err(_) => {
             rtk.push_str("☸️  No pods found");
-            println!("{}", rtk);
+            emit(&rtk);
             timer.track("kubectl get pods", "rtk kubectl pods", &raw, &rtk);
             return Ok(());
         }
@@ -207,7 +208,7 @@ fn kubectl_pods(args: &[String], _verbose: u8) -> Result<()> {
     let items = json["items"].as_array();
     if items.is_none() || items.unwrap().is_empty() {
         rtk.push_str("☸️  No pods found");
-        println!("{}", rtk);
+        emit(&rtk);
         timer.track("kubectl get pods", "rtk kubectl pods", &raw, &rtk);
         return Ok(());
     }
@@ -299,7 +300,7 @@ fn kubectl_services(args: &[String], _verbose: u8) -> Result<()> {
         Ok(v) => v,
         Err(_) => {
             rtk.push_str("☸️  No services found");
-            println!("{}", rtk);
+            emit(&rtk);
             timer.track("kubectl get svc", "rtk kubectl svc", &raw, &rtk);
             return Ok(());
         }
@@ -308,7 +309,7 @@ fn kubectl_services(args: &[String], _verbose: u8) -> Result<()> {
     let items = json["items"].as_array();
     if items.is_none() || items.unwrap().is_empty() {
         rtk.push_str("☸️  No services found");
-        println!("{}", rtk);
+        emit(&rtk);
         timer.track("kubectl get svc", "rtk kubectl svc", &raw, &rtk);
         return Ok(());
     }
@@ -375,7 +376,7 @@ fn kubectl_logs(args: &[String], _verbose: u8) -> Result<()> {
     let raw = String::from_utf8_lossy(&output.stdout).to_string();
     let analyzed = crate::log_cmd::run_stdin_str(&raw);
     let rtk = format!("☸️  Logs for {}:\n{}", pod, analyzed);
-    println!("{}", rtk);
+    emit(&rtk);
     timer.track(
         &format!("kubectl logs {}", pod),
         "rtk kubectl logs",
@@ -1,6 +1,6 @@
 use crate::json_cmd;
 use crate::tracking;
-use crate::utils::truncate;
+use crate::utils::{emit, truncate};
 use anyhow::{Context, Result};
 use std::process::Command;
 
@@ -35,7 +35,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
 
     // Auto-detect JSON and pipe through filter
     let filtered = filter_curl_output(&stdout);
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track(
         &format!("curl {}", args.join(" ")),
@@ -1,8 +1,20 @@
+use crate::explain::{self, DropTally};
 use crate::tracking;
+use crate::utils::{emit, run_raw, run_streaming, CommandError};
 use anyhow::{Context, Result};
 use std::process::Command;
 
-pub fn run(args: &[String], verbose: u8) -> Result<()> {
+/// Files below this line coverage percentage are listed in `deno coverage` output
+const COVERAGE_THRESHOLD: f64 = 80.0;
+
+pub fn run(args: &[String], verbose: u8, raw: bool) -> Result<()> {
+    if raw {
+        return run_raw(
+            Command::new("deno").args(args),
+            &format!("deno {}", args.join(" ")),
+        );
+    }
+
     let timer = tracking::TimedExecution::start();
 
     // Detect subcommand
@@ -17,6 +29,37 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         eprintln!("Running: deno {}", args.join(" "));
     }
 
+    // Long-running subcommands: filter and print as lines arrive
+    if matches!(subcommand, Some("serve") | Some("run")) {
+        let streamed = run_streaming(&mut cmd, keep_deno_run_line)
+            .context("Failed to run deno")?;
+        if streamed.filtered.is_empty() && streamed.status.success() {
+            emit("ok ✓");
+        }
+        explain::report(&deno_run_drops(&streamed.raw));
+
+        timer.track(
+            &format!("deno {}", args.join(" ")),
+            &format!("rtk deno {}", args.join(" ")),
+            &streamed.raw,
+            &streamed.filtered,
+        );
+
+        if !streamed.status.success() {
+            for denial in permission_denials(&streamed.raw) {
+                emit(&denial);
+            }
+            return Err(CommandError::with_excerpt(
+                "deno",
+                subcommand.unwrap_or(""),
+                streamed.status.code(),
+                String::new(),
+            )
+            .into());
+        }
+        return Ok(());
+    }
+
     let output = cmd.output().context("Failed to run deno")?;
     let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
@@ -27,7 +70,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         Some("lint") => filter_deno_lint(&raw),
         Some("check") => filter_deno_check(&raw),
         Some("task") => filter_deno_task(&raw),
-        Some("run") => filter_deno_run(&raw),
+        Some("fmt") if args.iter().any(|a| a == "--check") => filter_deno_fmt_check(&raw),
         Some("fmt") => filter_deno_fmt(&raw),
         Some("compile") => filter_deno_compile(&raw),
         Some("bench") => filter_deno_bench(&raw),
@@ -35,10 +78,11 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         Some("info") => filter_deno_info(&raw),
         Some("install") => filter_deno_install(&raw),
         Some("upgrade") => filter_deno_upgrade(&raw),
+        Some("coverage") => filter_deno_coverage(&raw),
         _ => raw.clone(), // Passthrough for other commands
     };
 
-    println!("{}", filtered.trim());
+    emit(filtered.trim());
 
     timer.track(
         &format!("deno {}", args.join(" ")),
@@ -48,7 +92,13 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     );
 
     if !output.status.success() {
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(CommandError::with_excerpt(
+            "deno",
+            subcommand.unwrap_or(""),
+            output.status.code(),
+            String::new(),
+        )
+        .into());
     }
 
     Ok(())
@@ -185,31 +235,80 @@ fn filter_deno_task(output: &str) -> String {
     }
 }
 
-/// Filter deno run output - strip startup messages
-fn filter_deno_run(output: &str) -> String {
-    let mut result = Vec::new();
+/// Per-line filter for deno run/serve: drop startup noise, keep actual output
+fn keep_deno_run_line(line: &str) -> bool {
+    deno_run_drop_reason(line).is_none()
+}
 
-    for line in output.lines() {
-        // Skip common startup noise
-        if line.contains("Download")
-            || line.contains("Check file://")
-            || line.starts_with("Compile")
-            || (line.contains("Warning") && line.contains("--allow-"))
-        {
-            continue;
-        }
+/// Why [`keep_deno_run_line`] drops a line, for `--explain`
+fn deno_run_drop_reason(line: &str) -> Option<&'static str> {
+    // A runtime denial is why the script failed, even if it mentions --allow-
+    if parse_permission_denial(line).is_some() {
+        return None;
+    }
+    // Skip common startup noise
+    if line.contains("Download") {
+        Some("download")
+    } else if line.contains("Check file://") {
+        Some("type-check")
+    } else if line.starts_with("Compile") {
+        Some("compile")
+    } else if line.contains("Warning") && line.contains("--allow-") {
+        Some("permission warning")
+    } else if line.trim().is_empty() {
+        Some("blank")
+    } else {
+        None
+    }
+}
 
-        // Keep actual output
-        if !line.trim().is_empty() {
-            result.push(line.to_string());
-        }
+/// Drop reasons for a whole deno run/serve transcript
+fn deno_run_drops(output: &str) -> DropTally {
+    let mut tally = DropTally::default();
+    for reason in output.lines().filter_map(deno_run_drop_reason) {
+        tally.add(reason);
     }
+    tally
+}
 
-    if result.is_empty() {
-        "ok ✓".to_string()
-    } else {
-        result.join("\n")
+/// One "✗ needs --allow-<perm> (<target>)" line per distinct denied permission
+fn permission_denials(output: &str) -> Vec<String> {
+    let mut denials: Vec<String> = Vec::new();
+    for denial in output.lines().filter_map(parse_permission_denial) {
+        if !denials.contains(&denial) {
+            denials.push(denial);
+        }
     }
+    denials
+}
+
+/// `PermissionDenied: Requires net access to "api.example.com", run again with
+/// the --allow-net flag` -> "✗ needs --allow-net (api.example.com)"
+fn parse_permission_denial(line: &str) -> Option<String> {
+    let denied = line.contains("PermissionDenied")
+        || line.contains("NotCapable")
+        || line.contains("run again with the --allow-");
+    if !denied {
+        return None;
+    }
+    let rest = &line[line.find("Requires ")? + "Requires ".len()..];
+    let (perm, rest) = rest.split_once(" access")?;
+    let flag = line
+        .split_whitespace()
+        .find(|w| w.starts_with("--allow-"))
+        .map(|w| {
+            w.trim_end_matches(|c: char| !c.is_ascii_alphanumeric())
+                .to_string()
+        })
+        .unwrap_or_else(|| format!("--allow-{}", perm));
+    let target = rest
+        .strip_prefix(" to \"")
+        .and_then(|t| t.split('"').next())
+        .filter(|t| !t.is_empty());
+    Some(match target {
+        Some(target) => format!("✗ needs {} ({})", flag, target),
+        None => format!("✗ needs {}", flag),
+    })
 }
 
 /// Filter deno fmt output - show only changed files or errors
@@ -250,6 +349,42 @@ fn filter_deno_fmt(output: &str) -> String {
     }
 }
 
+/// Filter deno fmt --check output - list files that would be reformatted
+fn filter_deno_fmt_check(output: &str) -> String {
+    let mut files = Vec::new();
+    let mut errors = Vec::new();
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        // Each unformatted file's diff starts with "from /path/file.ts:"
+        if let Some(path) = trimmed.strip_prefix("from ").and_then(|p| p.strip_suffix(':')) {
+            files.push(path.to_string());
+            continue;
+        }
+        // Summary line is redundant with our own count
+        if trimmed.starts_with("error: Found") && trimmed.contains("not formatted") {
+            continue;
+        }
+        if trimmed.starts_with("error") {
+            errors.push(trimmed.to_string());
+        }
+    }
+
+    if files.is_empty() {
+        if errors.is_empty() {
+            return "ok ✓ All files formatted".to_string();
+        }
+        return errors.join("\n");
+    }
+
+    let mut result = vec![format!("✗ {} files need formatting", files.len())];
+    for file in &files {
+        result.push(format!("  {}", file));
+    }
+    result.extend(errors);
+    result.join("\n")
+}
+
 /// Filter deno compile output - show only final binary info
 fn filter_deno_compile(output: &str) -> String {
     let mut result = Vec::new();
@@ -281,37 +416,101 @@ fn filter_deno_compile(output: &str) -> String {
     }
 }
 
-/// Filter deno bench output - show summary only
+/// One row of the `deno bench` results table
+#[derive(Debug, PartialEq)]
+struct BenchRow {
+    name: String,
+    /// Average time per iteration as printed, e.g. "248.5 ns"
+    time: String,
+    /// Same time in nanoseconds, for ranking
+    nanos: f64,
+    iter_per_sec: String,
+}
+
+/// Nanoseconds per unit in deno's `<n> <unit>/iter` column
+fn bench_unit_nanos(unit: &str) -> Option<f64> {
+    match unit {
+        "ps" => Some(0.001),
+        "ns" => Some(1.0),
+        "µs" | "us" => Some(1_000.0),
+        "ms" => Some(1_000_000.0),
+        "s" => Some(1_000_000_000.0),
+        _ => None,
+    }
+}
+
+/// "url parse   248.5 ns/iter   4,024,000   (238.6 ns … 361.9 ns) ..."
+fn parse_bench_row(line: &str) -> Option<BenchRow> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    let idx = tokens.iter().position(|t| t.ends_with("/iter"))?;
+    if idx < 2 {
+        return None;
+    }
+    let value = tokens[idx - 1];
+    let unit = tokens[idx].trim_end_matches("/iter");
+    let nanos = value.replace(',', "").parse::<f64>().ok()? * bench_unit_nanos(unit)?;
+    Some(BenchRow {
+        name: tokens[..idx - 1].join(" "),
+        time: format!("{} {}", value, unit),
+        nanos,
+        iter_per_sec: tokens.get(idx + 1).unwrap_or(&"").to_string(),
+    })
+}
+
+/// Filter deno bench output - results ranked slowest-first, plus errors
 fn filter_deno_bench(output: &str) -> String {
-    let mut result = Vec::new();
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
 
     for line in output.lines() {
-        // Skip warmup and individual iteration outputs
-        if line.contains("running")
-            || line.contains("Warmup")
-            || line.contains("warmup")
-            || line.starts_with("    at ")
-        {
-            continue;
+        if let Some(row) = parse_bench_row(line) {
+            rows.push(row);
+        } else if line.contains("error:") || line.contains("Error:") {
+            errors.push(line.trim().to_string());
         }
+    }
 
-        // Keep benchmark results and summary
-        if line.contains("benchmark")
-            || line.contains("Benchmark")
-            || line.contains("time:")
-            || line.contains("iter/s")
-            || line.contains("result:")
-            || line.contains("error:")
-        {
-            result.push(line.to_string());
-        }
+    if rows.is_empty() && errors.is_empty() {
+        return "ok ✓ Benchmarks complete".to_string();
     }
 
-    if result.is_empty() {
-        "ok ✓ Benchmarks complete".to_string()
-    } else {
-        result.join("\n")
+    rows.sort_by(|a, b| b.nanos.total_cmp(&a.nanos));
+
+    let name_width = rows
+        .iter()
+        .map(|r| r.name.chars().count())
+        .chain(std::iter::once("benchmark".len()))
+        .max()
+        .unwrap_or(0);
+    let time_width = rows
+        .iter()
+        .map(|r| r.time.chars().count())
+        .chain(std::iter::once("time/iter".len()))
+        .max()
+        .unwrap_or(0);
+
+    let mut result = Vec::new();
+    if !rows.is_empty() {
+        result.push(format!(
+            "{:<nw$}  {:>tw$}  iter/s",
+            "benchmark",
+            "time/iter",
+            nw = name_width,
+            tw = time_width
+        ));
+        for row in &rows {
+            result.push(format!(
+                "{:<nw$}  {:>tw$}  {}",
+                row.name,
+                row.time,
+                row.iter_per_sec,
+                nw = name_width,
+                tw = time_width
+            ));
+        }
     }
+    result.extend(errors);
+    result.join("\n")
 }
 
 /// Filter deno doc output - keep structure, trim verbose
@@ -450,6 +649,66 @@ fn filter_deno_upgrade(output: &str) -> String {
     }
 }
 
+/// Filter deno coverage output - overall percentages plus files below threshold
+fn filter_deno_coverage(output: &str) -> String {
+    let mut branch_col = None;
+    let mut line_col = None;
+    let mut overall: Option<(f64, f64)> = None;
+    let mut low_files = Vec::new();
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if !trimmed.starts_with('|') {
+            continue;
+        }
+
+        let cells: Vec<&str> = trimmed
+            .trim_matches('|')
+            .split('|')
+            .map(|c| c.trim())
+            .collect();
+
+        // Header row: locate the percentage columns
+        if cells.first() == Some(&"File") {
+            branch_col = cells.iter().position(|c| c.starts_with("Branch"));
+            line_col = cells.iter().position(|c| c.starts_with("Line"));
+            continue;
+        }
+
+        let (Some(b), Some(l)) = (branch_col, line_col) else {
+            continue;
+        };
+        let (Some(branch), Some(line_pct)) = (
+            cells.get(b).and_then(|c| c.parse::<f64>().ok()),
+            cells.get(l).and_then(|c| c.parse::<f64>().ok()),
+        ) else {
+            continue; // Separator row or malformed cell
+        };
+
+        let file = cells.first().copied().unwrap_or("");
+        if file == "All files" {
+            overall = Some((line_pct, branch));
+        } else if line_pct < COVERAGE_THRESHOLD {
+            low_files.push(format!("  {}: {:.1}%", file, line_pct));
+        }
+    }
+
+    let Some((line_total, branch_total)) = overall else {
+        return output.to_string();
+    };
+
+    if low_files.is_empty() {
+        return format!("ok ✓ Coverage {:.1}%", line_total);
+    }
+
+    let mut result = vec![format!(
+        "Coverage: {:.1}% lines, {:.1}% branches",
+        line_total, branch_total
+    )];
+    result.extend(low_files);
+    result.join("\n")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -522,18 +781,67 @@ Found 2 problems
     }
 
     #[test]
-    fn test_filter_deno_run_clean() {
+    fn test_keep_deno_run_line() {
         let output = r#"
 Download https://deno.land/std@0.224.0/http/server.ts
 Check file:///Users/test/server.ts
 Server listening on http://localhost:8000
 "#;
-        let result = filter_deno_run(output);
+        let kept: Vec<&str> = output.lines().filter(|l| keep_deno_run_line(l)).collect();
+        let result = kept.join("\n");
+        assert_eq!(kept.len(), 1);
         assert!(result.contains("Server listening"));
         assert!(!result.contains("Download"));
         assert!(!result.contains("Check file://"));
     }
 
+    #[test]
+    fn test_deno_run_drop_reasons() {
+        let output = "\
+Download https://deno.land/std@0.224.0/http/server.ts
+Download https://deno.land/std@0.224.0/http/status.ts
+Download https://deno.land/std@0.224.0/async/delay.ts
+Check file:///Users/test/server.ts
+Warning: --allow-net is ignored when --allow-all is set
+
+Server listening on http://localhost:8000
+";
+        let tally = deno_run_drops(output);
+        assert_eq!(tally.count("download"), 3);
+        assert_eq!(tally.count("type-check"), 1);
+        assert_eq!(tally.count("permission warning"), 1);
+        assert_eq!(tally.count("blank"), 1);
+        assert_eq!(
+            tally.summary(),
+            "suppressed: 3 download lines, 1 type-check line, 1 permission warning line, 1 blank line"
+        );
+    }
+
+    #[test]
+    fn test_deno_run_permission_denial() {
+        let output = r#"Warning: --allow-net is ignored when --allow-all is set
+Fetching data...
+error: Uncaught (in promise) PermissionDenied: Requires net access to "api.example.com", run again with the --allow-net flag
+    at mainFetch (ext:deno_fetch/26_fetch.js:182:11)
+"#;
+        let kept: Vec<&str> = output.lines().filter(|l| keep_deno_run_line(l)).collect();
+        assert!(!kept.iter().any(|l| l.starts_with("Warning")));
+        assert!(kept.iter().any(|l| l.contains("Requires net access")));
+
+        assert_eq!(
+            permission_denials(output),
+            vec!["✗ needs --allow-net (api.example.com)"]
+        );
+        assert_eq!(
+            parse_permission_denial(
+                "error: Uncaught NotCapable: Requires env access, run again with the --allow-env flag"
+            )
+            .as_deref(),
+            Some("✗ needs --allow-env")
+        );
+        assert_eq!(parse_permission_denial("Fetching data..."), None);
+    }
+
     #[test]
     fn test_filter_deno_fmt_clean() {
         let output = "Checked 15 files\n";
@@ -552,6 +860,34 @@ Formatted tests/app_test.ts
         assert!(result.contains("Formatted 3 files"));
     }
 
+    #[test]
+    fn test_filter_deno_fmt_check_needs_formatting() {
+        let output = r#"
+from /home/user/app/src/main.ts:
+ 1 | -const x=1
+ 1 | +const x = 1;
+
+from /home/user/app/src/util.ts:
+ 3 | -export function f(){return 1}
+ 3 | +export function f() {
+ 4 | +  return 1;
+ 5 | +}
+
+error: Found 2 not formatted files in 7 files
+"#;
+        let result = filter_deno_fmt_check(output);
+        assert!(result.starts_with("✗ 2 files need formatting"));
+        assert!(result.contains("  /home/user/app/src/main.ts"));
+        assert!(result.contains("  /home/user/app/src/util.ts"));
+        assert!(!result.contains("const x"));
+        assert!(!result.contains("Found 2"));
+    }
+
+    #[test]
+    fn test_filter_deno_fmt_check_clean() {
+        assert_eq!(filter_deno_fmt_check("Checked 7 files\n"), "ok ✓ All files formatted");
+    }
+
     #[test]
     fn test_filter_deno_info() {
         let output = r#"
@@ -595,4 +931,68 @@ deno upgraded from 1.40.0 to 1.41.0
         assert!(!result.contains("Downloading"));
         assert!(!result.contains("100.0%"));
     }
+
+    #[test]
+    fn test_filter_deno_bench_ranks_slowest_first() {
+        let output = r#"
+    CPU | Apple M1
+Runtime | Deno 1.40.0 (aarch64-apple-darwin)
+
+file:///Users/test/bench.ts
+benchmark      time/iter (avg)        iter/s      (min … max)           p75      p99     p995
+-------------- ----------------------------- --------------------- --------------------------
+Date.now()             29.2 ns/iter  34,230,000     (28.5 ns … 56.9 ns)   29.1 ns  35.3 ns  38.1 ns
+url parse             248.5 ns/iter   4,024,000     (238.6 ns … 361.9 ns) 249.3 ns 307.6 ns 312.5 ns
+"#;
+        assert_eq!(
+            filter_deno_bench(output),
+            "benchmark   time/iter  iter/s\n\
+             url parse    248.5 ns  4,024,000\n\
+             Date.now()    29.2 ns  34,230,000"
+        );
+    }
+
+    #[test]
+    fn test_filter_deno_bench_keeps_errors() {
+        let output = "fast   1.2 µs/iter   833,333   (1.1 µs … 2.0 µs)\n\
+                      broken   error: Error: boom\n";
+        let result = filter_deno_bench(output);
+        assert!(result.contains("fast"));
+        assert!(result.contains("broken   error: Error: boom"));
+        assert_eq!(parse_bench_row("broken   error: Error: boom"), None);
+        assert_eq!(
+            parse_bench_row("fast   1.2 µs/iter   833,333")
+                .unwrap()
+                .nanos,
+            1_200.0
+        );
+    }
+
+    #[test]
+    fn test_filter_deno_coverage_below_threshold() {
+        let output = r#"
+| File              | Branch % | Line % |
+| ----------------- | -------- | ------ |
+| src/app.ts        |     85.0 |   92.5 |
+| src/utils.ts      |     60.0 |   72.3 |
+| src/db/client.ts  |    100.0 |   45.0 |
+| All files         |     79.0 |   84.1 |
+"#;
+        let result = filter_deno_coverage(output);
+        assert!(result.contains("Coverage: 84.1% lines, 79.0% branches"));
+        assert!(result.contains("src/utils.ts: 72.3%"));
+        assert!(result.contains("src/db/client.ts: 45.0%"));
+        assert!(!result.contains("src/app.ts"));
+    }
+
+    #[test]
+    fn test_filter_deno_coverage_all_above_threshold() {
+        let output = r#"
+| File         | Branch % | Line % |
+| ------------ | -------- | ------ |
+| src/app.ts   |     90.0 |   95.0 |
+| All files    |     90.0 |   95.0 |
+"#;
+        assert_eq!(filter_deno_coverage(output), "ok ✓ Coverage 95.0%");
+    }
 }
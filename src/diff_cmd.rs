@@ -1,5 +1,5 @@
 use crate::tracking;
-use crate::utils::truncate;
+use crate::utils::{emit, truncate};
 use anyhow::Result;
 use std::fs;
 use std::path::Path;
@@ -43,7 +43,7 @@ pub fn run(
         // Files are identical
         if !quiet {
             rtk.push_str("✅ Files are identical");
-            println!("{}", rtk);
+            emit(&rtk);
         }
         timer.track(
             &format!("diff {} {}", file1.display(), file2.display()),
@@ -108,7 +108,7 @@ pub fn run_stdin(_verbose: u8) -> Result<()> {
 
     // Parse unified diff format
     let condensed = condense_unified_diff(&input);
-    println!("{}", condensed);
+    emit(&condensed);
 
     timer.track("diff (stdin)", "rtk diff (stdin)", &input, &condensed);
 
@@ -0,0 +1,94 @@
+//! `rtk doctor`: check that the external tools rtk wraps are installed.
+//!
+//! Each wrapper shells out to a binary and only fails at call time when it is
+//! missing; this probes them all up front with `--version`.
+
+use crate::glyphs::Glyphs;
+use crate::tracking;
+use anyhow::Result;
+use std::process::Command;
+
+/// Binaries wrapped by rtk modules
+const TOOLS: &[&str] = &["git", "gh", "deno", "pnpm", "nx", "supabase", "ccusage"];
+
+pub fn run(verbose: u8) -> Result<()> {
+    let glyphs = Glyphs::current();
+    let mut found = 0;
+
+    for tool in TOOLS {
+        if verbose > 0 {
+            eprintln!("Probing: {} --version", tool);
+        }
+        match probe_version(tool) {
+            Some(version) => {
+                found += 1;
+                println!("{} {} {}", glyphs.success, tool, version);
+            }
+            None => println!("{} {} (not found)", glyphs.failure, tool),
+        }
+    }
+
+    match tracking::check_db_writable() {
+        Ok(path) => println!("{} tracking db {}", glyphs.success, path.display()),
+        Err(e) => println!("{} tracking db not writable: {}", glyphs.failure, e),
+    }
+
+    println!("{}/{} tools found", found, TOOLS.len());
+    Ok(())
+}
+
+/// Run `<tool> --version`; None when the binary is missing or fails.
+fn probe_version(tool: &str) -> Option<String> {
+    let output = Command::new(tool).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Some(parse_version(&stdout).unwrap_or_else(|| "(unknown version)".to_string()))
+}
+
+/// First dotted version number in `--version` output, without a leading "v".
+///
+/// "git version 2.43.0" → "2.43.0", "- Local: v17.0.2" → "17.0.2"
+fn parse_version(output: &str) -> Option<String> {
+    output.split_whitespace().find_map(|word| {
+        let word = word.trim_start_matches('v');
+        let version: String = word
+            .chars()
+            .take_while(|c| c.is_ascii_digit() || *c == '.')
+            .collect();
+        let version = version.trim_end_matches('.');
+        (version.contains('.') && version.starts_with(|c: char| c.is_ascii_digit()))
+            .then(|| version.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(
+            parse_version("git version 2.43.0\n"),
+            Some("2.43.0".to_string())
+        );
+        assert_eq!(
+            parse_version(
+                "gh version 2.40.1 (2023-12-13)\nhttps://github.com/cli/cli/releases/tag/v2.40.1\n"
+            ),
+            Some("2.40.1".to_string())
+        );
+        assert_eq!(
+            parse_version("deno 1.40.0 (release, x86_64-unknown-linux-gnu)\nv8 12.1.285.6\n"),
+            Some("1.40.0".to_string())
+        );
+        assert_eq!(parse_version("9.1.0\n"), Some("9.1.0".to_string()));
+        assert_eq!(
+            parse_version("Nx Version:\n- Local: v17.0.2\n- Global: Not found\n"),
+            Some("17.0.2".to_string())
+        );
+        assert_eq!(parse_version("no version here"), None);
+        assert_eq!(parse_version(""), None);
+    }
+}
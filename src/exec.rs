@@ -0,0 +1,110 @@
+//! External command execution behind a trait.
+//!
+//! Filters are pure functions and easy to test, but the `run` code around them
+//! (arg assembly, exit codes, success lines) shells out directly. Handlers that
+//! take a [`CommandRunner`] can be driven by [`mock::MockRunner`] in tests with
+//! canned stdout/stderr and exit codes; production code passes [`SystemRunner`].
+//!
+//! So far the git network commands (push, pull, fetch) and the git/gh
+//! passthroughs take a runner; other handlers still call `Command` directly.
+
+use std::io;
+use std::process::{Command, ExitStatus, Output};
+
+pub trait CommandRunner {
+    /// Run `cmd` to completion, capturing stdout and stderr.
+    fn output(&self, cmd: &mut Command) -> io::Result<Output>;
+
+    /// Run `cmd` with the terminal's stdio (editors, prompts, live progress).
+    fn status(&self, cmd: &mut Command) -> io::Result<ExitStatus>;
+}
+
+/// Spawns the real process
+pub struct SystemRunner;
+
+impl CommandRunner for SystemRunner {
+    fn output(&self, cmd: &mut Command) -> io::Result<Output> {
+        cmd.output()
+    }
+
+    fn status(&self, cmd: &mut Command) -> io::Result<ExitStatus> {
+        cmd.status()
+    }
+}
+
+#[cfg(test)]
+pub mod mock {
+    use super::CommandRunner;
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::io;
+    use std::process::{Command, ExitStatus, Output};
+
+    /// Replays queued outputs in order and records every command line it was
+    /// asked to run ("git push origin main").
+    #[derive(Default)]
+    pub struct MockRunner {
+        responses: RefCell<VecDeque<Output>>,
+        calls: RefCell<Vec<String>>,
+        inherited: RefCell<Vec<String>>,
+    }
+
+    impl MockRunner {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Queue the result of the next command.
+        pub fn respond(self, code: i32, stdout: &str, stderr: &str) -> Self {
+            self.responses.borrow_mut().push_back(Output {
+                status: exit_status(code),
+                stdout: stdout.as_bytes().to_vec(),
+                stderr: stderr.as_bytes().to_vec(),
+            });
+            self
+        }
+
+        pub fn calls(&self) -> Vec<String> {
+            self.calls.borrow().clone()
+        }
+
+        /// The subset of [`Self::calls`] run through `status` (inherited stdio).
+        pub fn inherited(&self) -> Vec<String> {
+            self.inherited.borrow().clone()
+        }
+    }
+
+    impl CommandRunner for MockRunner {
+        fn output(&self, cmd: &mut Command) -> io::Result<Output> {
+            let mut line = cmd.get_program().to_string_lossy().into_owned();
+            for arg in cmd.get_args() {
+                line.push(' ');
+                line.push_str(&arg.to_string_lossy());
+            }
+            self.calls.borrow_mut().push(line.clone());
+            self.responses
+                .borrow_mut()
+                .pop_front()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, line))
+        }
+
+        fn status(&self, cmd: &mut Command) -> io::Result<ExitStatus> {
+            let output = self.output(cmd)?;
+            let line = self.calls.borrow().last().cloned().unwrap_or_default();
+            self.inherited.borrow_mut().push(line);
+            Ok(output.status)
+        }
+    }
+
+    #[cfg(unix)]
+    fn exit_status(code: i32) -> ExitStatus {
+        use std::os::unix::process::ExitStatusExt;
+        ExitStatus::from_raw(code << 8)
+    }
+
+    #[cfg(windows)]
+    fn exit_status(code: i32) -> ExitStatus {
+        use std::os::windows::process::ExitStatusExt;
+        ExitStatus::from_raw(code as u32)
+    }
+}
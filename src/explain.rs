@@ -0,0 +1,81 @@
+//! `--explain`: what a filter dropped, by category.
+//!
+//! Filters that support it tally each suppressed line under a short reason
+//! ("download", "progress") and the run path reports the tally on stderr:
+//! `suppressed: 14 download lines, 3 progress lines`.
+
+/// Suppressed-line counts per reason, in first-seen order
+#[derive(Debug, Default, PartialEq)]
+pub struct DropTally {
+    counts: Vec<(&'static str, usize)>,
+}
+
+impl DropTally {
+    pub fn add(&mut self, reason: &'static str) {
+        match self.counts.iter_mut().find(|(r, _)| *r == reason) {
+            Some((_, n)) => *n += 1,
+            None => self.counts.push((reason, 1)),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn count(&self, reason: &str) -> usize {
+        self.counts
+            .iter()
+            .find(|(r, _)| *r == reason)
+            .map_or(0, |(_, n)| *n)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// "suppressed: 14 download lines, 1 blank line"
+    pub fn summary(&self) -> String {
+        let parts: Vec<String> = self
+            .counts
+            .iter()
+            .map(|(reason, n)| {
+                let noun = if *n == 1 { "line" } else { "lines" };
+                format!("{} {} {}", n, reason, noun)
+            })
+            .collect();
+        format!("suppressed: {}", parts.join(", "))
+    }
+}
+
+/// Returns true when `--explain` was passed (`RTK_EXPLAIN`).
+pub fn enabled() -> bool {
+    std::env::var("RTK_EXPLAIN")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+/// Print the tally to stderr when `--explain` is on and anything was dropped
+pub fn report(tally: &DropTally) {
+    if enabled() && !tally.is_empty() {
+        eprintln!("{}", tally.summary());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_tally_summary() {
+        let mut tally = DropTally::default();
+        assert!(tally.is_empty());
+        for _ in 0..14 {
+            tally.add("download");
+        }
+        tally.add("blank");
+        tally.add("download");
+        assert_eq!(tally.count("download"), 15);
+        assert_eq!(tally.count("progress"), 0);
+        assert_eq!(
+            tally.summary(),
+            "suppressed: 15 download lines, 1 blank line"
+        );
+    }
+}
@@ -1,11 +1,12 @@
 use crate::tracking;
+use crate::utils::emit;
 use anyhow::Result;
 use ignore::WalkBuilder;
 use std::collections::HashMap;
 use std::path::Path;
 
 /// Match a filename against a glob pattern (supports `*` and `?`).
-fn glob_match(pattern: &str, name: &str) -> bool {
+pub(crate) fn glob_match(pattern: &str, name: &str) -> bool {
     glob_match_inner(pattern.as_bytes(), name.as_bytes())
 }
 
@@ -97,7 +98,7 @@ pub fn run(
 
     if files.is_empty() {
         let msg = format!("0 for '{}'", effective_pattern);
-        println!("{}", msg);
+        emit(&msg);
         timer.track(
             &format!("find {} -name '{}'", path, effective_pattern),
             "rtk find",
@@ -188,7 +189,7 @@ pub fn run(
             .map(|(e, c)| format!(".{}({})", e, c))
             .collect();
         ext_line = format!("ext: {}", ext_str.join(" "));
-        println!("{}", ext_line);
+        emit(&ext_line);
     }
 
     let rtk_output = format!("{}F {}D + {}", total_files, dirs_count, ext_line);
@@ -1,7 +1,7 @@
 use crate::prettier_cmd;
 use crate::ruff_cmd;
 use crate::tracking;
-use crate::utils::package_manager_exec;
+use crate::utils::{emit, package_manager_exec};
 use anyhow::{Context, Result};
 use std::path::Path;
 use std::process::Command;
@@ -127,7 +127,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         _ => raw.trim().to_string(),
     };
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track(
         &format!("{} {}", formatter, user_args.join(" ")),
@@ -1,5 +1,5 @@
 use crate::display_helpers::{format_duration, print_period_table};
-use crate::tracking::{DayStats, MonthStats, Tracker, WeekStats};
+use crate::tracking::{self, DayStats, MonthStats, Tracker, WeekStats};
 use crate::utils::format_tokens;
 use anyhow::{Context, Result};
 use serde::Serialize;
@@ -342,3 +342,19 @@ fn export_csv(
 
     Ok(())
 }
+
+/// `rtk tracking export`: stream raw tracked events for external dashboards
+pub fn run_events_export(since: Option<&str>, format: &str) -> Result<()> {
+    if format != "jsonl" {
+        anyhow::bail!("Unsupported export format '{}' (supported: jsonl)", format);
+    }
+    let since = since.map(tracking::parse_since).transpose()?;
+
+    let tracker = Tracker::new().context("Failed to initialize tracking database")?;
+    let stdout = std::io::stdout();
+    let mut out = std::io::BufWriter::new(stdout.lock());
+    tracker
+        .for_each_event(since, |event| tracking::write_event_jsonl(&event, &mut out))
+        .context("Failed to export tracked events")?;
+    Ok(())
+}
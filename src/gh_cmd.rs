@@ -3,20 +3,52 @@
 //! Provides token-optimized alternatives to verbose `gh` commands.
 //! Focuses on extracting essential information from JSON outputs.
 
+use crate::cache;
+use crate::config;
+use crate::exec::{CommandRunner, SystemRunner};
 use crate::git;
-use crate::json_cmd;
+use crate::glyphs::{ascii_mode, Glyphs};
+use crate::term;
 use crate::tracking;
-use crate::utils::{ok_confirmation, truncate};
+use crate::utils::{
+    emit, emit_json, ok_confirmation, run_raw, stderr_excerpt, truncate, CommandError,
+};
 use anyhow::{Context, Result};
+use serde::Serialize;
 use serde_json::Value;
-use std::process::Command;
+use std::process::{Command, Stdio};
 
 /// Run a gh command with token-optimized output
-pub fn run(subcommand: &str, args: &[String], verbose: u8, ultra_compact: bool) -> Result<()> {
+pub fn run(
+    subcommand: &str,
+    args: &[String],
+    verbose: u8,
+    ultra_compact: bool,
+    raw: bool,
+) -> Result<()> {
+    if raw {
+        return run_raw(
+            Command::new("gh").arg(subcommand).args(args),
+            &format!("gh {} {}", subcommand, args.join(" ")),
+        );
+    }
+
+    let (limit, args) = if is_list_command(subcommand, args) {
+        let (flag, args) = extract_limit_arg(args)?;
+        (
+            resolve_list_limit(flag, config::defaults().list_limit),
+            args,
+        )
+    } else {
+        (None, args.to_vec())
+    };
+    let args = args.as_slice();
+
     match subcommand {
-        "pr" => run_pr(args, verbose, ultra_compact),
-        "issue" => run_issue(args, verbose, ultra_compact),
-        "run" => run_workflow(args, verbose, ultra_compact),
+        "pr" => run_pr(args, limit, verbose, ultra_compact),
+        "issue" => run_issue(args, limit, verbose, ultra_compact),
+        "run" => run_workflow(args, limit, verbose, ultra_compact),
+        "workflow" => run_workflow_defs(args, verbose),
         "repo" => run_repo(args, verbose, ultra_compact),
         "api" => run_api(args, verbose),
         _ => {
@@ -26,13 +58,58 @@ pub fn run(subcommand: &str, args: &[String], verbose: u8, ultra_compact: bool)
     }
 }
 
-fn run_pr(args: &[String], verbose: u8, ultra_compact: bool) -> Result<()> {
+/// Default number of PRs/issues shown by `list`
+const DEFAULT_LIST_LIMIT: usize = 20;
+
+/// Default number of workflow runs fetched by `gh run list`
+const DEFAULT_RUN_LIMIT: usize = 10;
+
+/// `--limit` wins over the config file's `list_limit`; None keeps the
+/// per-command default
+fn resolve_list_limit(flag: Option<usize>, configured: Option<usize>) -> Option<usize> {
+    flag.or(configured.filter(|&n| n > 0))
+}
+
+/// `gh pr|issue|run list` — the commands that honor `--limit`
+fn is_list_command(subcommand: &str, args: &[String]) -> bool {
+    matches!(subcommand, "pr" | "issue" | "run") && args.first().map(|a| a.as_str()) == Some("list")
+}
+
+/// Split `--limit N` / `--limit=N` / `-n N` / `-L N` out of list args.
+///
+/// The value drives both gh's server-side `--limit` and how many rows are shown.
+/// A missing or non-numeric value is an error rather than silently ignored.
+fn extract_limit_arg(args: &[String]) -> Result<(Option<usize>, Vec<String>)> {
+    let mut limit = None;
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let (flag, value) = if matches!(arg.as_str(), "--limit" | "-n" | "-L") {
+            (arg.as_str(), iter.next().map(|v| v.as_str()))
+        } else if let Some(v) = arg.strip_prefix("--limit=") {
+            ("--limit", Some(v))
+        } else {
+            rest.push(arg.clone());
+            continue;
+        };
+        match value.map(|v| (v, v.trim().parse::<usize>())) {
+            Some((_, Ok(n))) => limit = Some(n),
+            Some((v, Err(_))) => {
+                anyhow::bail!("invalid {} value '{}': expected a number", flag, v)
+            }
+            None => anyhow::bail!("{} requires a number", flag),
+        }
+    }
+    Ok((limit, rest))
+}
+
+fn run_pr(args: &[String], limit: Option<usize>, verbose: u8, ultra_compact: bool) -> Result<()> {
     if args.is_empty() {
         return run_passthrough("gh", "pr", args);
     }
 
     match args[0].as_str() {
-        "list" => list_prs(&args[1..], verbose, ultra_compact),
+        "list" => list_prs(&args[1..], limit, verbose, ultra_compact),
         "view" => view_pr(&args[1..], verbose, ultra_compact),
         "checks" => pr_checks(&args[1..], verbose, ultra_compact),
         "status" => pr_status(verbose, ultra_compact),
@@ -45,19 +122,41 @@ fn run_pr(args: &[String], verbose: u8, ultra_compact: bool) -> Result<()> {
     }
 }
 
-fn list_prs(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
+fn list_prs(
+    args: &[String],
+    limit: Option<usize>,
+    _verbose: u8,
+    _ultra_compact: bool,
+) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    if let Some(cached) = cache::lookup("gh pr list") {
+        emit(cached.trim_end());
+        return Ok(());
+    }
+
+    // Base columns are always shown; `--fields` appends extras
+    const BASE_FIELDS: [&str; 5] = ["number", "title", "state", "author", "updatedAt"];
+    let (extra_fields, args) = extract_fields_arg(args);
+    let mut extra_fields = extra_fields.unwrap_or_default();
+    validate_pr_fields(&extra_fields)?;
+    extra_fields.retain(|f| !BASE_FIELDS.contains(&f.as_str()));
+
+    let mut json_fields = BASE_FIELDS.join(",");
+    for field in &extra_fields {
+        json_fields.push(',');
+        json_fields.push_str(field);
+    }
+
     let mut cmd = Command::new("gh");
-    cmd.args([
-        "pr",
-        "list",
-        "--json",
-        "number,title,state,author,updatedAt",
-    ]);
+    cmd.args(["pr", "list", "--json", &json_fields]);
+    if let Some(n) = limit {
+        cmd.arg("--limit").arg(n.to_string());
+    }
+    let shown = limit.unwrap_or(DEFAULT_LIST_LIMIT);
 
     // Pass through additional flags
-    for arg in args {
+    for arg in &args {
         cmd.arg(arg);
     }
 
@@ -67,8 +166,7 @@ fn list_prs(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         timer.track("gh pr list", "rtk gh pr list", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_error("pr list", output.status.code(), &stderr).into());
     }
 
     let json: Value =
@@ -77,75 +175,267 @@ fn list_prs(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
     let mut filtered = String::new();
 
     if let Some(prs) = json.as_array() {
-        if ultra_compact {
+        if ascii_mode() {
             filtered.push_str("PRs\n");
-            println!("PRs");
         } else {
             filtered.push_str("📋 Pull Requests\n");
-            println!("📋 Pull Requests");
         }
 
-        for pr in prs.iter().take(20) {
+        for pr in prs.iter().take(shown) {
             let number = pr["number"].as_i64().unwrap_or(0);
             let title = pr["title"].as_str().unwrap_or("???");
             let state = pr["state"].as_str().unwrap_or("???");
             let author = pr["author"]["login"].as_str().unwrap_or("???");
 
-            let state_icon = if ultra_compact {
-                match state {
-                    "OPEN" => "O",
-                    "MERGED" => "M",
-                    "CLOSED" => "C",
-                    _ => "?",
-                }
-            } else {
-                match state {
-                    "OPEN" => "🟢",
-                    "MERGED" => "🟣",
-                    "CLOSED" => "🔴",
-                    _ => "⚪",
-                }
-            };
+            let state_icon = pr_state_glyph(state, Glyphs::current());
 
-            let line = format!(
-                "  {} #{} {} ({})\n",
+            let mut line = format!(
+                "  {} #{} {} ({})",
                 state_icon,
                 number,
                 truncate(title, 60),
                 author
             );
+            for field in &extra_fields {
+                if let Some(extra) = format_pr_extra(field, &pr[field.as_str()]) {
+                    line.push(' ');
+                    line.push_str(&extra);
+                }
+            }
+            line.push('\n');
             filtered.push_str(&line);
-            print!("{}", line);
         }
 
-        if prs.len() > 20 {
-            let more_line = format!("  ... {} more (use gh pr list for all)\n", prs.len() - 20);
+        if prs.len() > shown {
+            let more_line = format!(
+                "  ... {} more (use gh pr list for all)\n",
+                prs.len() - shown
+            );
             filtered.push_str(&more_line);
-            print!("{}", more_line);
         }
     }
 
+    emit(filtered.trim_end());
+    cache::store("gh pr list", &filtered);
     timer.track("gh pr list", "rtk gh pr list", &raw, &filtered);
     Ok(())
 }
 
+/// rtk's distilled `gh pr view` fields, printed as JSON by `--json-summary`
+#[derive(Debug, Serialize)]
+struct PrSummary {
+    number: i64,
+    title: String,
+    state: String,
+    author: String,
+    mergeable: String,
+    reviews: ReviewCounts,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    checks: Option<CheckCounts>,
+    url: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ReviewCounts {
+    approved: usize,
+    changes_requested: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct CheckCounts {
+    passed: usize,
+    failed: usize,
+    total: usize,
+}
+
+/// rtk's distilled `gh issue view` fields, printed as JSON by `--json-summary`
+#[derive(Debug, Serialize)]
+struct IssueSummary {
+    number: i64,
+    title: String,
+    state: String,
+    author: String,
+    url: String,
+}
+
+/// Split rtk's `--json-summary` flag out of view args
+fn extract_json_summary_flag(args: &[String]) -> (bool, Vec<String>) {
+    let rest: Vec<String> = args
+        .iter()
+        .filter(|a| *a != "--json-summary")
+        .cloned()
+        .collect();
+    (rest.len() != args.len(), rest)
+}
+
+fn pr_summary(json: &Value) -> PrSummary {
+    let count_state = |items: &[Value], wanted: &str| {
+        items
+            .iter()
+            .filter(|i| {
+                i["state"].as_str() == Some(wanted) || i["conclusion"].as_str() == Some(wanted)
+            })
+            .count()
+    };
+
+    let reviews = json["reviews"]["nodes"]
+        .as_array()
+        .map(|r| r.as_slice())
+        .unwrap_or_default();
+
+    PrSummary {
+        number: json["number"].as_i64().unwrap_or(0),
+        title: json["title"].as_str().unwrap_or("???").to_string(),
+        state: json["state"].as_str().unwrap_or("???").to_string(),
+        author: json["author"]["login"]
+            .as_str()
+            .unwrap_or("???")
+            .to_string(),
+        mergeable: json["mergeable"].as_str().unwrap_or("UNKNOWN").to_string(),
+        reviews: ReviewCounts {
+            approved: count_state(reviews, "APPROVED"),
+            changes_requested: count_state(reviews, "CHANGES_REQUESTED"),
+        },
+        checks: json["statusCheckRollup"]
+            .as_array()
+            .map(|checks| CheckCounts {
+                passed: count_state(checks, "SUCCESS"),
+                failed: count_state(checks, "FAILURE"),
+                total: checks.len(),
+            }),
+        url: json["url"].as_str().unwrap_or("").to_string(),
+    }
+}
+
+fn issue_summary(json: &Value) -> IssueSummary {
+    IssueSummary {
+        number: json["number"].as_i64().unwrap_or(0),
+        title: json["title"].as_str().unwrap_or("???").to_string(),
+        state: json["state"].as_str().unwrap_or("???").to_string(),
+        author: json["author"]["login"]
+            .as_str()
+            .unwrap_or("???")
+            .to_string(),
+        url: json["url"].as_str().unwrap_or("").to_string(),
+    }
+}
+
+/// Fields `gh pr list --json` accepts
+const PR_FIELDS: &[&str] = &[
+    "additions",
+    "assignees",
+    "author",
+    "autoMergeRequest",
+    "baseRefName",
+    "body",
+    "changedFiles",
+    "closed",
+    "closedAt",
+    "comments",
+    "commits",
+    "createdAt",
+    "deletions",
+    "files",
+    "headRefName",
+    "headRefOid",
+    "headRepository",
+    "headRepositoryOwner",
+    "id",
+    "isCrossRepository",
+    "isDraft",
+    "labels",
+    "latestReviews",
+    "maintainerCanModify",
+    "mergeCommit",
+    "mergeStateStatus",
+    "mergeable",
+    "mergedAt",
+    "mergedBy",
+    "milestone",
+    "number",
+    "potentialMergeCommit",
+    "projectCards",
+    "projectItems",
+    "reactionGroups",
+    "reviewDecision",
+    "reviewRequests",
+    "reviews",
+    "state",
+    "statusCheckRollup",
+    "title",
+    "updatedAt",
+    "url",
+];
+
+/// Reject `--fields` entries gh would not recognize, before calling gh
+fn validate_pr_fields(fields: &[String]) -> Result<()> {
+    let unknown: Vec<&str> = fields
+        .iter()
+        .map(|f| f.as_str())
+        .filter(|f| !PR_FIELDS.contains(f))
+        .collect();
+    if !unknown.is_empty() {
+        anyhow::bail!(
+            "Unknown PR field(s) for --fields: {} (known: {})",
+            unknown.join(", "),
+            PR_FIELDS.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Extra `--fields` column: "[draft]", "[bug,urgent]", "[feature/x]", "[additions=12]".
+/// None for false/empty values.
+fn format_pr_extra(field: &str, value: &Value) -> Option<String> {
+    // Objects and arrays of objects render by their login/name/title
+    let label = |v: &Value| -> Option<String> {
+        match v {
+            Value::String(s) => Some(s.clone()),
+            Value::Object(_) => ["login", "name", "title"]
+                .iter()
+                .find_map(|k| v[*k].as_str())
+                .map(|s| s.to_string()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        }
+    };
+
+    match value {
+        Value::Bool(true) => Some(format!(
+            "[{}]",
+            field.strip_prefix("is").unwrap_or(field).to_lowercase()
+        )),
+        Value::Number(n) => Some(format!("[{}={}]", field, n)),
+        Value::String(s) if !s.is_empty() => Some(format!("[{}]", s)),
+        Value::Array(items) if !items.is_empty() => {
+            let labels: Vec<String> = items.iter().filter_map(label).collect();
+            (!labels.is_empty()).then(|| format!("[{}]", labels.join(",")))
+        }
+        Value::Object(_) => label(value).map(|l| format!("[{}]", l)),
+        _ => None,
+    }
+}
+
 fn view_pr(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    let (json_summary, args) = extract_json_summary_flag(args);
+    let with_comments = args.iter().any(|a| a == "--comments");
+    let args: Vec<String> = args.into_iter().filter(|a| a != "--comments").collect();
     if args.is_empty() {
         return Err(anyhow::anyhow!("PR number required"));
     }
 
     let pr_number = &args[0];
 
+    let mut fields =
+        "number,title,state,author,body,url,mergeable,reviews,statusCheckRollup".to_string();
+    if with_comments {
+        fields.push_str(",comments");
+    }
+
     let mut cmd = Command::new("gh");
-    cmd.args([
-        "pr",
-        "view",
-        pr_number,
-        "--json",
-        "number,title,state,author,body,url,mergeable,reviews,statusCheckRollup",
-    ]);
+    cmd.args(["pr", "view", pr_number, "--json", &fields]);
 
     let output = cmd.output().context("Failed to run gh pr view")?;
     let raw = String::from_utf8_lossy(&output.stdout).to_string();
@@ -158,141 +448,116 @@ fn view_pr(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
             &stderr,
             &stderr,
         );
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_error("pr view", output.status.code(), &stderr).into());
     }
 
     let json: Value =
         serde_json::from_slice(&output.stdout).context("Failed to parse gh pr view output")?;
 
+    let summary = pr_summary(&json);
+
+    if json_summary {
+        let filtered = serde_json::to_string(&summary)?;
+        emit_json(&filtered);
+        timer.track(
+            &format!("gh pr view {}", pr_number),
+            &format!("rtk gh pr view {} --json-summary", pr_number),
+            &raw,
+            &filtered,
+        );
+        return Ok(());
+    }
+
     let mut filtered = String::new();
 
-    // Extract essential info
-    let number = json["number"].as_i64().unwrap_or(0);
-    let title = json["title"].as_str().unwrap_or("???");
-    let state = json["state"].as_str().unwrap_or("???");
-    let author = json["author"]["login"].as_str().unwrap_or("???");
-    let url = json["url"].as_str().unwrap_or("");
-    let mergeable = json["mergeable"].as_str().unwrap_or("UNKNOWN");
-
-    let state_icon = if ultra_compact {
-        match state {
-            "OPEN" => "O",
-            "MERGED" => "M",
-            "CLOSED" => "C",
-            _ => "?",
-        }
-    } else {
-        match state {
-            "OPEN" => "🟢",
-            "MERGED" => "🟣",
-            "CLOSED" => "🔴",
-            _ => "⚪",
-        }
-    };
+    let PrSummary {
+        number,
+        ref title,
+        ref state,
+        ref author,
+        ref mergeable,
+        ref reviews,
+        ref checks,
+        ref url,
+    } = summary;
+
+    let state_icon = pr_state_glyph(state, Glyphs::current());
 
     let line = format!("{} PR #{}: {}\n", state_icon, number, title);
     filtered.push_str(&line);
-    print!("{}", line);
 
     let line = format!("  {}\n", author);
     filtered.push_str(&line);
-    print!("{}", line);
 
-    let mergeable_str = match mergeable {
+    let mergeable_str = match mergeable.as_str() {
         "MERGEABLE" => "✓",
         "CONFLICTING" => "✗",
         _ => "?",
     };
     let line = format!("  {} | {}\n", state, mergeable_str);
     filtered.push_str(&line);
-    print!("{}", line);
 
     // Show reviews summary
-    if let Some(reviews) = json["reviews"]["nodes"].as_array() {
-        let approved = reviews
-            .iter()
-            .filter(|r| r["state"].as_str() == Some("APPROVED"))
-            .count();
-        let changes = reviews
-            .iter()
-            .filter(|r| r["state"].as_str() == Some("CHANGES_REQUESTED"))
-            .count();
-
-        if approved > 0 || changes > 0 {
-            let line = format!(
-                "  Reviews: {} approved, {} changes requested\n",
-                approved, changes
-            );
-            filtered.push_str(&line);
-            print!("{}", line);
-        }
+    if reviews.approved > 0 || reviews.changes_requested > 0 {
+        let line = format!(
+            "  Reviews: {} approved, {} changes requested\n",
+            reviews.approved, reviews.changes_requested
+        );
+        filtered.push_str(&line);
     }
 
     // Show checks summary
-    if let Some(checks) = json["statusCheckRollup"].as_array() {
-        let total = checks.len();
-        let passed = checks
-            .iter()
-            .filter(|c| {
-                c["conclusion"].as_str() == Some("SUCCESS")
-                    || c["state"].as_str() == Some("SUCCESS")
-            })
-            .count();
-        let failed = checks
-            .iter()
-            .filter(|c| {
-                c["conclusion"].as_str() == Some("FAILURE")
-                    || c["state"].as_str() == Some("FAILURE")
-            })
-            .count();
-
+    if let Some(&CheckCounts {
+        passed,
+        failed,
+        total,
+    }) = checks.as_ref()
+    {
         if ultra_compact {
             if failed > 0 {
                 let line = format!("  ✗{}/{}  {} fail\n", passed, total, failed);
                 filtered.push_str(&line);
-                print!("{}", line);
             } else {
                 let line = format!("  ✓{}/{}\n", passed, total);
                 filtered.push_str(&line);
-                print!("{}", line);
             }
         } else {
             let line = format!("  Checks: {}/{} passed\n", passed, total);
             filtered.push_str(&line);
-            print!("{}", line);
             if failed > 0 {
-                let line = format!("  ⚠️  {} checks failed\n", failed);
+                let line = format!("  {} {} checks failed\n", Glyphs::current().warning, failed);
                 filtered.push_str(&line);
-                print!("{}", line);
             }
         }
     }
 
     let line = format!("  {}\n", url);
     filtered.push_str(&line);
-    print!("{}", line);
 
     // Show body summary (first 3 lines max)
     if let Some(body) = json["body"].as_str() {
         if !body.is_empty() {
             filtered.push('\n');
-            println!();
             for line in body.lines().take(3) {
                 if !line.trim().is_empty() {
                     let formatted = format!("  {}\n", truncate(line, 80));
                     filtered.push_str(&formatted);
-                    print!("{}", formatted);
                 }
             }
             if body.lines().count() > 3 {
                 let line = format!("  ... (gh pr view {} for full)\n", pr_number);
                 filtered.push_str(&line);
-                print!("{}", line);
             }
         }
     }
 
+    if with_comments {
+        let section = format_pr_comments(&json, PR_COMMENTS_SHOWN);
+        filtered.push('\n');
+        filtered.push_str(&section);
+    }
+
+    emit(filtered.trim_end());
     timer.track(
         &format!("gh pr view {}", pr_number),
         &format!("rtk gh pr view {}", pr_number),
@@ -302,6 +567,75 @@ fn view_pr(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
     Ok(())
 }
 
+/// Comments shown by `gh pr view --comments`
+const PR_COMMENTS_SHOWN: usize = 10;
+
+/// Items of a gh list field, whether plain (`[...]`) or GraphQL (`{nodes: [...]}`)
+fn json_items(value: &Value) -> &[Value] {
+    value
+        .as_array()
+        .or_else(|| value["nodes"].as_array())
+        .map(|v| v.as_slice())
+        .unwrap_or_default()
+}
+
+/// `@author: first line` for PR conversation comments, review bodies and
+/// review comments (`@author path:line: first line`), oldest first; only the
+/// last `max` are kept
+fn format_pr_comments(json: &Value, max: usize) -> String {
+    let mut entries: Vec<(String, String)> = Vec::new();
+    let mut push = |item: &Value, location: Option<String>, date_key: &str| {
+        let Some(first_line) = item["body"]
+            .as_str()
+            .and_then(|b| b.lines().map(str::trim).find(|l| !l.is_empty()))
+        else {
+            return;
+        };
+        let author = item["author"]["login"].as_str().unwrap_or("???");
+        let prefix = match location {
+            Some(loc) => format!("@{} {}", author, loc),
+            None => format!("@{}", author),
+        };
+        let date = item[date_key].as_str().unwrap_or("").to_string();
+        entries.push((date, format!("  {}: {}", prefix, truncate(first_line, 80))));
+    };
+
+    for comment in json_items(&json["comments"]) {
+        push(comment, None, "createdAt");
+    }
+    for review in json_items(&json["reviews"]) {
+        push(review, None, "submittedAt");
+        for comment in json_items(&review["comments"]) {
+            let location = comment["path"].as_str().map(|path| {
+                match comment["line"]
+                    .as_u64()
+                    .or(comment["originalLine"].as_u64())
+                {
+                    Some(line) => format!("{}:{}", path, line),
+                    None => path.to_string(),
+                }
+            });
+            push(comment, location, "createdAt");
+        }
+    }
+
+    // ISO 8601 timestamps sort chronologically as strings
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut result = format!("  Comments ({}):\n", entries.len());
+    if entries.is_empty() {
+        result.push_str("  (none)\n");
+    }
+    if entries.len() > max {
+        result.push_str(&format!("  ... {} earlier\n", entries.len() - max));
+    }
+    for (_, line) in entries.iter().skip(entries.len().saturating_sub(max)) {
+        result.push_str(line);
+        result.push('\n');
+    }
+    result
+}
+
 fn pr_checks(args: &[String], _verbose: u8, _ultra_compact: bool) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
@@ -325,8 +659,7 @@ fn pr_checks(args: &[String], _verbose: u8, _ultra_compact: bool) -> Result<()>
             &stderr,
             &stderr,
         );
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_error("pr checks", output.status.code(), &stderr).into());
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
@@ -348,44 +681,43 @@ fn pr_checks(args: &[String], _verbose: u8, _ultra_compact: bool) -> Result<()>
         }
     }
 
-    let mut filtered = String::new();
-
-    let line = "🔍 CI Checks Summary:\n";
-    filtered.push_str(line);
-    print!("{}", line);
+    let filtered =
+        format_checks_summary(passed, failed, pending, &failed_checks, Glyphs::current());
 
-    let line = format!("  ✅ Passed: {}\n", passed);
-    filtered.push_str(&line);
-    print!("{}", line);
+    emit(filtered.trim_end());
+    timer.track(
+        &format!("gh pr checks {}", pr_number),
+        &format!("rtk gh pr checks {}", pr_number),
+        &raw,
+        &filtered,
+    );
+    Ok(())
+}
 
-    let line = format!("  ❌ Failed: {}\n", failed);
-    filtered.push_str(&line);
-    print!("{}", line);
+/// Pass/fail/pending counts for `gh pr checks`, then the failing checks
+fn format_checks_summary(
+    passed: usize,
+    failed: usize,
+    pending: usize,
+    failed_checks: &[String],
+    glyphs: &Glyphs,
+) -> String {
+    let mut filtered = String::new();
 
+    filtered.push_str("CI Checks Summary:\n");
+    filtered.push_str(&format!("  {} Passed: {}\n", glyphs.success, passed));
+    filtered.push_str(&format!("  {} Failed: {}\n", glyphs.failure, failed));
     if pending > 0 {
-        let line = format!("  ⏳ Pending: {}\n", pending);
-        filtered.push_str(&line);
-        print!("{}", line);
+        filtered.push_str(&format!("  {} Pending: {}\n", glyphs.pending, pending));
     }
 
     if !failed_checks.is_empty() {
-        let line = "\n  Failed checks:\n";
-        filtered.push_str(line);
-        print!("{}", line);
+        filtered.push_str("\n  Failed checks:\n");
         for check in failed_checks {
-            let line = format!("    {}\n", check);
-            filtered.push_str(&line);
-            print!("{}", line);
+            filtered.push_str(&format!("    {}\n", check));
         }
     }
-
-    timer.track(
-        &format!("gh pr checks {}", pr_number),
-        &format!("rtk gh pr checks {}", pr_number),
-        &raw,
-        &filtered,
-    );
-    Ok(())
+    filtered
 }
 
 fn pr_status(_verbose: u8, _ultra_compact: bool) -> Result<()> {
@@ -405,8 +737,7 @@ fn pr_status(_verbose: u8, _ultra_compact: bool) -> Result<()> {
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         timer.track("gh pr status", "rtk gh pr status", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_error("pr status", output.status.code(), &stderr).into());
     }
 
     let json: Value =
@@ -417,38 +748,57 @@ fn pr_status(_verbose: u8, _ultra_compact: bool) -> Result<()> {
     if let Some(created_by) = json["createdBy"].as_array() {
         let line = format!("📝 Your PRs ({}):\n", created_by.len());
         filtered.push_str(&line);
-        print!("{}", line);
         for pr in created_by.iter().take(5) {
             let number = pr["number"].as_i64().unwrap_or(0);
             let title = pr["title"].as_str().unwrap_or("???");
             let reviews = pr["reviewDecision"].as_str().unwrap_or("PENDING");
             let line = format!("  #{} {} [{}]\n", number, truncate(title, 50), reviews);
             filtered.push_str(&line);
-            print!("{}", line);
         }
     }
 
+    emit(filtered.trim_end());
     timer.track("gh pr status", "rtk gh pr status", &raw, &filtered);
     Ok(())
 }
 
-fn run_issue(args: &[String], verbose: u8, ultra_compact: bool) -> Result<()> {
+fn run_issue(
+    args: &[String],
+    limit: Option<usize>,
+    verbose: u8,
+    ultra_compact: bool,
+) -> Result<()> {
     if args.is_empty() {
         return run_passthrough("gh", "issue", args);
     }
 
     match args[0].as_str() {
-        "list" => list_issues(&args[1..], verbose, ultra_compact),
+        "list" => list_issues(&args[1..], limit, verbose, ultra_compact),
         "view" => view_issue(&args[1..], verbose),
+        "create" => issue_create(&args[1..], verbose),
         _ => run_passthrough("gh", "issue", args),
     }
 }
 
-fn list_issues(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
+fn list_issues(
+    args: &[String],
+    limit: Option<usize>,
+    _verbose: u8,
+    _ultra_compact: bool,
+) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    if let Some(cached) = cache::lookup("gh issue list") {
+        emit(cached.trim_end());
+        return Ok(());
+    }
+
     let mut cmd = Command::new("gh");
     cmd.args(["issue", "list", "--json", "number,title,state,author"]);
+    if let Some(n) = limit {
+        cmd.arg("--limit").arg(n.to_string());
+    }
+    let shown = limit.unwrap_or(DEFAULT_LIST_LIMIT);
 
     for arg in args {
         cmd.arg(arg);
@@ -460,8 +810,7 @@ fn list_issues(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()>
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         timer.track("gh issue list", "rtk gh issue list", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_error("issue list", output.status.code(), &stderr).into());
     }
 
     let json: Value =
@@ -470,43 +819,34 @@ fn list_issues(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()>
     let mut filtered = String::new();
 
     if let Some(issues) = json.as_array() {
-        if ultra_compact {
+        if ascii_mode() {
             filtered.push_str("Issues\n");
-            println!("Issues");
         } else {
             filtered.push_str("🐛 Issues\n");
-            println!("🐛 Issues");
         }
-        for issue in issues.iter().take(20) {
+        for issue in issues.iter().take(shown) {
             let number = issue["number"].as_i64().unwrap_or(0);
             let title = issue["title"].as_str().unwrap_or("???");
             let state = issue["state"].as_str().unwrap_or("???");
 
-            let icon = if ultra_compact {
-                if state == "OPEN" {
-                    "O"
-                } else {
-                    "C"
-                }
+            let glyphs = Glyphs::current();
+            let icon = if state == "OPEN" {
+                glyphs.open
             } else {
-                if state == "OPEN" {
-                    "🟢"
-                } else {
-                    "🔴"
-                }
+                glyphs.closed
             };
             let line = format!("  {} #{} {}\n", icon, number, truncate(title, 60));
             filtered.push_str(&line);
-            print!("{}", line);
         }
 
-        if issues.len() > 20 {
-            let line = format!("  ... {} more\n", issues.len() - 20);
+        if issues.len() > shown {
+            let line = format!("  ... {} more\n", issues.len() - shown);
             filtered.push_str(&line);
-            print!("{}", line);
         }
     }
 
+    emit(filtered.trim_end());
+    cache::store("gh issue list", &filtered);
     timer.track("gh issue list", "rtk gh issue list", &raw, &filtered);
     Ok(())
 }
@@ -514,6 +854,7 @@ fn list_issues(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()>
 fn view_issue(args: &[String], _verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    let (json_summary, args) = extract_json_summary_flag(args);
     if args.is_empty() {
         return Err(anyhow::anyhow!("Issue number required"));
     }
@@ -540,78 +881,100 @@ fn view_issue(args: &[String], _verbose: u8) -> Result<()> {
             &stderr,
             &stderr,
         );
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_error("issue view", output.status.code(), &stderr).into());
     }
 
     let json: Value =
         serde_json::from_slice(&output.stdout).context("Failed to parse gh issue view output")?;
 
-    let number = json["number"].as_i64().unwrap_or(0);
-    let title = json["title"].as_str().unwrap_or("???");
-    let state = json["state"].as_str().unwrap_or("???");
-    let author = json["author"]["login"].as_str().unwrap_or("???");
-    let url = json["url"].as_str().unwrap_or("");
+    let summary = issue_summary(&json);
+
+    if json_summary {
+        let filtered = serde_json::to_string(&summary)?;
+        emit_json(&filtered);
+        timer.track(
+            &format!("gh issue view {}", issue_number),
+            &format!("rtk gh issue view {} --json-summary", issue_number),
+            &raw,
+            &filtered,
+        );
+        return Ok(());
+    }
+
+    let filtered = format_issue_view(&summary, json["body"].as_str(), Glyphs::current());
 
-    let icon = if state == "OPEN" { "🟢" } else { "🔴" };
+    emit(filtered.trim_end());
+    timer.track(
+        &format!("gh issue view {}", issue_number),
+        &format!("rtk gh issue view {}", issue_number),
+        &raw,
+        &filtered,
+    );
+    Ok(())
+}
 
+/// Issue header, author/status/URL and the first lines of the description
+fn format_issue_view(summary: &IssueSummary, body: Option<&str>, glyphs: &Glyphs) -> String {
     let mut filtered = String::new();
 
-    let line = format!("{} Issue #{}: {}\n", icon, number, title);
+    let icon = pr_state_glyph(&summary.state, glyphs);
+    let line = format!("{} Issue #{}: {}\n", icon, summary.number, summary.title);
     filtered.push_str(&line);
-    print!("{}", line);
 
-    let line = format!("  Author: @{}\n", author);
+    let line = format!("  Author: @{}\n", summary.author);
     filtered.push_str(&line);
-    print!("{}", line);
 
-    let line = format!("  Status: {}\n", state);
+    let line = format!("  Status: {}\n", summary.state);
     filtered.push_str(&line);
-    print!("{}", line);
 
-    let line = format!("  URL: {}\n", url);
+    let line = format!("  URL: {}\n", summary.url);
     filtered.push_str(&line);
-    print!("{}", line);
 
-    if let Some(body) = json["body"].as_str() {
+    if let Some(body) = body {
         if !body.is_empty() {
             let line = "\n  Description:\n";
             filtered.push_str(line);
-            print!("{}", line);
             for line in body.lines().take(3) {
                 if !line.trim().is_empty() {
                     let formatted = format!("    {}\n", truncate(line, 80));
                     filtered.push_str(&formatted);
-                    print!("{}", formatted);
                 }
             }
         }
     }
-
-    timer.track(
-        &format!("gh issue view {}", issue_number),
-        &format!("rtk gh issue view {}", issue_number),
-        &raw,
-        &filtered,
-    );
-    Ok(())
+    filtered
 }
 
-fn run_workflow(args: &[String], verbose: u8, ultra_compact: bool) -> Result<()> {
+fn run_workflow(
+    args: &[String],
+    limit: Option<usize>,
+    verbose: u8,
+    ultra_compact: bool,
+) -> Result<()> {
     if args.is_empty() {
         return run_passthrough("gh", "run", args);
     }
 
     match args[0].as_str() {
-        "list" => list_runs(&args[1..], verbose, ultra_compact),
+        "list" => list_runs(&args[1..], limit, verbose, ultra_compact),
         "view" => view_run(&args[1..], verbose),
         _ => run_passthrough("gh", "run", args),
     }
 }
 
-fn list_runs(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
+fn list_runs(
+    args: &[String],
+    limit: Option<usize>,
+    _verbose: u8,
+    _ultra_compact: bool,
+) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    if let Some(cached) = cache::lookup("gh run list") {
+        emit(cached.trim_end());
+        return Ok(());
+    }
+
     let mut cmd = Command::new("gh");
     cmd.args([
         "run",
@@ -619,7 +982,8 @@ fn list_runs(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
         "--json",
         "databaseId,name,status,conclusion,createdAt",
     ]);
-    cmd.arg("--limit").arg("10");
+    let limit = limit.unwrap_or(DEFAULT_RUN_LIMIT);
+    cmd.arg("--limit").arg(limit.to_string());
 
     for arg in args {
         cmd.arg(arg);
@@ -631,8 +995,7 @@ fn list_runs(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         timer.track("gh run list", "rtk gh run list", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_error("run list", output.status.code(), &stderr).into());
     }
 
     let json: Value =
@@ -641,57 +1004,152 @@ fn list_runs(args: &[String], _verbose: u8, ultra_compact: bool) -> Result<()> {
     let mut filtered = String::new();
 
     if let Some(runs) = json.as_array() {
-        if ultra_compact {
+        if ascii_mode() {
             filtered.push_str("Runs\n");
-            println!("Runs");
         } else {
             filtered.push_str("🏃 Workflow Runs\n");
-            println!("🏃 Workflow Runs");
         }
-        for run in runs {
+        for run in runs.iter().take(limit) {
             let id = run["databaseId"].as_i64().unwrap_or(0);
             let name = run["name"].as_str().unwrap_or("???");
             let status = run["status"].as_str().unwrap_or("???");
             let conclusion = run["conclusion"].as_str().unwrap_or("");
 
-            let icon = if ultra_compact {
-                match conclusion {
-                    "success" => "✓",
-                    "failure" => "✗",
-                    "cancelled" => "X",
-                    _ => {
-                        if status == "in_progress" {
-                            "~"
-                        } else {
-                            "?"
-                        }
-                    }
-                }
-            } else {
-                match conclusion {
-                    "success" => "✅",
-                    "failure" => "❌",
-                    "cancelled" => "🚫",
-                    _ => {
-                        if status == "in_progress" {
-                            "⏳"
-                        } else {
-                            "⚪"
-                        }
-                    }
-                }
-            };
+            let icon = run_status_glyph(status, conclusion, Glyphs::current());
 
             let line = format!("  {} {} [{}]\n", icon, truncate(name, 50), id);
             filtered.push_str(&line);
-            print!("{}", line);
         }
     }
 
+    emit(filtered.trim_end());
+    cache::store("gh run list", &filtered);
     timer.track("gh run list", "rtk gh run list", &raw, &filtered);
     Ok(())
 }
 
+/// PR state → glyph (open/merged/closed)
+fn pr_state_glyph(state: &str, glyphs: &Glyphs) -> &'static str {
+    match state {
+        "OPEN" => glyphs.open,
+        "MERGED" => glyphs.merged,
+        "CLOSED" => glyphs.closed,
+        _ => glyphs.unknown,
+    }
+}
+
+/// Workflow run status/conclusion → glyph
+fn run_status_glyph(status: &str, conclusion: &str, glyphs: &Glyphs) -> &'static str {
+    match conclusion {
+        "success" => glyphs.success,
+        "failure" => glyphs.failure,
+        "cancelled" => glyphs.cancelled,
+        _ if status == "in_progress" => glyphs.pending,
+        _ => glyphs.unknown,
+    }
+}
+
+fn run_workflow_defs(args: &[String], verbose: u8) -> Result<()> {
+    if args.is_empty() {
+        return run_passthrough("gh", "workflow", args);
+    }
+
+    match args[0].as_str() {
+        "list" => list_workflows(&args[1..], verbose),
+        "run" => workflow_dispatch(&args[1..], verbose),
+        _ => run_passthrough("gh", "workflow", args),
+    }
+}
+
+const WORKFLOW_LIST_LIMIT: usize = 20;
+
+fn list_workflows(args: &[String], _verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = Command::new("gh");
+    cmd.args(["workflow", "list", "--json", "name,state,id"]);
+    for arg in args {
+        cmd.arg(arg);
+    }
+
+    let output = cmd.output().context("Failed to run gh workflow list")?;
+    let raw = String::from_utf8_lossy(&output.stdout).to_string();
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        timer.track("gh workflow list", "rtk gh workflow list", &stderr, &stderr);
+        return Err(gh_error("workflow list", output.status.code(), &stderr).into());
+    }
+
+    let json: Value = serde_json::from_slice(&output.stdout)
+        .context("Failed to parse gh workflow list output")?;
+
+    let filtered = format_workflow_list(&json, WORKFLOW_LIST_LIMIT);
+    emit(&filtered);
+
+    timer.track("gh workflow list", "rtk gh workflow list", &raw, &filtered);
+    Ok(())
+}
+
+/// Format `gh workflow list --json name,state,id` as "<name> [<state>] (id <id>)"
+fn format_workflow_list(json: &Value, limit: usize) -> String {
+    let workflows = match json.as_array() {
+        Some(w) if !w.is_empty() => w,
+        _ => return "No workflows".to_string(),
+    };
+
+    let mut lines: Vec<String> = workflows
+        .iter()
+        .take(limit)
+        .map(|wf| {
+            let name = wf["name"].as_str().unwrap_or("???");
+            let state = wf["state"].as_str().unwrap_or("unknown");
+            let id = wf["id"].as_i64().unwrap_or(0);
+            format!("{} [{}] (id {})", truncate(name, 50), state, id)
+        })
+        .collect();
+
+    if workflows.len() > limit {
+        lines.push(format!("... +{} more", workflows.len() - limit));
+    }
+
+    lines.join("\n")
+}
+
+fn workflow_dispatch(args: &[String], _verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = Command::new("gh");
+    cmd.args(["workflow", "run"]);
+    for arg in args {
+        cmd.arg(arg);
+    }
+
+    let output = cmd.output().context("Failed to run gh workflow run")?;
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+    if !output.status.success() {
+        timer.track("gh workflow run", "rtk gh workflow run", &stderr, &stderr);
+        return Err(gh_error("workflow run", output.status.code(), &stderr).into());
+    }
+
+    // Workflow name/id/file is the first non-flag arg
+    let workflow = args
+        .iter()
+        .find(|a| !a.starts_with('-'))
+        .map(|s| s.as_str())
+        .unwrap_or("");
+
+    let filtered = ok_confirmation("✓ dispatched", workflow);
+    emit(&filtered);
+
+    // gh prints the dispatch notice (and run URL hint) on stderr
+    let raw = format!("{}{}", stdout, stderr);
+    timer.track("gh workflow run", "rtk gh workflow run", &raw, &filtered);
+    Ok(())
+}
+
 fn view_run(args: &[String], _verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
@@ -701,6 +1159,10 @@ fn view_run(args: &[String], _verbose: u8) -> Result<()> {
 
     let run_id = &args[0];
 
+    if args.iter().any(|a| a == "--log-failed") {
+        return view_run_log_failed(run_id);
+    }
+
     let mut cmd = Command::new("gh");
     cmd.args(["run", "view", run_id]);
 
@@ -715,8 +1177,7 @@ fn view_run(args: &[String], _verbose: u8) -> Result<()> {
             &stderr,
             &stderr,
         );
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_error("run view", output.status.code(), &stderr).into());
     }
 
     // Parse output and show only failures
@@ -725,9 +1186,8 @@ fn view_run(args: &[String], _verbose: u8) -> Result<()> {
 
     let mut filtered = String::new();
 
-    let line = format!("🏃 Workflow Run #{}\n", run_id);
-    filtered.push_str(&line);
-    print!("{}", line);
+    let glyphs = Glyphs::current();
+    filtered.push_str(&run_header(run_id));
 
     for line in stdout.lines() {
         if line.contains("JOBS") {
@@ -740,17 +1200,16 @@ fn view_run(args: &[String], _verbose: u8) -> Result<()> {
                 continue;
             }
             if line.contains('✗') || line.contains("fail") {
-                let formatted = format!("  ❌ {}\n", line.trim());
+                let formatted = format!("  {} {}\n", glyphs.failure, line.trim());
                 filtered.push_str(&formatted);
-                print!("{}", formatted);
             }
         } else if line.contains("Status:") || line.contains("Conclusion:") {
             let formatted = format!("  {}\n", line.trim());
             filtered.push_str(&formatted);
-            print!("{}", formatted);
         }
     }
 
+    emit(filtered.trim_end());
     timer.track(
         &format!("gh run view {}", run_id),
         &format!("rtk gh run view {}", run_id),
@@ -760,6 +1219,118 @@ fn view_run(args: &[String], _verbose: u8) -> Result<()> {
     Ok(())
 }
 
+/// Error lines kept from `gh run view --log-failed`
+const LOG_FAILED_BUDGET: usize = 40;
+
+/// `gh run view <id> --log-failed`: only the error lines of each failed step
+fn view_run_log_failed(run_id: &str) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = Command::new("gh");
+    cmd.args(["run", "view", run_id, "--log-failed"]);
+
+    let output = cmd
+        .output()
+        .context("Failed to run gh run view --log-failed")?;
+    let raw = String::from_utf8_lossy(&output.stdout).to_string();
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(gh_error("run view", output.status.code(), &stderr).into());
+    }
+
+    let filtered = format!(
+        "{}{}",
+        run_header(run_id),
+        extract_failed_log_lines(&raw, LOG_FAILED_BUDGET, Glyphs::current())
+    );
+    emit(&filtered);
+
+    timer.track(
+        &format!("gh run view {} --log-failed", run_id),
+        &format!("rtk gh run view {} --log-failed", run_id),
+        &raw,
+        &filtered,
+    );
+    Ok(())
+}
+
+/// First line of `gh run view` output, "🏃 Workflow Run #<id>"
+fn run_header(run_id: &str) -> String {
+    if ascii_mode() {
+        format!("Run #{}\n", run_id)
+    } else {
+        format!("🏃 Workflow Run #{}\n", run_id)
+    }
+}
+
+/// Group "job\tstep\t<timestamp> message" log lines by job/step and keep
+/// only the ones that explain the failure, at most `budget` in total
+fn extract_failed_log_lines(log: &str, budget: usize, glyphs: &Glyphs) -> String {
+    let mut result = String::new();
+    let mut current_step = String::new();
+    let mut last_line = "";
+    let mut kept = 0;
+    let mut dropped = 0;
+
+    for line in log.lines() {
+        let mut parts = line.splitn(3, '\t');
+        let (job, step, message) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(job), Some(step), Some(message)) => (job, step, message),
+            _ => ("", "", line),
+        };
+        let message = strip_log_timestamp(message);
+        let message = message.strip_prefix("##[error]").unwrap_or(message).trim();
+
+        if !is_log_error_line(message) || message == last_line {
+            continue;
+        }
+        last_line = message;
+
+        if kept == budget {
+            dropped += 1;
+            continue;
+        }
+        kept += 1;
+
+        let step_label = format!("{} {} {}", job, glyphs.arrow, step);
+        if step_label != current_step && !job.is_empty() {
+            result.push_str(&format!("  {} {}\n", glyphs.failure, step_label));
+            current_step = step_label;
+        }
+        result.push_str(&format!("    {}\n", truncate(message, 160)));
+    }
+
+    if kept == 0 {
+        result.push_str("  (no error lines found in failed step logs)\n");
+    }
+    if dropped > 0 {
+        result.push_str(&format!("  ... +{} more error lines\n", dropped));
+    }
+    result
+}
+
+/// Drop the "2024-05-01T10:00:00.1234567Z " prefix Actions puts on log lines
+fn strip_log_timestamp(message: &str) -> &str {
+    match message.split_once(' ') {
+        Some((ts, rest)) if ts.len() >= 20 && ts.ends_with('Z') && ts.contains('T') => rest,
+        _ => message,
+    }
+}
+
+fn is_log_error_line(message: &str) -> bool {
+    let lower = message.to_lowercase();
+    if let Some(rest) = lower.split("exit code ").nth(1) {
+        let code: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+        return code.parse::<i32>().is_ok_and(|c| c != 0);
+    }
+    lower.contains("error")
+        || lower.contains("assert")
+        || lower.contains("panicked")
+        || lower.contains("failed")
+        || lower.starts_with("fail")
+}
+
 fn run_repo(args: &[String], _verbose: u8, _ultra_compact: bool) -> Result<()> {
     // Parse subcommand (default to "view")
     let (subcommand, rest_args) = if args.is_empty() {
@@ -792,8 +1363,7 @@ fn run_repo(args: &[String], _verbose: u8, _ultra_compact: bool) -> Result<()> {
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         timer.track("gh repo view", "rtk gh repo view", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_error("repo view", output.status.code(), &stderr).into());
     }
 
     let json: Value =
@@ -817,26 +1387,22 @@ fn run_repo(args: &[String], _verbose: u8, _ultra_compact: bool) -> Result<()> {
 
     let line = format!("📦 {}/{}\n", owner, name);
     filtered.push_str(&line);
-    print!("{}", line);
 
     let line = format!("  {}\n", visibility);
     filtered.push_str(&line);
-    print!("{}", line);
 
     if !description.is_empty() {
         let line = format!("  {}\n", truncate(description, 80));
         filtered.push_str(&line);
-        print!("{}", line);
     }
 
     let line = format!("  ⭐ {} stars | 🔱 {} forks\n", stars, forks);
     filtered.push_str(&line);
-    print!("{}", line);
 
     let line = format!("  {}\n", url);
     filtered.push_str(&line);
-    print!("{}", line);
 
+    emit(filtered.trim_end());
     timer.track("gh repo view", "rtk gh repo view", &raw, &filtered);
     Ok(())
 }
@@ -855,30 +1421,193 @@ fn pr_create(args: &[String], _verbose: u8) -> Result<()> {
     let stderr = String::from_utf8_lossy(&output.stderr).to_string();
 
     if !output.status.success() {
-        timer.track("gh pr create", "rtk gh pr create", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        let err = match rate_limit_message(&stderr) {
+            Some(message) => {
+                CommandError::with_excerpt("gh", "pr create", Some(EX_TEMPFAIL), message)
+            }
+            None => CommandError::with_excerpt(
+                "gh",
+                "pr create",
+                output.status.code(),
+                pr_create_error(&stderr),
+            ),
+        };
+        timer.track(
+            "gh pr create",
+            "rtk gh pr create",
+            &stderr,
+            &err.to_string(),
+        );
+        return Err(err.into());
     }
 
-    // gh pr create outputs the URL on success
-    let url = stdout.trim();
+    // gh pr create prints the new PR's URL as its last stdout line
+    let url = stdout
+        .lines()
+        .rev()
+        .map(|l| l.trim())
+        .find(|l| l.starts_with("http"))
+        .unwrap_or_else(|| stdout.trim());
+
+    let filtered = match extract_issue_or_pr_number(url) {
+        Some(number) => format!("ok ✓ PR #{} created: {}", number, url),
+        None => ok_confirmation("created", url),
+    };
+    emit(&filtered);
 
-    // Try to extract PR number from URL (e.g., https://github.com/owner/repo/pull/42)
-    let pr_num = url.rsplit('/').next().unwrap_or("");
+    timer.track("gh pr create", "rtk gh pr create", &stdout, &filtered);
+    Ok(())
+}
+
+/// Number from a URL like https://github.com/owner/repo/pull/42 or
+/// https://github.com/owner/repo/issues/42
+fn extract_issue_or_pr_number(url: &str) -> Option<u64> {
+    let (_, rest) = url
+        .split_once("/pull/")
+        .or_else(|| url.split_once("/issues/"))?;
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    digits.parse().ok()
+}
 
-    let detail = if !pr_num.is_empty() && pr_num.chars().all(|c| c.is_ascii_digit()) {
-        format!("#{} {}", pr_num, url)
+/// One-line explanation for the common `gh pr create` failures
+fn pr_create_error(stderr: &str) -> String {
+    let stderr = stderr.trim();
+    if stderr.contains("No commits between") {
+        "no commits between base and head branch".to_string()
+    } else if stderr.contains("must first push the current branch")
+        || stderr.contains("no upstream")
+    {
+        "branch has no upstream (git push -u origin <branch>)".to_string()
+    } else if let Some(url) = stderr
+        .lines()
+        .rev()
+        .find(|l| l.trim().starts_with("http"))
+        .filter(|_| stderr.contains("already exists"))
+    {
+        format!("PR already exists: {}", url.trim())
     } else {
-        url.to_string()
-    };
+        stderr_excerpt(stderr)
+    }
+}
 
-    let filtered = ok_confirmation("created", &detail);
-    println!("{}", filtered);
+fn issue_create(args: &[String], _verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
 
-    timer.track("gh pr create", "rtk gh pr create", &stdout, &filtered);
+    let output = Command::new("gh")
+        .args(["issue", "create"])
+        .args(args)
+        .output()
+        .context("Failed to run gh issue create")?;
+    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+    if !output.status.success() {
+        let err = match rate_limit_message(&stderr) {
+            Some(message) => {
+                CommandError::with_excerpt("gh", "issue create", Some(EX_TEMPFAIL), message)
+            }
+            None => CommandError::with_excerpt(
+                "gh",
+                "issue create",
+                output.status.code(),
+                issue_create_error(&stderr),
+            ),
+        };
+        timer.track(
+            "gh issue create",
+            "rtk gh issue create",
+            &stderr,
+            &err.to_string(),
+        );
+        return Err(err.into());
+    }
+
+    // Like pr create, the new issue's URL is the last stdout line
+    let url = stdout
+        .lines()
+        .rev()
+        .map(|l| l.trim())
+        .find(|l| l.starts_with("http"))
+        .unwrap_or_else(|| stdout.trim());
+
+    let filtered = match extract_issue_or_pr_number(url) {
+        Some(number) => format!("ok ✓ issue #{}: {}", number, url),
+        None => ok_confirmation("created", url),
+    };
+    emit(&filtered);
+
+    timer.track("gh issue create", "rtk gh issue create", &stdout, &filtered);
     Ok(())
 }
 
+/// Exit code for a rate-limited call (EX_TEMPFAIL), so automation can back off
+const EX_TEMPFAIL: i32 = 75;
+
+/// Error for a failed gh call: rate limits become one `⏳` line with exit
+/// code [`EX_TEMPFAIL`], anything else keeps gh's code and stderr excerpt
+fn gh_error(subcommand: &str, code: Option<i32>, stderr: &str) -> CommandError {
+    gh_output_error(subcommand, code, "", stderr)
+}
+
+/// [`gh_error`] for calls whose stdout may carry the rate-limit headers
+/// (`gh api -i` prints them there); the excerpt still comes from stderr
+fn gh_output_error(
+    subcommand: &str,
+    code: Option<i32>,
+    stdout: &str,
+    stderr: &str,
+) -> CommandError {
+    match rate_limit_message(&format!("{}\n{}", stderr, stdout)) {
+        Some(message) => CommandError::with_excerpt("gh", subcommand, Some(EX_TEMPFAIL), message),
+        None => CommandError::new("gh", subcommand, code, stderr),
+    }
+}
+
+/// "⏳ GitHub rate limit hit, resets at 14:05 UTC" when gh reports a primary
+/// or secondary rate limit; the reset time comes from an `X-Ratelimit-Reset`
+/// header (`gh api -i`) or a "reset(s) at ..." phrase when present
+fn rate_limit_message(stderr: &str) -> Option<String> {
+    let lower = stderr.to_lowercase();
+    if !lower.contains("rate limit") {
+        return None;
+    }
+    let glyph = Glyphs::current().pending;
+
+    let from_header = lower.lines().find_map(|line| {
+        let epoch: i64 = line
+            .trim()
+            .strip_prefix("x-ratelimit-reset:")?
+            .trim()
+            .parse()
+            .ok()?;
+        let reset = chrono::DateTime::from_timestamp(epoch, 0)?;
+        Some(reset.format("%H:%M UTC").to_string())
+    });
+    let from_text = || {
+        let (_, rest) = stderr
+            .split_once("resets at ")
+            .or_else(|| stderr.split_once("reset at "))?;
+        let end = rest.find(['\n', '(']).unwrap_or(rest.len());
+        Some(rest[..end].trim().trim_end_matches('.').to_string()).filter(|t| !t.is_empty())
+    };
+
+    Some(match from_header.or_else(from_text) {
+        Some(reset) => format!("{} GitHub rate limit hit, resets at {}", glyph, reset),
+        None => format!("{} GitHub rate limit hit, try again later", glyph),
+    })
+}
+
+/// One-line explanation for `gh issue create` failures: an unknown label or
+/// assignee is reported by the line that names it
+fn issue_create_error(stderr: &str) -> String {
+    stderr
+        .lines()
+        .map(|l| l.trim())
+        .find(|l| l.contains("could not add label") || l.contains("could not assign"))
+        .map(|l| l.to_string())
+        .unwrap_or_else(|| stderr_excerpt(stderr.trim()))
+}
+
 fn pr_merge(args: &[String], _verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
@@ -894,8 +1623,7 @@ fn pr_merge(args: &[String], _verbose: u8) -> Result<()> {
 
     if !output.status.success() {
         timer.track("gh pr merge", "rtk gh pr merge", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_error("pr merge", output.status.code(), &stderr).into());
     }
 
     // Extract PR number from args (first non-flag arg)
@@ -912,7 +1640,7 @@ fn pr_merge(args: &[String], _verbose: u8) -> Result<()> {
     };
 
     let filtered = ok_confirmation("merged", &detail);
-    println!("{}", filtered);
+    emit(&filtered);
 
     // Use stdout or detail as raw input (gh pr merge doesn't output much)
     let raw = if !stdout.trim().is_empty() {
@@ -940,17 +1668,16 @@ fn pr_diff(args: &[String], _verbose: u8) -> Result<()> {
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         timer.track("gh pr diff", "rtk gh pr diff", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_error("pr diff", output.status.code(), &stderr).into());
     }
 
     let filtered = if raw.trim().is_empty() {
         let msg = "No diff\n";
-        print!("{}", msg);
+        emit(msg.trim_end());
         msg.to_string()
     } else {
         let compacted = git::compact_diff(&raw, 100);
-        println!("{}", compacted);
+        git::emit_diff(&compacted);
         compacted
     };
 
@@ -981,8 +1708,7 @@ fn pr_action(action: &str, args: &[String], _verbose: u8) -> Result<()> {
             &stderr,
             &stderr,
         );
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_error(&format!("pr {}", action), output.status.code(), &stderr).into());
     }
 
     // Extract PR number from args
@@ -993,7 +1719,7 @@ fn pr_action(action: &str, args: &[String], _verbose: u8) -> Result<()> {
         .unwrap_or_default();
 
     let filtered = ok_confirmation(action, &pr_num);
-    println!("{}", filtered);
+    emit(&filtered);
 
     // Use stdout or pr_num as raw input
     let raw = if !stdout.trim().is_empty() {
@@ -1012,50 +1738,139 @@ fn pr_action(action: &str, args: &[String], _verbose: u8) -> Result<()> {
 }
 
 fn run_api(args: &[String], _verbose: u8) -> Result<()> {
+    run_api_with(&SystemRunner, args)
+}
+
+fn run_api_with(runner: &impl CommandRunner, args: &[String]) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    let (fields, args) = extract_fields_arg(args);
+
     let mut cmd = Command::new("gh");
     cmd.arg("api");
-    for arg in args {
+    for arg in &args {
         cmd.arg(arg);
     }
 
-    let output = cmd.output().context("Failed to run gh api")?;
+    let output = runner.output(&mut cmd).context("Failed to run gh api")?;
     let raw = String::from_utf8_lossy(&output.stdout).to_string();
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr).to_string();
         timer.track("gh api", "rtk gh api", &stderr, &stderr);
-        eprintln!("{}", stderr.trim());
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(gh_output_error("api", output.status.code(), &raw, &stderr).into());
     }
 
-    // Try to parse as JSON and filter
-    let filtered = match json_cmd::filter_json_string(&raw, 5) {
-        Ok(schema) => {
-            println!("{}", schema);
-            schema
-        }
-        Err(_) => {
-            // Not JSON, print truncated raw output
-            let mut result = String::new();
-            let lines: Vec<&str> = raw.lines().take(20).collect();
-            let joined = lines.join("\n");
-            result.push_str(&joined);
-            print!("{}", joined);
-            if raw.lines().count() > 20 {
-                result.push_str("\n... (truncated)");
-                println!("\n... (truncated)");
-            }
-            result
+    // --fields: project the requested fields instead of the schema
+    if let Some(fields) = fields {
+        let json: Value =
+            serde_json::from_str(&raw).context("Failed to parse gh api output as JSON")?;
+        let filtered = project_fields(&json, &fields);
+        emit(&filtered);
+        timer.track("gh api", "rtk gh api --fields", &raw, &filtered);
+        return Ok(());
+    }
+
+    // No --fields: the response as gh printed it
+    emit(raw.trim_end());
+    timer.track_passthrough("gh api", "rtk gh api (passthrough)");
+    Ok(())
+}
+
+/// Split rtk's `--fields a,b,c` / `--fields=a,b,c` out of `gh api` / `gh pr list` args
+fn extract_fields_arg(args: &[String]) -> (Option<Vec<String>>, Vec<String>) {
+    let mut fields = None;
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = if arg == "--fields" {
+            iter.next().map(|v| v.as_str())
+        } else if let Some(v) = arg.strip_prefix("--fields=") {
+            Some(v)
+        } else {
+            rest.push(arg.clone());
+            continue;
+        };
+        if let Some(v) = value {
+            fields = Some(
+                v.split(',')
+                    .map(|f| f.trim().to_string())
+                    .filter(|f| !f.is_empty())
+                    .collect(),
+            );
         }
+    }
+    (fields, rest)
+}
+
+/// Project selected fields from a JSON value: one "a=1 b=x" row per array
+/// element (or a single row for an object). Dotted paths reach nested keys.
+fn project_fields(json: &Value, fields: &[String]) -> String {
+    let row = |item: &Value| -> String {
+        fields
+            .iter()
+            .map(|field| {
+                let value = field
+                    .split('.')
+                    .try_fold(item, |v, key| v.get(key))
+                    .unwrap_or(&Value::Null);
+                format!("{}={}", field, format_field_value(value))
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
     };
 
-    timer.track("gh api", "rtk gh api", &raw, &filtered);
-    Ok(())
+    match json {
+        Value::Array(items) if items.is_empty() => "[]".to_string(),
+        Value::Array(items) => items.iter().map(row).collect::<Vec<_>>().join("\n"),
+        other => row(other),
+    }
+}
+
+fn format_field_value(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(s) => truncate(s, 80),
+        Value::Array(items) if items.iter().all(|v| !v.is_object() && !v.is_array()) => items
+            .iter()
+            .map(format_field_value)
+            .collect::<Vec<_>>()
+            .join(","),
+        Value::Array(_) | Value::Object(_) => truncate(&value.to_string(), 80),
+        other => other.to_string(),
+    }
 }
 
 fn run_passthrough(cmd: &str, subcommand: &str, args: &[String]) -> Result<()> {
+    run_passthrough_with(
+        &SystemRunner,
+        cmd,
+        subcommand,
+        args,
+        term::stdout_is_terminal(),
+    )
+}
+
+/// Subcommands that redraw the screen or prompt on the terminal; their stdio
+/// is never captured, even when stdout is piped.
+fn is_interactive(subcommand: &str, args: &[String]) -> bool {
+    let action = args.first().map(|s| s.as_str()).unwrap_or("");
+    args.iter().any(|a| a == "--watch")
+        || matches!(
+            (subcommand, action),
+            ("run", "watch") | ("auth", "login") | ("auth", "refresh") | ("codespace", "ssh")
+        )
+}
+
+/// Forward an unfiltered subcommand. On a terminal it keeps the real stdio;
+/// when piped its stdout goes through [`emit`] so the global output caps apply.
+fn run_passthrough_with(
+    runner: &impl CommandRunner,
+    cmd: &str,
+    subcommand: &str,
+    args: &[String],
+    terminal: bool,
+) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     let mut command = Command::new(cmd);
@@ -1063,19 +1878,51 @@ fn run_passthrough(cmd: &str, subcommand: &str, args: &[String]) -> Result<()> {
     for arg in args {
         command.arg(arg);
     }
+    if !term::should_colorize() {
+        command.env("NO_COLOR", "1");
+    }
+    let args_str = tracking::args_display(&args.iter().map(|s| s.into()).collect::<Vec<_>>());
 
-    let status = command
-        .status()
+    if terminal || is_interactive(subcommand, args) {
+        let status = runner
+            .status(&mut command)
+            .context(format!("Failed to run {} {}", cmd, subcommand))?;
+        timer.track_passthrough(
+            &format!("{} {} {}", cmd, subcommand, args_str),
+            &format!("rtk {} {} {} (passthrough)", cmd, subcommand, args_str),
+        );
+        if !status.success() {
+            // gh already printed its error to the terminal
+            return Err(
+                CommandError::with_excerpt(cmd, subcommand, status.code(), String::new()).into(),
+            );
+        }
+        return Ok(());
+    }
+
+    // Prompts still read the terminal; only stdout/stderr are captured
+    command.stdin(Stdio::inherit());
+
+    let output = runner
+        .output(&mut command)
         .context(format!("Failed to run {} {}", cmd, subcommand))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    if output.status.success() {
+        eprint!("{}", stderr);
+    }
+    if !stdout.trim().is_empty() {
+        emit(stdout.trim_end());
+    }
 
-    let args_str = tracking::args_display(&args.iter().map(|s| s.into()).collect::<Vec<_>>());
     timer.track_passthrough(
         &format!("{} {} {}", cmd, subcommand, args_str),
         &format!("rtk {} {} {} (passthrough)", cmd, subcommand, args_str),
     );
 
-    if !status.success() {
-        std::process::exit(status.code().unwrap_or(1));
+    if !output.status.success() {
+        return Err(gh_output_error(subcommand, output.status.code(), &stdout, &stderr).into());
     }
 
     Ok(())
@@ -1084,6 +1931,117 @@ fn run_passthrough(cmd: &str, subcommand: &str, args: &[String]) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::exec::mock::MockRunner;
+    use crate::utils::capture;
+
+    #[test]
+    fn test_state_glyphs_ascii() {
+        let ascii = Glyphs::get(true);
+        assert_eq!(pr_state_glyph("MERGED", ascii), "[merged]");
+        assert_eq!(run_status_glyph("completed", "failure", ascii), "[fail]");
+        assert_eq!(run_status_glyph("in_progress", "", ascii), "[pending]");
+        assert_eq!(pr_state_glyph("OPEN", Glyphs::get(false)), "🟢");
+    }
+
+    #[test]
+    fn test_checks_and_issue_views_follow_glyphs() {
+        let failed = vec!["lint\tfail\t12s".to_string()];
+        let ascii = format_checks_summary(3, 1, 2, &failed, Glyphs::get(true));
+        assert!(ascii.is_ascii());
+        assert!(ascii.contains("  [ok] Passed: 3\n"));
+        assert!(ascii.contains("  [fail] Failed: 1\n"));
+        assert!(ascii.contains("  [pending] Pending: 2\n"));
+        let emoji = format_checks_summary(3, 1, 0, &failed, Glyphs::get(false));
+        assert!(emoji.contains("  ✅ Passed: 3\n"));
+        assert!(!emoji.contains("Pending"));
+
+        let summary = IssueSummary {
+            number: 12,
+            title: "Crash on start".to_string(),
+            state: "CLOSED".to_string(),
+            author: "alice".to_string(),
+            url: "https://github.com/o/r/issues/12".to_string(),
+        };
+        let ascii = format_issue_view(&summary, Some("Steps:\n\n1. run"), Glyphs::get(true));
+        assert!(ascii.is_ascii());
+        assert!(ascii.starts_with("[closed] Issue #12: Crash on start\n"));
+        assert!(ascii.ends_with("    Steps:\n    1. run\n"));
+        let emoji = format_issue_view(&summary, None, Glyphs::get(false));
+        assert!(emoji.starts_with("🔴 Issue #12"));
+    }
+
+    #[test]
+    fn test_extract_failed_log_lines() {
+        let log = "\
+test\tRun tests\t2024-05-01T10:00:00.1000000Z Compiling app v0.1.0
+test\tRun tests\t2024-05-01T10:00:01.1000000Z running 12 tests
+test\tRun tests\t2024-05-01T10:00:02.1000000Z test parser::ok ... ok
+test\tRun tests\t2024-05-01T10:00:02.2000000Z thread 'parser::edge' panicked at src/parser.rs:42:9:
+test\tRun tests\t2024-05-01T10:00:02.3000000Z assertion `left == right` failed
+test\tRun tests\t2024-05-01T10:00:02.4000000Z test result: FAILED. 11 passed; 1 failed
+test\tRun tests\t2024-05-01T10:00:02.5000000Z ##[error]Process completed with exit code 101.
+lint\tClippy\t2024-05-01T10:00:03.1000000Z error: unused variable: `x`
+lint\tClippy\t2024-05-01T10:00:03.2000000Z error: unused variable: `x`
+lint\tClippy\t2024-05-01T10:00:03.3000000Z Process completed with exit code 0.
+";
+        let result = extract_failed_log_lines(log, 40, Glyphs::get(false));
+        assert_eq!(
+            result,
+            "  ❌ test → Run tests\n\
+             \x20   thread 'parser::edge' panicked at src/parser.rs:42:9:\n\
+             \x20   assertion `left == right` failed\n\
+             \x20   test result: FAILED. 11 passed; 1 failed\n\
+             \x20   Process completed with exit code 101.\n\
+             \x20 ❌ lint → Clippy\n\
+             \x20   error: unused variable: `x`\n"
+        );
+
+        let capped = extract_failed_log_lines(log, 2, Glyphs::get(false));
+        assert!(capped.ends_with("  ... +3 more error lines\n"));
+        assert!(!capped.contains("Clippy"));
+
+        let ascii = extract_failed_log_lines(log, 40, Glyphs::get(true));
+        assert!(ascii.starts_with("  [fail] test -> Run tests\n"));
+        assert!(ascii.is_ascii());
+    }
+
+    #[test]
+    fn test_format_pr_comments() {
+        let json: Value = serde_json::from_str(
+            r#"{
+            "comments": [
+                {"author": {"login": "carol"}, "body": "Can we get this in before the release?\n\nThanks!", "createdAt": "2026-03-02T09:00:00Z"},
+                {"author": {"login": "alice"}, "body": "Rebased on main.", "createdAt": "2026-03-03T12:00:00Z"}
+            ],
+            "reviews": [
+                {
+                    "author": {"login": "bob"},
+                    "body": "",
+                    "state": "CHANGES_REQUESTED",
+                    "submittedAt": "2026-03-02T15:00:00Z",
+                    "comments": {"nodes": [
+                        {"author": {"login": "bob"}, "body": "This unwrap panics on empty input", "path": "src/parser.rs", "line": 42, "createdAt": "2026-03-02T14:58:00Z"}
+                    ]}
+                },
+                {"author": {"login": "bob"}, "body": "LGTM now", "state": "APPROVED", "submittedAt": "2026-03-04T08:00:00Z"}
+            ]
+        }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            format_pr_comments(&json, 10),
+            "  Comments (4):\n\
+             \x20 @carol: Can we get this in before the release?\n\
+             \x20 @bob src/parser.rs:42: This unwrap panics on empty input\n\
+             \x20 @alice: Rebased on main.\n\
+             \x20 @bob: LGTM now\n"
+        );
+        assert_eq!(
+            format_pr_comments(&json, 2),
+            "  Comments (4):\n  ... 2 earlier\n  @alice: Rebased on main.\n  @bob: LGTM now\n"
+        );
+    }
 
     #[test]
     fn test_truncate() {
@@ -1111,6 +2069,342 @@ mod tests {
         assert_eq!(truncate("abc", 3), "abc"); // exact fit
     }
 
+    #[test]
+    fn test_format_workflow_list() {
+        let json: Value = serde_json::from_str(
+            r#"[
+                {"id": 161335, "name": "CI", "state": "active"},
+                {"id": 161336, "name": "Release", "state": "disabled_manually"}
+            ]"#,
+        )
+        .unwrap();
+        let result = format_workflow_list(&json, 20);
+        assert_eq!(
+            result,
+            "CI [active] (id 161335)\nRelease [disabled_manually] (id 161336)"
+        );
+    }
+
+    #[test]
+    fn test_format_workflow_list_cap_and_empty() {
+        let workflows: Vec<Value> = (0..25)
+            .map(|i| serde_json::json!({"id": i, "name": format!("wf{}", i), "state": "active"}))
+            .collect();
+        let result = format_workflow_list(&Value::Array(workflows), 20);
+        assert_eq!(result.lines().count(), 21);
+        assert!(result.ends_with("... +5 more"));
+
+        assert_eq!(
+            format_workflow_list(&serde_json::json!([]), 20),
+            "No workflows"
+        );
+    }
+
+    #[test]
+    fn test_extract_fields_arg() {
+        let args: Vec<String> = ["repos/o/r/pulls", "--fields", "number,title", "--paginate"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let (fields, rest) = extract_fields_arg(&args);
+        assert_eq!(
+            fields,
+            Some(vec!["number".to_string(), "title".to_string()])
+        );
+        assert_eq!(rest, vec!["repos/o/r/pulls", "--paginate"]);
+
+        let (fields, rest) = extract_fields_arg(&["user".to_string()]);
+        assert!(fields.is_none());
+        assert_eq!(rest, vec!["user"]);
+    }
+
+    #[test]
+    fn test_list_limit_flag_over_config() {
+        assert_eq!(resolve_list_limit(Some(5), Some(50)), Some(5));
+        assert_eq!(resolve_list_limit(None, Some(50)), Some(50));
+        assert_eq!(resolve_list_limit(None, Some(0)), None);
+        assert_eq!(resolve_list_limit(None, None), None);
+    }
+
+    #[test]
+    fn test_extract_limit_arg() {
+        let args: Vec<String> = ["--state", "open", "--limit", "50"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let (limit, rest) = extract_limit_arg(&args).unwrap();
+        assert_eq!(limit, Some(50));
+        assert_eq!(rest, vec!["--state", "open"]);
+
+        let (limit, _) = extract_limit_arg(&["-n".to_string(), "5".to_string()]).unwrap();
+        assert_eq!(limit, Some(5));
+        let (limit, _) = extract_limit_arg(&["--limit=7".to_string()]).unwrap();
+        assert_eq!(limit, Some(7));
+
+        let (limit, rest) = extract_limit_arg(&["--author".to_string(), "me".to_string()]).unwrap();
+        assert!(limit.is_none());
+        assert_eq!(rest, vec!["--author", "me"]);
+
+        // A bad value is reported, not dropped
+        let err = extract_limit_arg(&["--limit".to_string(), "abc".to_string()]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid --limit value 'abc': expected a number"
+        );
+        let err = extract_limit_arg(&["--limit=".to_string()]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid --limit value '': expected a number"
+        );
+        let err = extract_limit_arg(&["-n".to_string()]).unwrap_err();
+        assert_eq!(err.to_string(), "-n requires a number");
+    }
+
+    #[test]
+    fn test_pr_summary_serializes_distilled_keys() {
+        let json: Value = serde_json::from_str(
+            r#"{
+                "number": 42,
+                "title": "Add retry",
+                "state": "OPEN",
+                "author": {"login": "alice"},
+                "body": "long body that is not part of the summary",
+                "url": "https://github.com/o/r/pull/42",
+                "mergeable": "MERGEABLE",
+                "reviews": {"nodes": [{"state": "APPROVED"}, {"state": "COMMENTED"}]},
+                "statusCheckRollup": [
+                    {"conclusion": "SUCCESS"},
+                    {"conclusion": "FAILURE"},
+                    {"state": "SUCCESS"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let summary = serde_json::to_value(pr_summary(&json)).unwrap();
+        let keys: Vec<&str> = summary
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|k| k.as_str())
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "number",
+                "title",
+                "state",
+                "author",
+                "mergeable",
+                "reviews",
+                "checks",
+                "url"
+            ]
+        );
+        assert_eq!(summary["author"], "alice");
+        assert_eq!(summary["reviews"]["approved"], 1);
+        assert_eq!(summary["reviews"]["changes_requested"], 0);
+        assert_eq!(summary["checks"]["passed"], 2);
+        assert_eq!(summary["checks"]["failed"], 1);
+        assert_eq!(summary["checks"]["total"], 3);
+
+        let issue: Value = serde_json::from_str(
+            r#"{"number": 7, "title": "Crash", "state": "CLOSED",
+                "author": {"login": "bob"}, "url": "https://github.com/o/r/issues/7"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::to_string(&issue_summary(&issue)).unwrap(),
+            r#"{"number":7,"title":"Crash","state":"CLOSED","author":"bob","url":"https://github.com/o/r/issues/7"}"#
+        );
+
+        let (flag, rest) = extract_json_summary_flag(&["--json-summary".into(), "42".into()]);
+        assert!(flag);
+        assert_eq!(rest, vec!["42"]);
+    }
+
+    #[test]
+    fn test_validate_pr_fields() {
+        let ok: Vec<String> = vec!["labels".into(), "isDraft".into(), "headRefName".into()];
+        assert!(validate_pr_fields(&ok).is_ok());
+        assert!(validate_pr_fields(&[]).is_ok());
+
+        let err = validate_pr_fields(&["labels".into(), "draft".into(), "lables".into()])
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("Unknown PR field(s) for --fields: draft, lables"));
+    }
+
+    #[test]
+    fn test_format_pr_extra() {
+        let pr: Value = serde_json::from_str(
+            r#"{"isDraft": true, "isCrossRepository": false,
+                "labels": [{"name": "bug"}, {"name": "urgent"}],
+                "assignees": [], "headRefName": "feature/x", "additions": 12,
+                "milestone": {"title": "v2"}}"#,
+        )
+        .unwrap();
+        let extra = |f: &str| format_pr_extra(f, &pr[f]);
+        assert_eq!(extra("isDraft"), Some("[draft]".to_string()));
+        assert_eq!(extra("isCrossRepository"), None);
+        assert_eq!(extra("labels"), Some("[bug,urgent]".to_string()));
+        assert_eq!(extra("assignees"), None);
+        assert_eq!(extra("headRefName"), Some("[feature/x]".to_string()));
+        assert_eq!(extra("additions"), Some("[additions=12]".to_string()));
+        assert_eq!(extra("milestone"), Some("[v2]".to_string()));
+        assert_eq!(extra("reviewDecision"), None);
+    }
+
+    #[test]
+    fn test_is_list_command() {
+        let list = vec!["list".to_string()];
+        assert!(is_list_command("pr", &list));
+        assert!(is_list_command("run", &list));
+        assert!(!is_list_command("api", &list));
+        assert!(!is_list_command("pr", &["view".to_string()]));
+    }
+
+    #[test]
+    fn test_project_fields_nested() {
+        let json: Value = serde_json::from_str(
+            r#"[
+                {"number": 42, "title": "Fix login", "draft": false,
+                 "user": {"login": "alice", "id": 1},
+                 "labels": ["bug", "p1"], "merged_at": null,
+                 "head": {"ref": "fix-login", "sha": "abc"}},
+                {"number": 43, "title": "Add docs", "draft": true,
+                 "user": {"login": "bob", "id": 2},
+                 "labels": [], "merged_at": "2026-01-01T00:00:00Z",
+                 "head": {"ref": "docs", "sha": "def"}}
+            ]"#,
+        )
+        .unwrap();
+        let fields: Vec<String> = ["number", "user.login", "labels", "merged_at"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let result = project_fields(&json, &fields);
+        assert_eq!(
+            result,
+            "number=42 user.login=alice labels=bug,p1 merged_at=-\n\
+             number=43 user.login=bob labels= merged_at=2026-01-01T00:00:00Z"
+        );
+
+        // Objects project to one row; nested objects are inlined as compact JSON
+        let head = project_fields(&json[0], &["head".to_string(), "missing".to_string()]);
+        assert_eq!(head, r#"head={"ref":"fix-login","sha":"abc"} missing=-"#);
+    }
+
+    #[test]
+    fn test_extract_issue_or_pr_number() {
+        assert_eq!(
+            extract_issue_or_pr_number("https://github.com/o/r/pull/42"),
+            Some(42)
+        );
+        assert_eq!(
+            extract_issue_or_pr_number("https://github.com/o/r/pull/1337/files"),
+            Some(1337)
+        );
+        assert_eq!(
+            extract_issue_or_pr_number("https://github.com/o/r/issues/318"),
+            Some(318)
+        );
+        assert_eq!(extract_issue_or_pr_number("https://github.com/o/r"), None);
+    }
+
+    #[test]
+    fn test_rate_limit_message() {
+        let glyph = Glyphs::current().pending;
+        assert_eq!(
+            rate_limit_message(
+                "HTTP 403: API rate limit exceeded for user ID 1234. \
+                 (https://api.github.com/graphql)\nX-Ratelimit-Reset: 1760537100\n"
+            ),
+            Some(format!(
+                "{} GitHub rate limit hit, resets at 14:05 UTC",
+                glyph
+            ))
+        );
+        assert_eq!(
+            rate_limit_message(
+                "GraphQL: API rate limit exceeded, resets at 2026-10-15 14:05 UTC.\n"
+            ),
+            Some(format!(
+                "{} GitHub rate limit hit, resets at 2026-10-15 14:05 UTC",
+                glyph
+            ))
+        );
+        assert_eq!(
+            rate_limit_message("You have exceeded a secondary rate limit.\n"),
+            Some(format!("{} GitHub rate limit hit, try again later", glyph))
+        );
+        assert_eq!(rate_limit_message("HTTP 404: Not Found\n"), None);
+
+        let err = gh_error("pr list", Some(1), "API rate limit exceeded\n");
+        assert_eq!(
+            crate::utils::exit_code(&anyhow::Error::from(err)),
+            EX_TEMPFAIL
+        );
+        let err = gh_error("pr list", Some(4), "HTTP 401: Bad credentials\n");
+        assert_eq!(crate::utils::exit_code(&anyhow::Error::from(err)), 4);
+    }
+
+    #[test]
+    fn test_rate_limit_headers_on_stdout() {
+        // `gh api -i` prints the response headers on stdout, the error on stderr
+        let stdout = "HTTP/2.0 403 Forbidden\n\
+                      X-Ratelimit-Remaining: 0\n\
+                      X-Ratelimit-Reset: 1760537100\n\
+                      \n\
+                      {\"message\":\"API rate limit exceeded for user ID 1.\"}\n";
+        let stderr = "gh: API rate limit exceeded for user ID 1. (HTTP 403)\n";
+        let dir = tempfile::tempdir().unwrap();
+        tracking::with_db_path(&dir.path().join("history.db"), || {
+            capture::emitted(None, None, || {
+                let runner = MockRunner::new().respond(1, stdout, stderr);
+                let err =
+                    run_api_with(&runner, &["-i".to_string(), "/user".to_string()]).unwrap_err();
+                assert!(err.to_string().contains("resets at 14:05 UTC"));
+                assert_eq!(crate::utils::exit_code(&err), EX_TEMPFAIL);
+
+                let runner = MockRunner::new().respond(1, stdout, stderr);
+                let args = vec!["list".to_string()];
+                let err = run_passthrough_with(&runner, "gh", "release", &args, false).unwrap_err();
+                assert!(err.to_string().contains("resets at 14:05 UTC"));
+                assert_eq!(crate::utils::exit_code(&err), EX_TEMPFAIL);
+            });
+        });
+    }
+
+    #[test]
+    fn test_issue_create_error() {
+        assert_eq!(
+            issue_create_error(
+                "Creating issue in o/r\n\ncould not add label: 'urgent' not found\n"
+            ),
+            "could not add label: 'urgent' not found"
+        );
+        assert_eq!(
+            issue_create_error("HTTP 401: Bad credentials (https://api.github.com/graphql)\n"),
+            "HTTP 401: Bad credentials (https://api.github.com/graphql)"
+        );
+    }
+
+    #[test]
+    fn test_pr_create_error() {
+        assert_eq!(
+            pr_create_error("pull request create failed: GraphQL: No commits between main and feat (createPullRequest)\n"),
+            "no commits between base and head branch"
+        );
+        assert!(pr_create_error(
+            "aborted: you must first push the current branch to a remote, or use the --head flag"
+        )
+        .contains("no upstream"));
+        assert_eq!(
+            pr_create_error("a pull request for branch \"feat\" into branch \"main\" already exists:\nhttps://github.com/o/r/pull/7\n"),
+            "PR already exists: https://github.com/o/r/pull/7"
+        );
+    }
+
     #[test]
     fn test_ok_confirmation_pr_create() {
         let result = ok_confirmation("created", "#42 https://github.com/foo/bar/pull/42");
@@ -1135,4 +2429,59 @@ mod tests {
         let result = ok_confirmation("edited", "#42");
         assert_eq!(result, "ok edited #42");
     }
+
+    #[test]
+    fn test_api_without_fields_is_unchanged() {
+        let raw = r#"{"login":"octocat","id":1,"plan":{"name":"pro","space":976562499}}"#;
+        let runner = MockRunner::new().respond(0, &format!("{}\n", raw), "");
+        let args = vec!["user".to_string()];
+        let out = capture::emitted(None, None, || run_api_with(&runner, &args).unwrap());
+        assert_eq!(runner.calls(), vec!["gh api user"]);
+        assert_eq!(out, format!("{}\n", raw));
+    }
+
+    #[test]
+    fn test_passthrough_keeps_stdio_on_terminal_or_interactive() {
+        let dir = tempfile::tempdir().unwrap();
+        tracking::with_db_path(&dir.path().join("history.db"), || {
+            let runner = MockRunner::new().respond(0, "", "");
+            let args = vec!["list".to_string()];
+            let out = capture::emitted(None, None, || {
+                run_passthrough_with(&runner, "gh", "release", &args, true).unwrap();
+            });
+            assert_eq!(runner.inherited(), vec!["gh release list"]);
+            assert_eq!(out, "");
+
+            // `run watch` redraws the terminal even when stdout is piped
+            let runner = MockRunner::new().respond(1, "", "");
+            let args = vec!["watch".to_string(), "42".to_string()];
+            let err = run_passthrough_with(&runner, "gh", "run", &args, false).unwrap_err();
+            assert_eq!(runner.inherited(), vec!["gh run watch 42"]);
+            assert_eq!(crate::utils::exit_code(&err), 1);
+
+            let runner = MockRunner::new().respond(0, "v1.0\n", "");
+            let args = vec!["list".to_string()];
+            let out = capture::emitted(None, None, || {
+                run_passthrough_with(&runner, "gh", "release", &args, false).unwrap();
+            });
+            assert!(runner.inherited().is_empty());
+            assert_eq!(out, "v1.0\n");
+        });
+    }
+
+    #[test]
+    fn test_passthrough_output_is_capped() {
+        let stdout = "line of gh output\n".repeat(20);
+        let runner = MockRunner::new().respond(0, &stdout, "");
+        let args = vec!["--web".to_string()];
+        let out = capture::emitted(None, Some(100), || {
+            run_passthrough_with(&runner, "gh", "gist", &args, false).unwrap();
+        });
+        assert_eq!(runner.calls(), vec!["gh gist --web"]);
+        assert!(out.starts_with("line of gh output\n"));
+        assert!(out.len() < 200);
+        assert!(
+            out.ends_with("... [output truncated: 270 bytes hidden, re-run raw tool to see all]\n")
+        );
+    }
 }
@@ -1,7 +1,18 @@
+use crate::cache;
+use crate::config;
+use crate::exec::{CommandRunner, SystemRunner};
+use crate::find_cmd::glob_match;
+use crate::glyphs::Glyphs;
+use crate::ls::human_size;
+use crate::term;
 use crate::tracking;
+use crate::utils::{emit, emit_json, extract_format_json, run_raw, truncate, CommandError};
 use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::ffi::OsString;
-use std::process::Command;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 #[derive(Debug, Clone)]
 pub enum GitCommand {
@@ -17,9 +28,61 @@ pub enum GitCommand {
     Fetch,
     Stash { subcommand: Option<String> },
     Worktree,
+    Reflog,
+    Tag,
+    Blame { file: String, range: Option<String> },
+    Restore,
+    Switch,
+    Shortlog,
+    Clean,
+    Bisect,
+    CherryPick,
+    Rm,
+    Mv,
+    Describe,
+    Reset,
 }
 
-pub fn run(cmd: GitCommand, args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()> {
+pub fn run(
+    cmd: GitCommand,
+    args: &[String],
+    max_lines: Option<usize>,
+    verbose: u8,
+    yes: bool,
+    raw: bool,
+) -> Result<()> {
+    // `--yes` may land in trailing args (e.g. `rtk git branch -D foo --yes`);
+    // only taken from subcommands that can ask for confirmation
+    let confirms = subcommand_args(&cmd)
+        .first()
+        .is_some_and(|sub| CONFIRMED_SUBCOMMANDS.contains(&sub.as_str()));
+    let trailing_yes = confirms && args.iter().any(|a| a == "--yes");
+    let yes = yes || trailing_yes;
+    // `--no-emoji` is the git-level spelling of the global `--ascii`
+    if args.iter().any(|a| a == "--no-emoji") {
+        std::env::set_var("RTK_ASCII", "1");
+    }
+    let args: Vec<String> = args
+        .iter()
+        .filter(|a| !(trailing_yes && *a == "--yes") && *a != "--no-emoji")
+        .cloned()
+        .collect();
+    let args = args.as_slice();
+
+    if raw {
+        let mut git_args = subcommand_args(&cmd);
+        git_args.extend(args.iter().cloned());
+        if let Some((subcommand, rest)) = git_args.split_first() {
+            if !confirm_destructive(subcommand, rest, yes)? {
+                return Err(declined(&git_args));
+            }
+        }
+        return run_raw(
+            Command::new("git").args(&git_args),
+            &format!("git {}", git_args.join(" ")),
+        );
+    }
+
     match cmd {
         GitCommand::Diff => run_diff(args, max_lines, verbose),
         GitCommand::Log => run_log(args, max_lines, verbose),
@@ -27,30 +90,357 @@ pub fn run(cmd: GitCommand, args: &[String], max_lines: Option<usize>, verbose:
         GitCommand::Show => run_show(args, max_lines, verbose),
         GitCommand::Add => run_add(args, verbose),
         GitCommand::Commit { message } => run_commit(&message, verbose),
-        GitCommand::Push => run_push(args, verbose),
+        GitCommand::Push => run_push(args, verbose, yes),
         GitCommand::Pull => run_pull(args, verbose),
-        GitCommand::Branch => run_branch(args, verbose),
+        GitCommand::Branch => run_branch(args, verbose, yes),
         GitCommand::Fetch => run_fetch(args, verbose),
         GitCommand::Stash { subcommand } => run_stash(subcommand.as_deref(), args, verbose),
         GitCommand::Worktree => run_worktree(args, verbose),
+        GitCommand::Reflog => run_reflog(args, verbose),
+        GitCommand::Tag => run_tag(args, verbose),
+        GitCommand::Blame { file, range } => {
+            run_blame(&file, range.as_deref(), args, max_lines, verbose)
+        }
+        GitCommand::Restore => run_restore(args, verbose),
+        GitCommand::Switch => run_switch(args, verbose),
+        GitCommand::Shortlog => run_shortlog(args, verbose),
+        GitCommand::Clean => run_clean(args, verbose, yes),
+        GitCommand::Bisect => run_bisect(args, verbose),
+        GitCommand::CherryPick => run_cherry_pick(args, verbose),
+        GitCommand::Rm => run_rm(args, verbose),
+        GitCommand::Mv => run_mv(args, verbose),
+        GitCommand::Describe => run_describe(args, verbose),
+        GitCommand::Reset => run_reset(args, verbose, yes),
     }
 }
 
-fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()> {
-    let timer = tracking::TimedExecution::start();
+/// The git subcommand (and implied args) a `GitCommand` maps to
+fn subcommand_args(cmd: &GitCommand) -> Vec<String> {
+    let sub = match cmd {
+        GitCommand::Diff => "diff",
+        GitCommand::Log => "log",
+        GitCommand::Status => "status",
+        GitCommand::Show => "show",
+        GitCommand::Add => "add",
+        GitCommand::Commit { message } => {
+            return vec!["commit".to_string(), "-m".to_string(), message.clone()]
+        }
+        GitCommand::Push => "push",
+        GitCommand::Pull => "pull",
+        GitCommand::Branch => "branch",
+        GitCommand::Fetch => "fetch",
+        GitCommand::Stash { subcommand } => {
+            let mut args = vec!["stash".to_string()];
+            args.extend(subcommand.clone());
+            return args;
+        }
+        GitCommand::Worktree => "worktree",
+        GitCommand::Reflog => "reflog",
+        GitCommand::Tag => "tag",
+        GitCommand::Restore => "restore",
+        GitCommand::Switch => "switch",
+        GitCommand::Shortlog => "shortlog",
+        GitCommand::Clean => "clean",
+        GitCommand::Bisect => "bisect",
+        GitCommand::CherryPick => "cherry-pick",
+        GitCommand::Rm => "rm",
+        GitCommand::Mv => "mv",
+        GitCommand::Describe => "describe",
+        GitCommand::Reset => "reset",
+        GitCommand::Blame { file, range } => {
+            let mut args = vec!["blame".to_string()];
+            if let Some(range) = range {
+                args.extend(["-L".to_string(), range.clone()]);
+            }
+            args.push(file.clone());
+            return args;
+        }
+    };
+    vec![sub.to_string()]
+}
+
+/// A destructive git operation detected from its arguments
+#[derive(Debug, PartialEq)]
+struct DestructiveOp {
+    /// Short description, e.g. "git branch -D"
+    action: String,
+    /// Explicit targets from the args (branches, refs, paths)
+    targets: Vec<String>,
+}
+
+/// Subcommands [`classify_destructive`] may flag, i.e. that accept `--yes`
+const CONFIRMED_SUBCOMMANDS: &[&str] = &["branch", "clean", "reset", "push"];
+
+/// Classify git args as destructive (data loss that git can't trivially undo).
+/// Returns None for safe operations.
+fn classify_destructive(subcommand: &str, args: &[String]) -> Option<DestructiveOp> {
+    let has = |flag: &str| args.iter().any(|a| a == flag);
+    // Short flag clusters like -fd, -xdf
+    let has_short = |c: char| {
+        args.iter()
+            .any(|a| a.starts_with('-') && !a.starts_with("--") && a[1..].contains(c))
+    };
+    let positional: Vec<String> = args
+        .iter()
+        .filter(|a| !a.starts_with('-'))
+        .cloned()
+        .collect();
+
+    match subcommand {
+        "branch" => {
+            let force_delete =
+                has("-D") || ((has("-d") || has("--delete")) && (has("-f") || has("--force")));
+            force_delete.then(|| DestructiveOp {
+                action: "git branch -D".to_string(),
+                targets: positional,
+            })
+        }
+        "clean" => {
+            let dry_run = has("-n") || has("--dry-run") || has_short('n');
+            let force = has("--force") || has_short('f');
+            (force && !dry_run).then(|| DestructiveOp {
+                action: "git clean -f".to_string(),
+                targets: positional,
+            })
+        }
+        "reset" => has("--hard").then(|| DestructiveOp {
+            action: "git reset --hard".to_string(),
+            targets: if positional.is_empty() {
+                vec!["HEAD".to_string()]
+            } else {
+                positional
+            },
+        }),
+        "push" => (has("--force") || has("-f")).then(|| DestructiveOp {
+            action: "git push --force".to_string(),
+            targets: positional,
+        }),
+        _ => None,
+    }
+}
+
+/// List what a destructive operation will affect, for the confirmation prompt
+fn destructive_affected(subcommand: &str, op: &DestructiveOp, args: &[String]) -> Vec<String> {
+    match subcommand {
+        "clean" => Command::new("git")
+            .arg("clean")
+            .arg("-n")
+            .args(clean_dry_run_args(args))
+            .output()
+            .map(|o| {
+                String::from_utf8_lossy(&o.stdout)
+                    .lines()
+                    .map(|l| l.trim_start_matches("Would remove ").to_string())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        "reset" => Command::new("git")
+            .args(["status", "--porcelain", "--untracked-files=no"])
+            .output()
+            .map(|o| {
+                String::from_utf8_lossy(&o.stdout)
+                    .lines()
+                    .filter_map(|l| l.get(3..).map(|f| format!("discard: {}", f)))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        _ => op.targets.clone(),
+    }
+}
+
+/// `git clean` args for a dry run listing what the real run would remove:
+/// every `-f`/`--force` is dropped, everything else (incl. `-e` patterns and
+/// paths after `--`) is kept as given.
+fn clean_dry_run_args(args: &[String]) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            out.push(arg.clone());
+            out.extend(iter.by_ref().cloned());
+            break;
+        }
+        if arg == "--force" {
+            continue;
+        }
+        if arg == "--exclude" {
+            out.push(arg.clone());
+            out.extend(iter.next().cloned());
+            continue;
+        }
+        let Some(cluster) = arg.strip_prefix('-').filter(|c| !c.starts_with('-')) else {
+            out.push(arg.clone());
+            continue;
+        };
+
+        // Short cluster like -xdf or -fe<pattern>: drop the f's, stop at -e
+        let mut kept = String::from("-");
+        let mut takes_value = false;
+        for (i, c) in cluster.char_indices() {
+            if c == 'e' {
+                kept.push_str(&cluster[i..]);
+                takes_value = i + 1 == cluster.len();
+                break;
+            }
+            if c != 'f' {
+                kept.push(c);
+            }
+        }
+        if kept != "-" {
+            out.push(kept);
+        }
+        if takes_value {
+            out.extend(iter.next().cloned());
+        }
+    }
+    out
+}
+
+/// Error for a destructive operation the user didn't confirm: exits 1 like
+/// a failed git call, with the declined command line as the subcommand
+fn declined(git_args: &[String]) -> anyhow::Error {
+    CommandError::with_excerpt(
+        "git",
+        &git_args.join(" "),
+        Some(1),
+        "aborted: not confirmed".to_string(),
+    )
+    .into()
+}
+
+fn with_subcommand(subcommand: &str, args: &[String]) -> Vec<String> {
+    std::iter::once(subcommand.to_string())
+        .chain(args.iter().cloned())
+        .collect()
+}
+
+/// What the prompt shows before "Proceed?": the operation, up to 10 of the
+/// affected items and, for `reset --hard`, how many files lose their changes
+fn confirmation_notice(
+    subcommand: &str,
+    op: &DestructiveOp,
+    affected: &[String],
+    glyphs: &Glyphs,
+) -> String {
+    let mut notice = format!("{} {} is destructive\n", glyphs.warning, op.action);
+    for item in affected.iter().take(10) {
+        notice.push_str(&format!("   {}\n", item));
+    }
+    if affected.len() > 10 {
+        notice.push_str(&format!("   ... +{} more\n", affected.len() - 10));
+    }
+    if subcommand == "reset" && !affected.is_empty() {
+        notice.push_str(&format!(
+            "{} reset --hard would discard changes in {} tracked files\n",
+            glyphs.warning,
+            affected.len()
+        ));
+    }
+    notice
+}
+
+/// Ask for confirmation before a destructive operation.
+/// Prints to stderr (stdout may be piped), defaults to No.
+/// Skipped with --yes or RTK_YES=1; refuses in non-interactive mode otherwise.
+fn confirm_destructive(subcommand: &str, args: &[String], yes: bool) -> Result<bool> {
+    use std::io::{self, BufRead, IsTerminal};
+
+    let op = match classify_destructive(subcommand, args) {
+        Some(op) => op,
+        None => return Ok(true),
+    };
+
+    let env_yes = std::env::var("RTK_YES")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
+    if yes || env_yes {
+        return Ok(true);
+    }
+
+    let affected = destructive_affected(subcommand, &op, args);
+    eprint!(
+        "{}",
+        confirmation_notice(subcommand, &op, &affected, Glyphs::current())
+    );
+    eprint!("Proceed? [y/N] ");
+
+    // If stdin is not a terminal (piped), default to No
+    if !io::stdin().is_terminal() {
+        eprintln!("\n(non-interactive mode, use --yes or RTK_YES=1 to proceed)");
+        return Ok(false);
+    }
+
+    let mut line = String::new();
+    io::stdin()
+        .lock()
+        .read_line(&mut line)
+        .context("Failed to read user input")?;
+
+    let response = line.trim().to_lowercase();
+    Ok(response == "y" || response == "yes")
+}
 
-    // Check if user wants stat output
+/// How `rtk git diff` renders its output
+#[derive(Debug, PartialEq)]
+enum DiffMode {
+    /// Raw git output (user asked for a stat format or `--no-compact`)
+    Passthrough,
+    /// Stat summary followed by `compact_diff` (default, incl. `--staged`/`--cached`)
+    Compact,
+}
+
+fn diff_mode(args: &[String]) -> DiffMode {
     let wants_stat = args
         .iter()
         .any(|arg| arg == "--stat" || arg == "--numstat" || arg == "--shortstat");
+    let no_compact = args.iter().any(|arg| arg == "--no-compact");
+
+    if wants_stat || no_compact {
+        DiffMode::Passthrough
+    } else {
+        DiffMode::Compact
+    }
+}
+
+/// Prepend `-M` so moved files show as one rename instead of a delete+add
+/// pair, unless the user already chose how renames/copies are detected.
+fn with_rename_detection(args: &[String]) -> Vec<String> {
+    let user_set = args.iter().any(|a| {
+        a.starts_with("-M")
+            || a.starts_with("-C")
+            || a.starts_with("--find-renames")
+            || a.starts_with("--find-copies")
+            || a == "--no-renames"
+    });
+    let mut result = Vec::with_capacity(args.len() + 1);
+    if !user_set {
+        result.push("-M".to_string());
+    }
+    result.extend(args.iter().cloned());
+    result
+}
+
+fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
 
-    // Check if user wants compact diff (default RTK behavior)
-    let wants_compact = !args.iter().any(|arg| arg == "--no-compact");
+    let mode = diff_mode(args);
+    let summary_only = args.iter().any(|a| a == "--summary-only");
+    let markdown = args.iter().any(|a| a == "--markdown");
+    // --no-compact, --summary-only and --markdown are rtk-only; git would reject them
+    let args: Vec<String> = args
+        .iter()
+        .filter(|a| *a != "--no-compact" && *a != "--summary-only" && *a != "--markdown")
+        .cloned()
+        .collect();
+    let args = args.as_slice();
 
-    if wants_stat || !wants_compact {
+    if mode == DiffMode::Passthrough {
         // User wants stat or explicitly no compacting - pass through directly
         let mut cmd = Command::new("git");
         cmd.arg("diff");
+        if !term::should_colorize() {
+            cmd.arg("--no-color");
+        }
         for arg in args {
             cmd.arg(arg);
         }
@@ -59,12 +449,11 @@ fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            eprintln!("{}", stderr);
-            std::process::exit(output.status.code().unwrap_or(1));
+            return Err(CommandError::new("git", "diff", output.status.code(), &stderr).into());
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
-        println!("{}", stdout.trim());
+        emit(stdout.trim());
 
         timer.track(
             &format!("git diff {}", args.join(" ")),
@@ -77,6 +466,37 @@ fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     }
 
     // Default RTK behavior: stat first, then compacted diff
+    let args = with_rename_detection(args);
+    let args = args.as_slice();
+
+    if markdown {
+        // Per-file headings carry the +/- totals, so no stat block
+        let output = Command::new("git")
+            .arg("diff")
+            .arg("--no-color")
+            .args(args)
+            .output()
+            .context("Failed to run git diff")?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(CommandError::new("git", "diff", output.status.code(), &stderr).into());
+        }
+        let hunk_lines = if summary_only { 0 } else { DIFF_HUNK_LINES };
+        let glyphs = Glyphs::current();
+        let compacted = compact_diff_with(&stdout, diff_max_lines(max_lines), hunk_lines, glyphs);
+        let rendered = markdown_diff(&compacted, glyphs);
+        emit(&rendered);
+
+        timer.track(
+            &format!("git diff {}", args.join(" ")),
+            &format!("rtk git diff --markdown {}", args.join(" ")),
+            &stdout,
+            &rendered,
+        );
+        return Ok(());
+    }
+
     let mut cmd = Command::new("git");
     cmd.arg("diff").arg("--stat");
 
@@ -92,7 +512,7 @@ fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     }
 
     // Print stat summary first
-    println!("{}", stat_stdout.trim());
+    emit(stat_stdout.trim());
 
     // Now get actual diff but compact it
     let mut diff_cmd = Command::new("git");
@@ -106,9 +526,16 @@ fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
 
     let mut final_output = stat_stdout.to_string();
     if !diff_stdout.is_empty() {
-        println!("\n--- Changes ---");
-        let compacted = compact_diff(&diff_stdout, max_lines.unwrap_or(100));
-        println!("{}", compacted);
+        emit("\n--- Changes ---");
+        // --summary-only: files and +/- totals, no hunk bodies
+        let hunk_lines = if summary_only { 0 } else { DIFF_HUNK_LINES };
+        let compacted = compact_diff_with(
+            &diff_stdout,
+            diff_max_lines(max_lines),
+            hunk_lines,
+            Glyphs::current(),
+        );
+        emit_diff(&compacted);
         final_output.push_str("\n--- Changes ---\n");
         final_output.push_str(&compacted);
     }
@@ -126,6 +553,14 @@ fn run_diff(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
 fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    let markdown = args.iter().any(|a| a == "--markdown");
+    let args: Vec<String> = args
+        .iter()
+        .filter(|a| *a != "--markdown")
+        .cloned()
+        .collect();
+    let args = args.as_slice();
+
     // If user wants --stat or --format only, pass through
     let wants_stat_only = args
         .iter()
@@ -138,17 +573,23 @@ fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     if wants_stat_only || wants_format {
         let mut cmd = Command::new("git");
         cmd.arg("show");
+        if !term::should_colorize() {
+            cmd.arg("--no-color");
+        }
         for arg in args {
             cmd.arg(arg);
         }
         let output = cmd.output().context("Failed to run git show")?;
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            eprintln!("{}", stderr);
-            std::process::exit(output.status.code().unwrap_or(1));
+            timer.track_passthrough(
+                &format!("git show {}", args.join(" ")),
+                &format!("rtk git show {} (passthrough, failed)", args.join(" ")),
+            );
+            return Err(CommandError::new("git", "show", output.status.code(), &stderr).into());
         }
         let stdout = String::from_utf8_lossy(&output.stdout);
-        println!("{}", stdout.trim());
+        emit(stdout.trim());
 
         timer.track(
             &format!("git show {}", args.join(" ")),
@@ -171,6 +612,18 @@ fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
         .map(|o| String::from_utf8_lossy(&o.stdout).to_string())
         .unwrap_or_default();
 
+    // Tags, trees and blobs have no commit summary/stat/diff to compact
+    if let Some(rendered) = show_non_commit(&SystemRunner, args) {
+        emit(&rendered);
+        timer.track(
+            &format!("git show {}", args.join(" ")),
+            &format!("rtk git show {}", args.join(" ")),
+            &raw_output,
+            &rendered,
+        );
+        return Ok(());
+    }
+
     // Step 1: one-line commit summary
     let mut summary_cmd = Command::new("git");
     summary_cmd.args(["show", "--no-patch", "--pretty=format:%h %s (%ar) <%an>"]);
@@ -180,11 +633,14 @@ fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     let summary_output = summary_cmd.output().context("Failed to run git show")?;
     if !summary_output.status.success() {
         let stderr = String::from_utf8_lossy(&summary_output.stderr);
-        eprintln!("{}", stderr);
-        std::process::exit(summary_output.status.code().unwrap_or(1));
+        timer.track_passthrough(
+            &format!("git show {}", args.join(" ")),
+            &format!("rtk git show {} (failed)", args.join(" ")),
+        );
+        return Err(CommandError::new("git", "show", summary_output.status.code(), &stderr).into());
     }
     let summary = String::from_utf8_lossy(&summary_output.stdout);
-    println!("{}", summary.trim());
+    emit(summary.trim());
 
     // Step 2: --stat summary
     let mut stat_cmd = Command::new("git");
@@ -195,8 +651,8 @@ fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     let stat_output = stat_cmd.output().context("Failed to run git show --stat")?;
     let stat_stdout = String::from_utf8_lossy(&stat_output.stdout);
     let stat_text = stat_stdout.trim();
-    if !stat_text.is_empty() {
-        println!("{}", stat_text);
+    if !stat_text.is_empty() && !markdown {
+        emit(stat_text);
     }
 
     // Step 3: compacted diff
@@ -212,10 +668,17 @@ fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     let mut final_output = summary.to_string();
     if !diff_text.is_empty() {
         if verbose > 0 {
-            println!("\n--- Changes ---");
+            emit("\n--- Changes ---");
         }
-        let compacted = compact_diff(diff_text, max_lines.unwrap_or(100));
-        println!("{}", compacted);
+        let compacted = compact_diff(diff_text, diff_max_lines(max_lines));
+        let compacted = if markdown {
+            let rendered = markdown_diff(&compacted, Glyphs::current());
+            emit(&rendered);
+            rendered
+        } else {
+            emit_diff(&compacted);
+            compacted
+        };
         final_output.push_str(&format!("\n{}", compacted));
     }
 
@@ -229,32 +692,292 @@ fn run_show(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()
     Ok(())
 }
 
+/// Object kinds `git show` can display, from `git cat-file -t`
+#[derive(Debug, PartialEq)]
+enum ObjectKind {
+    Commit,
+    Tag,
+    Tree,
+    Blob,
+}
+
+fn parse_object_kind(cat_file_type: &str) -> Option<ObjectKind> {
+    match cat_file_type.trim() {
+        "commit" => Some(ObjectKind::Commit),
+        "tag" => Some(ObjectKind::Tag),
+        "tree" => Some(ObjectKind::Tree),
+        "blob" => Some(ObjectKind::Blob),
+        _ => None,
+    }
+}
+
+/// Tree entries listed by `rtk git show <tree>`
+const SHOW_TREE_ENTRIES: usize = 50;
+
+/// Blob lines previewed by `rtk git show <blob>`
+const SHOW_BLOB_LINES: usize = 10;
+
+/// Compact rendering for a single tag, tree or blob argument. None for
+/// commits (and anything ambiguous) so the commit path handles it.
+fn show_non_commit(runner: &impl CommandRunner, args: &[String]) -> Option<String> {
+    let positional: Vec<&String> = args
+        .iter()
+        .take_while(|a| *a != "--")
+        .filter(|a| !a.starts_with('-'))
+        .collect();
+    let [rev] = positional.as_slice() else {
+        return None;
+    };
+
+    let git_stdout = |git_args: &[&str]| -> Option<String> {
+        let mut cmd = Command::new("git");
+        cmd.args(git_args);
+        let output = runner.output(&mut cmd).ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    match parse_object_kind(&git_stdout(&["cat-file", "-t", rev])?)? {
+        ObjectKind::Commit => None,
+        ObjectKind::Tag => git_stdout(&["cat-file", "-p", rev]).map(|o| format_tag_object(&o)),
+        ObjectKind::Tree => {
+            git_stdout(&["ls-tree", "-l", rev]).map(|o| format_tree_object(rev, &o))
+        }
+        ObjectKind::Blob => {
+            git_stdout(&["cat-file", "-p", rev]).map(|o| format_blob_object(rev, &o))
+        }
+    }
+}
+
+/// "tag v1.0 → commit abc1234 (Alice)" followed by the tag message,
+/// without the PGP signature block
+fn format_tag_object(raw: &str) -> String {
+    let (header, message) = raw.split_once("\n\n").unwrap_or((raw, ""));
+    let field = |name: &str| {
+        header
+            .lines()
+            .find_map(|l| l.strip_prefix(name).map(|v| v.trim().to_string()))
+    };
+    let object = field("object ").unwrap_or_default();
+    let short = object.get(..7).unwrap_or(&object);
+    let kind = field("type ").unwrap_or_else(|| "object".to_string());
+    let name = field("tag ").unwrap_or_default();
+    let tagger = field("tagger ")
+        .and_then(|t| t.split(" <").next().map(str::to_string))
+        .unwrap_or_default();
+
+    let mut result = format!("tag {} → {} {}", name, kind, short);
+    if !tagger.is_empty() {
+        result.push_str(&format!(" ({})", tagger));
+    }
+    for line in message
+        .lines()
+        .take_while(|l| !l.starts_with("-----BEGIN"))
+        .filter(|l| !l.trim().is_empty())
+    {
+        result.push_str(&format!("\n  {}", line.trim_end()));
+    }
+    result
+}
+
+/// `git ls-tree -l` → "tree <rev>: 2 dirs, 3 files", then dirs first
+/// ("src/") and files with their size ("Cargo.toml  1.2K")
+fn format_tree_object(rev: &str, ls_tree: &str) -> String {
+    let mut dirs = Vec::new();
+    let mut files = Vec::new();
+    for line in ls_tree.lines() {
+        let Some((meta, name)) = line.split_once('\t') else {
+            continue;
+        };
+        let fields: Vec<&str> = meta.split_whitespace().collect();
+        match fields.as_slice() {
+            [_, "tree", ..] => dirs.push(format!("{}/", name)),
+            [_, _, _, size] => match size.parse::<u64>() {
+                Ok(bytes) => files.push(format!("{}  {}", name, human_size(bytes))),
+                Err(_) => files.push(name.to_string()),
+            },
+            _ => files.push(name.to_string()),
+        }
+    }
+
+    let entries: Vec<String> = dirs.iter().chain(files.iter()).cloned().collect();
+    let mut result = vec![format!(
+        "tree {}: {} dirs, {} files",
+        rev,
+        dirs.len(),
+        files.len()
+    )];
+    result.extend(entries.iter().take(SHOW_TREE_ENTRIES).cloned());
+    if entries.len() > SHOW_TREE_ENTRIES {
+        result.push(format!("... +{} more", entries.len() - SHOW_TREE_ENTRIES));
+    }
+    result.join("\n")
+}
+
+/// "blob <rev>: 1.2K, 40 lines" plus the first lines of text content
+fn format_blob_object(rev: &str, content: &str) -> String {
+    let size = human_size(content.len() as u64);
+    if content.contains('\0') {
+        return format!("blob {}: {} (binary)", rev, size);
+    }
+    let total = content.lines().count();
+    let mut result = vec![format!("blob {}: {}, {} lines", rev, size, total)];
+    result.extend(content.lines().take(SHOW_BLOB_LINES).map(str::to_string));
+    if total > SHOW_BLOB_LINES {
+        result.push(format!("... +{} more lines", total - SHOW_BLOB_LINES));
+    }
+    result.join("\n")
+}
+
+const DIFF_MAX_LINES: usize = 100;
+
+/// Line budget for compacted diff/show output: `--max-lines`, then
+/// `RTK_DIFF_MAX_LINES`, then `max_lines` from the config file
+fn diff_max_lines(flag: Option<usize>) -> usize {
+    let env = std::env::var("RTK_DIFF_MAX_LINES").ok();
+    let default = config::defaults().max_lines.unwrap_or(DIFF_MAX_LINES);
+    resolve_max_lines(flag, env.as_deref(), default)
+}
+
+/// Flag wins over env wins over default; an unparseable env value is ignored
+fn resolve_max_lines(flag: Option<usize>, env: Option<&str>, default: usize) -> usize {
+    flag.or_else(|| env.and_then(|v| v.trim().parse().ok()))
+        .unwrap_or(default)
+}
+
 pub(crate) fn compact_diff(diff: &str, max_lines: usize) -> String {
+    compact_diff_with(diff, max_lines, DIFF_HUNK_LINES, Glyphs::current())
+}
+
+/// Changed lines shown per hunk before "... (truncated)"
+const DIFF_HUNK_LINES: usize = 10;
+
+/// Generated files whose diffs are rarely reviewed line by line; the
+/// config file's `noisy_diff_paths` adds to this list
+const NOISY_DIFF_PATTERNS: &[&str] = &[
+    "package-lock.json",
+    "npm-shrinkwrap.json",
+    "pnpm-lock.yaml",
+    "yarn.lock",
+    "bun.lockb",
+    "Cargo.lock",
+    "Gemfile.lock",
+    "poetry.lock",
+    "composer.lock",
+    "go.sum",
+    "*.min.js",
+    "*.min.css",
+    "*.map",
+    "*.snap",
+];
+
+/// True when `path` matches a built-in or configured noisy pattern. Patterns
+/// without a `/` match the file name, others the whole path.
+fn is_noisy_diff_path(path: &str) -> bool {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let matches = |pattern: &str| {
+        if pattern.contains('/') {
+            glob_match(pattern, path)
+        } else {
+            glob_match(pattern, name)
+        }
+    };
+    NOISY_DIFF_PATTERNS.iter().any(|p| matches(p))
+        || config::loaded()
+            .filters
+            .noisy_diff_paths
+            .iter()
+            .any(|p| matches(p))
+}
+
+/// Close a file section: "  +3 -1" after its hunks, or for a suppressed
+/// file, "(+412 -38, suppressed)" folded into its header
+fn finish_diff_file(
+    result: &mut [String],
+    header_idx: usize,
+    added: usize,
+    removed: usize,
+    suppressed: bool,
+) -> Option<String> {
+    if suppressed {
+        result[header_idx] = format!(
+            "{} (+{} -{}, suppressed)",
+            result[header_idx], added, removed
+        );
+        None
+    } else if added > 0 || removed > 0 {
+        Some(format!("  +{} -{}", added, removed))
+    } else {
+        None
+    }
+}
+
+/// `max_hunk_lines` of 0 keeps only the file headers and per-file totals
+fn compact_diff_with(
+    diff: &str,
+    max_lines: usize,
+    max_hunk_lines: usize,
+    glyphs: &Glyphs,
+) -> String {
     let mut result = Vec::new();
     let mut current_file = String::new();
     let mut added = 0;
     let mut removed = 0;
     let mut in_hunk = false;
     let mut hunk_lines = 0;
-    let max_hunk_lines = 10;
+    // Rename metadata for the current file section
+    let mut header_idx = 0;
+    let mut similarity: Option<String> = None;
+    let mut rename_from: Option<String> = None;
+    // Noisy generated file: count its changes but show no hunks
+    let mut suppressed = false;
 
     for line in diff.lines() {
         if line.starts_with("diff --git") {
             // New file
-            if !current_file.is_empty() && (added > 0 || removed > 0) {
-                result.push(format!("  +{} -{}", added, removed));
+            if !current_file.is_empty() {
+                let totals = finish_diff_file(&mut result, header_idx, added, removed, suppressed);
+                result.extend(totals);
             }
             current_file = line.split(" b/").nth(1).unwrap_or("unknown").to_string();
-            result.push(format!("\n📄 {}", current_file));
+            suppressed = is_noisy_diff_path(&current_file);
+            header_idx = result.len();
+            result.push(format!("\n{} {}", glyphs.file, current_file));
             added = 0;
             removed = 0;
             in_hunk = false;
+            similarity = None;
+            rename_from = None;
+        } else if !in_hunk && line.starts_with("similarity index ") {
+            similarity = Some(line.trim_start_matches("similarity index ").to_string());
+        } else if !in_hunk && line.starts_with("rename from ") {
+            rename_from = Some(line.trim_start_matches("rename from ").to_string());
+        } else if !in_hunk && line.starts_with("rename to ") {
+            let to = line.trim_start_matches("rename to ");
+            let from = rename_from.as_deref().unwrap_or("unknown");
+            let detail = match &similarity {
+                Some(pct) => format!("rename {}", pct),
+                None => "rename".to_string(),
+            };
+            result[header_idx] = format!(
+                "\n{} {} {} {} ({})",
+                glyphs.file, from, glyphs.arrow, to, detail
+            );
         } else if line.starts_with("@@") {
             // New hunk
             in_hunk = true;
             hunk_lines = 0;
-            let hunk_info = line.split("@@").nth(1).unwrap_or("").trim();
-            result.push(format!("  @@ {} @@", hunk_info));
+            if max_hunk_lines > 0 && !suppressed {
+                result.push(format_hunk_header(line));
+            }
+        } else if in_hunk && suppressed {
+            if line.starts_with('+') {
+                added += 1;
+            } else if line.starts_with('-') {
+                removed += 1;
+            }
         } else if in_hunk {
             if line.starts_with('+') && !line.starts_with("+++") {
                 added += 1;
@@ -276,7 +999,7 @@ pub(crate) fn compact_diff(diff: &str, max_lines: usize) -> String {
                 }
             }
 
-            if hunk_lines == max_hunk_lines {
+            if max_hunk_lines > 0 && hunk_lines == max_hunk_lines {
                 result.push("  ... (truncated)".to_string());
                 hunk_lines += 1;
             }
@@ -288,82 +1011,154 @@ pub(crate) fn compact_diff(diff: &str, max_lines: usize) -> String {
         }
     }
 
-    if !current_file.is_empty() && (added > 0 || removed > 0) {
-        result.push(format!("  +{} -{}", added, removed));
+    if !current_file.is_empty() {
+        let totals = finish_diff_file(&mut result, header_idx, added, removed, suppressed);
+        result.extend(totals);
     }
 
     result.join("\n")
 }
 
-fn run_log(args: &[String], _max_lines: Option<usize>, verbose: u8) -> Result<()> {
-    let timer = tracking::TimedExecution::start();
+/// "@@ -10,6 +10,7 @@ fn process()" -> "  @@ -10,6 +10,7 @@ fn process()".
+/// Keeps git's function-context hint after the closing `@@`.
+fn format_hunk_header(line: &str) -> String {
+    let body = line.trim_start_matches('@');
+    let (range, context) = match body.split_once("@@") {
+        Some((range, rest)) => (range.trim(), rest.trim_start_matches('@').trim()),
+        None => (body.trim(), ""),
+    };
+    if context.is_empty() {
+        format!("  @@ {} @@", range)
+    } else {
+        format!("  @@ {} @@ {}", range, context)
+    }
+}
 
-    let mut cmd = Command::new("git");
-    cmd.arg("log");
+/// Re-render a [`compact_diff`] result for markdown: each file becomes a
+/// `### path (+3 -1)` heading followed by a fenced ```diff block, so the
+/// output pastes into docs and chat without emoji headers.
+fn markdown_diff(compacted: &str, glyphs: &Glyphs) -> String {
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    let mut trailer: Option<&str> = None;
+
+    for line in compacted.lines() {
+        if let Some(header) = line.strip_prefix(glyphs.file) {
+            sections.push((header.trim().to_string(), Vec::new()));
+        } else if line.trim() == "... (more changes truncated)" {
+            trailer = Some(line.trim());
+        } else if let Some((heading, body)) = sections.last_mut() {
+            if is_diff_totals(line) {
+                heading.push_str(&format!(" ({})", line.trim()));
+            } else if !line.is_empty() {
+                body.push(line.strip_prefix("  ").unwrap_or(line));
+            }
+        }
+    }
 
-    // Check if user provided format flags
-    let has_format_flag = args.iter().any(|arg| {
-        arg.starts_with("--oneline") || arg.starts_with("--pretty") || arg.starts_with("--format")
-    });
+    let mut out: Vec<String> = Vec::new();
+    for (heading, body) in &sections {
+        out.push(format!("### {}", heading));
+        if !body.is_empty() {
+            out.push(String::new());
+            out.push("```diff".to_string());
+            out.extend(body.iter().map(|l| l.to_string()));
+            out.push("```".to_string());
+        }
+        out.push(String::new());
+    }
+    match trailer {
+        Some(note) => out.push(note.to_string()),
+        None => {
+            out.pop();
+        }
+    }
+    out.join("\n")
+}
 
-    // Check if user provided limit flag
-    let has_limit_flag = args.iter().any(|arg| {
-        arg.starts_with('-') && arg.chars().nth(1).map_or(false, |c| c.is_ascii_digit())
-    });
+/// Print a [`compact_diff`] result, with added lines green and removed lines
+/// red when color is enabled. The returned/tracked text stays plain.
+pub(crate) fn emit_diff(compacted: &str) {
+    if term::should_colorize() {
+        emit(&colorize_diff(compacted));
+    } else {
+        emit(compacted);
+    }
+}
+
+/// Wrap "  +line" / "  -line" hunk lines in green / red, keeping the +/- text.
+/// Per-file "  +3 -1" totals are left alone.
+fn colorize_diff(compacted: &str) -> String {
+    compacted
+        .lines()
+        .map(|line| {
+            let color = if is_diff_totals(line) {
+                None
+            } else if line.starts_with("  +") {
+                Some("32")
+            } else if line.starts_with("  -") {
+                Some("31")
+            } else {
+                None
+            };
+            match color {
+                Some(code) => format!("\x1b[{}m{}\x1b[0m", code, line),
+                None => line.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
 
-    // Apply RTK defaults only if user didn't specify them
-    if !has_format_flag {
-        cmd.args(["--pretty=format:%h %s (%ar) <%an>"]);
+/// "  +3 -1" line emitted by [`compact_diff`] after each file
+fn is_diff_totals(line: &str) -> bool {
+    match line
+        .strip_prefix("  +")
+        .and_then(|rest| rest.split_once(" -"))
+    {
+        Some((added, removed)) => {
+            added.parse::<usize>().is_ok() && removed.parse::<usize>().is_ok()
+        }
+        None => false,
     }
+}
 
-    let limit = if !has_limit_flag {
-        cmd.arg("-10");
-        10
-    } else {
-        // Extract limit from args if provided
-        args.iter()
-            .find(|arg| {
-                arg.starts_with('-') && arg.chars().nth(1).map_or(false, |c| c.is_ascii_digit())
-            })
-            .and_then(|arg| arg[1..].parse::<usize>().ok())
-            .unwrap_or(10)
-    };
+const BLAME_MAX_LINES: usize = 100;
 
-    // Only add --no-merges if user didn't explicitly request merge commits
-    let wants_merges = args
-        .iter()
-        .any(|arg| arg == "--merges" || arg == "--min-parents=2");
-    if !wants_merges {
-        cmd.arg("--no-merges");
+fn run_blame(
+    file: &str,
+    range: Option<&str>,
+    args: &[String],
+    max_lines: Option<usize>,
+    verbose: u8,
+) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut cmd = Command::new("git");
+    cmd.args(["blame", "--date=short"]);
+    if let Some(range) = range {
+        cmd.args(["-L", range]);
     }
+    cmd.args(args).arg("--").arg(file);
 
-    // Pass all user arguments
-    for arg in args {
-        cmd.arg(arg);
+    let range_desc = range.map(|r| format!(" -L {}", r)).unwrap_or_default();
+    if verbose > 0 {
+        eprintln!("git blame{} {}", range_desc, file);
     }
 
-    let output = cmd.output().context("Failed to run git log")?;
+    let output = cmd.output().context("Failed to run git blame")?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        eprintln!("{}", stderr);
-        // Propagate git's exit code
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(CommandError::new("git", "blame", output.status.code(), &stderr).into());
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-
-    if verbose > 0 {
-        eprintln!("Git log output:");
-    }
-
-    // Post-process: truncate long messages, cap lines
-    let filtered = filter_log_output(&stdout, limit);
-    println!("{}", filtered);
+    let filtered = filter_blame_output(&stdout, max_lines.unwrap_or(BLAME_MAX_LINES));
+    emit(&filtered);
 
     timer.track(
-        &format!("git log {}", args.join(" ")),
-        &format!("rtk git log {}", args.join(" ")),
+        &format!("git blame{} {}", range_desc, file),
+        &format!("rtk git blame{} {}", range_desc, file),
         &stdout,
         &filtered,
     );
@@ -371,121 +1166,772 @@ fn run_log(args: &[String], _max_lines: Option<usize>, verbose: u8) -> Result<()
     Ok(())
 }
 
-/// Filter git log output: truncate long messages, cap lines
-fn filter_log_output(output: &str, limit: usize) -> String {
+/// Reformat `git blame --date=short` output, capped at `max_lines`
+fn filter_blame_output(output: &str, max_lines: usize) -> String {
+    let lines: Vec<String> = output
+        .lines()
+        .map(|l| format_blame_line(l).unwrap_or_else(|| l.to_string()))
+        .collect();
+
+    let mut result: Vec<String> = lines.iter().take(max_lines).cloned().collect();
+    if lines.len() > max_lines {
+        result.push(format!("... +{} more lines", lines.len() - max_lines));
+    }
+    result.join("\n")
+}
+
+/// "a1b2c3d4e (Jane Doe 2024-03-01 42) code" → "a1b2c3d Jane Doe 2024-03-01 │ code"
+fn format_blame_line(line: &str) -> Option<String> {
+    let open = line.find(" (")?;
+    // Boundary commits are prefixed with '^'; a filename may follow the hash
+    let hash = line[..open]
+        .split_whitespace()
+        .next()?
+        .trim_start_matches('^');
+    let short_hash: String = hash.chars().take(7).collect();
+
+    let rest = &line[open + 2..];
+    let close = rest.find(')')?;
+    let meta: Vec<&str> = rest[..close].split_whitespace().collect();
+    let code = &rest[close + 1..];
+    let code = code.strip_prefix(' ').unwrap_or(code);
+
+    // meta = author words..., date, line number
+    if meta.len() < 3 {
+        return None;
+    }
+    let date = meta[meta.len() - 2];
+    let author = meta[..meta.len() - 2].join(" ");
+
+    Some(format!("{} {} {} │ {}", short_hash, author, date, code))
+}
+
+fn run_log(args: &[String], max_lines: Option<usize>, verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if let Some(cached) = cache::lookup("git log") {
+        emit(&cached);
+        return Ok(());
+    }
+
+    let (log_args, limit) = build_log_args(args);
+
+    let mut cmd = Command::new("git");
+    cmd.arg("log");
+    for arg in &log_args {
+        cmd.arg(arg);
+    }
+
+    let output = cmd.output().context("Failed to run git log")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(CommandError::new("git", "log", output.status.code(), &stderr).into());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    if verbose > 0 {
+        eprintln!("Git log output:");
+    }
+
+    // Post-process: truncate long messages, cap lines
+    let limit = max_lines.map_or(limit, |max| max.min(limit));
+    let filtered = if log_args.iter().any(|a| a == FOLLOW_PRETTY) {
+        annotate_follow_renames(&stdout, limit)
+    } else if args.iter().any(|a| a == "--stat") {
+        collapse_log_stats(&stdout, limit)
+    } else {
+        filter_log_output(&stdout, limit)
+    };
+    emit(&filtered);
+    cache::store("git log", &filtered);
+
+    timer.track(
+        &format!("git log {}", args.join(" ")),
+        &format!("rtk git log {}", args.join(" ")),
+        &stdout,
+        &filtered,
+    );
+
+    Ok(())
+}
+
+/// Commits shown by `rtk git log` when neither `-N`, `RTK_LOG_LIMIT` nor the
+/// config file's `log_limit` is given
+const DEFAULT_LOG_LIMIT: usize = 10;
+
+/// Default commit count: `RTK_LOG_LIMIT` when it's a positive number, then
+/// the configured `log_limit`
+fn resolve_log_limit(env: Option<&str>, configured: Option<usize>) -> usize {
+    env.and_then(|v| v.trim().parse::<usize>().ok())
+        .filter(|&n| n > 0)
+        .or(configured.filter(|&n| n > 0))
+        .unwrap_or(DEFAULT_LOG_LIMIT)
+}
+
+/// [`build_log_args_with`] using the `RTK_LOG_LIMIT` / config default
+fn build_log_args(args: &[String]) -> (Vec<String>, usize) {
+    let env = std::env::var("RTK_LOG_LIMIT").ok();
+    let limit = resolve_log_limit(env.as_deref(), config::defaults().log_limit);
+    build_log_args_with(args, limit)
+}
+
+/// Build git log arguments: map rtk convenience flags and apply RTK defaults
+/// (pretty format, limit, --no-merges) only when the user didn't specify them.
+/// A start date (`--today`, `--week`, `--since`/`--after`) replaces the default
+/// `-<default_limit>`; an end date alone (`--until`/`--before`) keeps it. Returns the final argument list and the effective line limit.
+fn build_log_args_with(args: &[String], default_limit: usize) -> (Vec<String>, usize) {
+    let mut user_args: Vec<String> = Vec::new();
+    let mut wants_graph = false;
+
+    // Map rtk-side convenience flags to their git equivalents
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--graph" {
+            wants_graph = true;
+        } else if arg == "--author" {
+            if let Some(name) = iter.next() {
+                user_args.push(format!("--author={}", name));
+            }
+        } else if arg == "--today" {
+            user_args.push("--since=midnight".to_string());
+        } else if arg == "--week" {
+            user_args.push("--since=1.week.ago".to_string());
+        } else if arg == "--since" {
+            if let Some(date) = iter.next() {
+                user_args.push(format!("--since={}", date));
+            }
+        } else {
+            user_args.push(arg.clone());
+        }
+    }
+
+    // Check if user provided format flags
+    let has_format_flag = user_args.iter().any(|arg| {
+        arg.starts_with("--oneline") || arg.starts_with("--pretty") || arg.starts_with("--format")
+    });
+
+    // Check if user provided limit flag
+    let has_limit_flag = user_args
+        .iter()
+        .any(|arg| arg.starts_with('-') && arg.chars().nth(1).is_some_and(|c| c.is_ascii_digit()));
+
+    // A start date is the intended limiter: no default count. An end date
+    // alone still reaches back to the first commit, so it keeps the default.
+    let has_date_bound = user_args.iter().any(|arg| {
+        ["--since", "--after"]
+            .iter()
+            .any(|flag| arg == flag || arg.starts_with(&format!("{}=", flag)))
+    });
+
+    let mut log_args: Vec<String> = Vec::new();
+
+    // Graph mode: keep git's graph rendering, skip the RTK pretty format
+    if wants_graph {
+        log_args.push("--graph".to_string());
+        if !has_format_flag {
+            log_args.push("--oneline".to_string());
+        }
+    } else if !has_format_flag && user_args.iter().any(|a| a == "--follow") {
+        // File biography: rename events ride along as --name-status records
+        log_args.push(FOLLOW_PRETTY.to_string());
+        log_args.push("--name-status".to_string());
+    } else if !has_format_flag {
+        log_args.push("--pretty=format:%h %s (%ar) <%an>".to_string());
+    }
+
+    let limit = if !has_limit_flag && has_date_bound {
+        usize::MAX
+    } else if !has_limit_flag {
+        log_args.push(format!("-{}", default_limit));
+        default_limit
+    } else {
+        // Extract limit from args if provided
+        user_args
+            .iter()
+            .find(|arg| {
+                arg.starts_with('-') && arg.chars().nth(1).is_some_and(|c| c.is_ascii_digit())
+            })
+            .and_then(|arg| arg[1..].parse::<usize>().ok())
+            .unwrap_or(default_limit)
+    };
+
+    // Only add --no-merges if user didn't explicitly request merge commits
+    let wants_merges = user_args
+        .iter()
+        .any(|arg| arg == "--merges" || arg == "--min-parents=2");
+    if !wants_merges {
+        log_args.push("--no-merges".to_string());
+    }
+
+    // Pass all user arguments
+    log_args.extend(user_args);
+
+    (log_args, limit)
+}
+
+/// `--follow` pretty format: the default summary behind a record separator,
+/// so each commit's `--name-status` lines can be told apart
+const FOLLOW_PRETTY: &str = "--pretty=format:%x1e%h %s (%ar) <%an>";
+
+/// Merge `--follow --name-status` records into summary lines, adding
+/// "↳ renamed from <old>" under the commit where the file took its newer name.
+/// At most `limit` commits are kept.
+fn annotate_follow_renames(output: &str, limit: usize) -> String {
+    let mut result: Vec<String> = Vec::new();
+    for record in output
+        .split('\x1e')
+        .filter(|r| !r.trim().is_empty())
+        .take(limit)
+    {
+        let mut lines = record.lines();
+        let Some(summary) = lines.next() else {
+            continue;
+        };
+        result.push(truncate_log_line(summary));
+        // "R087<TAB>old/path<TAB>new/path"
+        for line in lines {
+            let mut fields = line.split('\t');
+            if let (Some(status), Some(old), Some(_new)) =
+                (fields.next(), fields.next(), fields.next())
+            {
+                if status.starts_with('R') {
+                    result.push(format!("  ↳ renamed from {}", old));
+                }
+            }
+        }
+    }
+    result.join("\n")
+}
+
+/// Fold each commit's `--stat` block into its summary line:
+///
+/// "abc1234 fix parser (2 days ago) <Alice>" + file lines + "2 files changed, ..."
+/// → "abc1234 fix parser (2 days ago) <Alice>  +12 -3 across 2 files"
+///
+/// Long subjects are truncated before the stat is appended so the counts
+/// survive; at most `limit` commits are kept.
+fn collapse_log_stats(output: &str, limit: usize) -> String {
+    let mut commits: Vec<String> = Vec::new();
+    for line in output.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !line.starts_with(' ') {
+            commits.push(truncate_log_line(line));
+        } else if let Some((files, added, removed)) = parse_shortstat(line) {
+            if let Some(commit) = commits.last_mut() {
+                commit.push_str(&format!(
+                    "  +{} -{} across {} file{}",
+                    added,
+                    removed,
+                    files,
+                    if files == 1 { "" } else { "s" }
+                ));
+            }
+        }
+    }
+    commits.truncate(limit);
+    commits.join("\n")
+}
+
+/// (files, insertions, deletions) from "3 files changed, 10 insertions(+), 2 deletions(-)"
+fn parse_shortstat(line: &str) -> Option<(usize, usize, usize)> {
+    if !line.contains(" changed") {
+        return None;
+    }
+    let mut counts = (0, 0, 0);
+    for part in line.split(',') {
+        let mut words = part.split_whitespace();
+        let n: usize = words.next()?.parse().ok()?;
+        match words.next() {
+            Some(w) if w.starts_with("file") => counts.0 = n,
+            Some(w) if w.starts_with("insertion") => counts.1 = n,
+            Some(w) if w.starts_with("deletion") => counts.2 = n,
+            _ => return None,
+        }
+    }
+    Some(counts)
+}
+
+fn truncate_log_line(line: &str) -> String {
+    if line.len() > 80 {
+        let truncated: String = line.chars().take(77).collect();
+        format!("{}...", truncated)
+    } else {
+        line.to_string()
+    }
+}
+
+/// Filter git log output: truncate long messages, cap lines
+fn filter_log_output(output: &str, limit: usize) -> String {
     let lines: Vec<&str> = output.lines().collect();
     let capped: Vec<String> = lines
         .iter()
         .take(limit)
-        .map(|line| {
-            if line.len() > 80 {
-                let truncated: String = line.chars().take(77).collect();
-                format!("{}...", truncated)
-            } else {
-                line.to_string()
-            }
-        })
+        .map(|line| truncate_log_line(line))
         .collect();
 
     capped.join("\n").trim().to_string()
 }
 
-/// Format porcelain output into compact RTK status display
-fn format_status_output(porcelain: &str) -> String {
-    let lines: Vec<&str> = porcelain.lines().collect();
+/// How many files of each category `format_status_output` lists before "... +N more"
+#[derive(Debug, Clone, PartialEq)]
+struct StatusLimits {
+    staged: usize,
+    modified: usize,
+    untracked: usize,
+    conflicts: usize,
+}
 
-    if lines.is_empty() {
-        return "Clean working tree".to_string();
+impl Default for StatusLimits {
+    fn default() -> Self {
+        Self {
+            staged: 5,
+            modified: 5,
+            untracked: 3,
+            conflicts: 5,
+        }
     }
+}
 
-    let mut output = String::new();
+impl StatusLimits {
+    /// Same cap for every category (`--max-files N`)
+    fn uniform(max: usize) -> Self {
+        Self {
+            staged: max,
+            modified: max,
+            untracked: max,
+            conflicts: max,
+        }
+    }
+}
+
+/// Split rtk's `--max-files N` / `--max-files=N` out of status args; without
+/// it, the config's `max_files` (if set) replaces the per-section defaults
+fn extract_status_limits(args: &[String]) -> (StatusLimits, Vec<String>) {
+    let mut limits = config::defaults()
+        .max_files
+        .map(StatusLimits::uniform)
+        .unwrap_or_default();
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = if arg == "--max-files" {
+            iter.next().map(|v| v.as_str())
+        } else if let Some(v) = arg.strip_prefix("--max-files=") {
+            Some(v)
+        } else {
+            rest.push(arg.clone());
+            continue;
+        };
+        if let Some(max) = value.and_then(|v| v.parse().ok()) {
+            limits = StatusLimits::uniform(max);
+        }
+    }
+    (limits, rest)
+}
+
+/// Seconds between `--watch` frames unless `--watch=N` is given
+const STATUS_WATCH_SECS: u64 = 2;
+
+/// Pull rtk's own `--watch`, `--watch N` or `--watch=N` (seconds) out of the
+/// status args
+fn extract_watch_interval(args: &[String]) -> (Option<u64>, Vec<String>) {
+    let mut watch = None;
+    let mut rest = Vec::new();
+    let mut iter = args.iter().peekable();
+    while let Some(arg) = iter.next() {
+        if arg == "--watch" {
+            let secs = iter
+                .next_if(|next| !next.is_empty() && next.chars().all(|c| c.is_ascii_digit()))
+                .and_then(|next| next.parse().ok())
+                .filter(|s| *s > 0);
+            watch = Some(secs.unwrap_or(STATUS_WATCH_SECS));
+        } else if let Some(secs) = arg.strip_prefix("--watch=") {
+            watch = Some(
+                secs.parse()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .unwrap_or(STATUS_WATCH_SECS),
+            );
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+    (watch, rest)
+}
+
+/// Reprint the compact status every `interval` seconds until Ctrl-C.
+///
+/// Frames are not tracked: the same status repeated every few seconds says
+/// nothing about savings.
+fn watch_status(args: &[String], limits: &StatusLimits, interval: u64, verbose: u8) -> Result<()> {
+    let interrupted = install_interrupt_flag();
+    let tick = std::time::Duration::from_millis(100);
+    let git_args = watch_status_args(args);
+
+    if verbose > 0 {
+        eprintln!("git {} (every {}s)", git_args.join(" "), interval);
+    }
+
+    while !interrupted.load(Ordering::Relaxed) {
+        let output = Command::new("git")
+            .args(&git_args)
+            .output()
+            .context("Failed to run git status")?;
+        // Ctrl-C reaches the whole process group, including a running git
+        if interrupted.load(Ordering::Relaxed) {
+            break;
+        }
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(CommandError::new("git", "status", output.status.code(), &stderr).into());
+        }
+        let formatted = format_status_output(&String::from_utf8_lossy(&output.stdout), limits);
+
+        // Clear screen, cursor home
+        print!("\x1b[2J\x1b[H");
+        println!("every {}s: rtk git status (Ctrl-C to stop)\n", interval);
+        println!("{}", formatted);
 
-    // Parse branch info
-    if let Some(branch_line) = lines.first() {
-        if branch_line.starts_with("##") {
-            let branch = branch_line.trim_start_matches("## ");
-            output.push_str(&format!("📌 {}\n", branch));
+        for _ in 0..interval * 10 {
+            if interrupted.load(Ordering::Relaxed) {
+                break;
+            }
+            std::thread::sleep(tick);
         }
     }
 
-    // Count changes by type
-    let mut staged = 0;
-    let mut modified = 0;
-    let mut untracked = 0;
-    let mut conflicts = 0;
+    Ok(())
+}
+
+/// Each watch frame's git call: porcelain status plus the user's own args
+/// (pathspecs, `-uno`)
+fn watch_status_args(args: &[String]) -> Vec<String> {
+    let mut git_args: Vec<String> = ["status", "--porcelain", "-b"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    git_args.extend(args.iter().cloned());
+    git_args
+}
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Route SIGINT to a flag so the watch loop can stop and exit 0 instead of
+/// being killed mid-frame.
+#[cfg(unix)]
+fn install_interrupt_flag() -> &'static AtomicBool {
+    extern "C" fn on_sigint(_: libc::c_int) {
+        INTERRUPTED.store(true, Ordering::Relaxed);
+    }
+    let handler: extern "C" fn(libc::c_int) = on_sigint;
+    // SAFETY: the handler only stores to an atomic, which is async-signal-safe
+    unsafe {
+        libc::signal(libc::SIGINT, handler as libc::sighandler_t);
+    }
+    &INTERRUPTED
+}
+
+#[cfg(not(unix))]
+fn install_interrupt_flag() -> &'static AtomicBool {
+    &INTERRUPTED
+}
+
+/// Format porcelain output into compact RTK status display
+fn format_status_output(porcelain: &str, limits: &StatusLimits) -> String {
+    let (detached, submodules) = status_context(porcelain);
+    format_status_with(
+        porcelain,
+        limits,
+        Glyphs::current(),
+        detached.as_deref(),
+        &submodules,
+    )
+}
+
+/// Detached-HEAD label and dirty submodules, looked up only when the
+/// porcelain output calls for them
+fn status_context(porcelain: &str) -> (Option<String>, Vec<SubmoduleChange>) {
+    let detached = porcelain
+        .lines()
+        .next()
+        .filter(|l| *l == DETACHED_BRANCH_LINE)
+        .and_then(|_| detached_head_label());
+    // Porcelain v1 shows a dirty submodule as " M path"; only v2 says why
+    let submodules = if porcelain.lines().skip(1).any(|l| l.get(1..2) == Some("M")) {
+        submodule_changes()
+    } else {
+        Vec::new()
+    };
+    (detached, submodules)
+}
+
+/// Submodule with unstaged changes, from `git status --porcelain=v2`
+#[derive(Debug, Clone, PartialEq)]
+struct SubmoduleChange {
+    path: String,
+    new_commits: bool,
+    modified_content: bool,
+    untracked_content: bool,
+}
+
+impl SubmoduleChange {
+    /// "new commits, modified content"
+    fn describe(&self) -> String {
+        let kinds: Vec<&str> = [
+            (self.new_commits, "new commits"),
+            (self.modified_content, "modified content"),
+            (self.untracked_content, "untracked content"),
+        ]
+        .iter()
+        .filter(|(set, _)| *set)
+        .map(|(_, kind)| *kind)
+        .collect();
+        kinds.join(", ")
+    }
+}
+
+fn submodule_changes() -> Vec<SubmoduleChange> {
+    Command::new("git")
+        .args(["status", "--porcelain=v2"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| parse_submodule_changes(&String::from_utf8_lossy(&o.stdout)))
+        .unwrap_or_default()
+}
+
+/// Parse "1 .M SC.. <modes> <hashes> path" entries: the "S<c><m><u>" field
+/// flags commit changed / tracked changes / untracked files in a submodule.
+/// Only worktree-side (unstaged) changes count, matching the Modified section.
+fn parse_submodule_changes(porcelain_v2: &str) -> Vec<SubmoduleChange> {
+    porcelain_v2
+        .lines()
+        .filter_map(|line| {
+            let fields = match line.get(0..2) {
+                Some("1 ") => 9,
+                Some("2 ") => 10,
+                _ => return None,
+            };
+            let parts: Vec<&str> = line.splitn(fields, ' ').collect();
+            if parts.len() != fields {
+                return None;
+            }
+            let xy = parts[1];
+            let sub = parts[2];
+            if !sub.starts_with('S') || xy.get(1..2) == Some(".") {
+                return None;
+            }
+            let flag = |i: usize, c: &str| sub.get(i..i + 1) == Some(c);
+            // Renames carry "path<TAB>origPath"
+            let path = parts[fields - 1].split('\t').next().unwrap_or("");
+            Some(SubmoduleChange {
+                path: path.to_string(),
+                new_commits: flag(1, "C"),
+                modified_content: flag(2, "M"),
+                untracked_content: flag(3, "U"),
+            })
+        })
+        .collect()
+}
+
+/// Porcelain branch line git prints when HEAD is not on a branch
+const DETACHED_BRANCH_LINE: &str = "## HEAD (no branch)";
+
+/// "detached @ abc1234", plus the operation that detached HEAD if any
+/// (rebase, bisect), from one `git rev-parse` call
+fn detached_head_label() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--git-dir", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+    let git_dir = std::path::PathBuf::from(lines.next()?.trim());
+    let hash = lines.next()?.trim().to_string();
+
+    let rebasing = ["rebase-merge", "rebase-apply"]
+        .iter()
+        .find_map(|dir| std::fs::read_to_string(git_dir.join(dir).join("head-name")).ok());
+    let operation = if let Some(head_name) = rebasing {
+        let branch = head_name.trim().trim_start_matches("refs/heads/");
+        Some(format!("rebasing {}", branch))
+    } else if git_dir.join("BISECT_LOG").exists() {
+        Some("bisecting".to_string())
+    } else {
+        None
+    };
+
+    Some(format_detached_label(&hash, operation.as_deref()))
+}
+
+fn format_detached_label(hash: &str, operation: Option<&str>) -> String {
+    match operation {
+        Some(op) => format!("detached @ {} ({})", hash, op),
+        None => format!("detached @ {}", hash),
+    }
+}
+
+/// Porcelain `git status -b` split by state; printed as-is by
+/// `rtk git status --format json`
+#[derive(Debug, Default, PartialEq, Serialize)]
+struct StatusSummary {
+    branch: Option<String>,
+    staged: Vec<String>,
+    modified: Vec<String>,
+    untracked: Vec<String>,
+    conflicts: Vec<String>,
+    submodules: Vec<String>,
+}
+
+/// `detached`: label from [`detached_head_label`], used when porcelain
+/// reports "## HEAD (no branch)". `submodules` are kept out of `modified`.
+fn parse_status(
+    porcelain: &str,
+    detached: Option<&str>,
+    submodules: &[SubmoduleChange],
+) -> StatusSummary {
+    let mut summary = StatusSummary {
+        submodules: submodules.iter().map(|s| s.path.clone()).collect(),
+        ..Default::default()
+    };
+    let mut lines = porcelain.lines().peekable();
 
-    let mut staged_files = Vec::new();
-    let mut modified_files = Vec::new();
-    let mut untracked_files = Vec::new();
+    if let Some(branch_line) = lines.next_if(|l| l.starts_with("##")) {
+        summary.branch = if branch_line == DETACHED_BRANCH_LINE {
+            Some(detached.unwrap_or("detached HEAD").to_string())
+        } else {
+            Some(branch_line.trim_start_matches("## ").to_string())
+        };
+    }
 
-    for line in lines.iter().skip(1) {
+    for line in lines {
         if line.len() < 3 {
             continue;
         }
         let status = line.get(0..2).unwrap_or("  ");
-        let file = line.get(3..).unwrap_or("");
+        let file = line.get(3..).unwrap_or("").to_string();
 
-        match status.chars().next().unwrap_or(' ') {
-            'M' | 'A' | 'D' | 'R' | 'C' => {
-                staged += 1;
-                staged_files.push(file);
-            }
-            'U' => conflicts += 1,
-            _ => {}
+        // Unmerged paths: both sides deleted/added, or either side unmerged
+        if matches!(status, "DD" | "AU" | "UD" | "UA" | "DU" | "AA" | "UU") {
+            summary.conflicts.push(file);
+            continue;
+        }
+
+        if matches!(
+            status.chars().next().unwrap_or(' '),
+            'M' | 'A' | 'D' | 'R' | 'C'
+        ) {
+            summary.staged.push(file.clone());
         }
 
         match status.chars().nth(1).unwrap_or(' ') {
-            'M' | 'D' => {
-                modified += 1;
-                modified_files.push(file);
-            }
+            'M' if summary.submodules.contains(&file) => {}
+            'M' | 'D' => summary.modified.push(file.clone()),
             _ => {}
         }
 
         if status == "??" {
-            untracked += 1;
-            untracked_files.push(file);
+            summary.untracked.push(file);
         }
     }
 
-    // Build summary
-    if staged > 0 {
-        output.push_str(&format!("✅ Staged: {} files\n", staged));
-        for f in staged_files.iter().take(5) {
-            output.push_str(&format!("   {}\n", f));
-        }
-        if staged_files.len() > 5 {
-            output.push_str(&format!("   ... +{} more\n", staged_files.len() - 5));
-        }
+    summary
+}
+
+/// `detached`: label from [`detached_head_label`], used when porcelain
+/// reports "## HEAD (no branch)". `submodules` are listed in their own section
+/// instead of under Modified.
+fn format_status_with(
+    porcelain: &str,
+    limits: &StatusLimits,
+    glyphs: &Glyphs,
+    detached: Option<&str>,
+    submodules: &[SubmoduleChange],
+) -> String {
+    if porcelain.lines().next().is_none() {
+        return "Clean working tree".to_string();
     }
 
-    if modified > 0 {
-        output.push_str(&format!("📝 Modified: {} files\n", modified));
-        for f in modified_files.iter().take(5) {
-            output.push_str(&format!("   {}\n", f));
-        }
-        if modified_files.len() > 5 {
-            output.push_str(&format!("   ... +{} more\n", modified_files.len() - 5));
-        }
+    let summary = parse_status(porcelain, detached, submodules);
+    let mut output = String::new();
+
+    if let Some(branch) = &summary.branch {
+        output.push_str(&format!("{} {}\n", glyphs.branch, branch));
     }
 
-    if untracked > 0 {
-        output.push_str(&format!("❓ Untracked: {} files\n", untracked));
-        for f in untracked_files.iter().take(3) {
-            output.push_str(&format!("   {}\n", f));
+    let (staged, modified) = (&summary.staged, &summary.modified);
+    push_file_section(&mut output, glyphs.staged, "Staged", staged, limits.staged);
+    push_file_section(
+        &mut output,
+        glyphs.modified,
+        "Modified",
+        modified,
+        limits.modified,
+    );
+
+    if !submodules.is_empty() {
+        output.push_str(&format!(
+            "{} Submodules: {} changed\n",
+            glyphs.submodule,
+            submodules.len()
+        ));
+        for s in submodules.iter().take(limits.modified) {
+            output.push_str(&format!("   {} ({})\n", s.path, s.describe()));
         }
-        if untracked_files.len() > 3 {
-            output.push_str(&format!("   ... +{} more\n", untracked_files.len() - 3));
+        if submodules.len() > limits.modified {
+            output.push_str(&format!(
+                "   ... +{} more\n",
+                submodules.len() - limits.modified
+            ));
         }
     }
 
-    if conflicts > 0 {
-        output.push_str(&format!("⚠️  Conflicts: {} files\n", conflicts));
-    }
+    let (untracked, conflicts) = (&summary.untracked, &summary.conflicts);
+    push_file_section(
+        &mut output,
+        glyphs.untracked,
+        "Untracked",
+        untracked,
+        limits.untracked,
+    );
+    push_file_section(
+        &mut output,
+        glyphs.conflict,
+        "Conflicts",
+        conflicts,
+        limits.conflicts,
+    );
 
     output.trim_end().to_string()
 }
 
+/// "<glyph> Title: N files" followed by up to `limit` paths
+fn push_file_section(
+    output: &mut String,
+    glyph: &str,
+    title: &str,
+    files: &[String],
+    limit: usize,
+) {
+    if files.is_empty() {
+        return;
+    }
+    output.push_str(&format!("{} {}: {} files\n", glyph, title, files.len()));
+    for f in files.iter().take(limit) {
+        output.push_str(&format!("   {}\n", f));
+    }
+    if files.len() > limit {
+        output.push_str(&format!("   ... +{} more\n", files.len() - limit));
+    }
+}
+
 /// Minimal filtering for git status with user-provided args
 fn filter_status_with_args(output: &str) -> String {
     let mut result = Vec::new();
@@ -526,6 +1972,19 @@ fn filter_status_with_args(output: &str) -> String {
 fn run_status(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    let (json, args) = extract_format_json(args);
+    let (limits, args) = extract_status_limits(&args);
+    let (watch, args) = extract_watch_interval(&args);
+    let args = args.as_slice();
+
+    if let Some(interval) = watch {
+        return watch_status(args, &limits, interval, verbose);
+    }
+
+    if json {
+        return status_json(args, &timer);
+    }
+
     // If user provided flags, apply minimal filtering
     if !args.is_empty() {
         let output = Command::new("git")
@@ -543,7 +2002,7 @@ fn run_status(args: &[String], verbose: u8) -> Result<()> {
 
         // Apply minimal filtering: strip ANSI, remove hints, empty lines
         let filtered = filter_status_with_args(&stdout);
-        print!("{}", filtered);
+        emit(filtered.trim_end());
 
         timer.track(
             &format!("git status {}", args.join(" ")),
@@ -556,7 +2015,12 @@ fn run_status(args: &[String], verbose: u8) -> Result<()> {
     }
 
     // Default RTK compact mode (no args provided)
-    // Get raw git status for tracking
+    if let Some(cached) = cache::lookup("git status") {
+        emit(&cached);
+        return Ok(());
+    }
+
+    // Get raw git status for tracking
     let raw_output = Command::new("git")
         .args(["status"])
         .output()
@@ -574,10 +2038,13 @@ fn run_status(args: &[String], verbose: u8) -> Result<()> {
     let formatted = if !stderr.is_empty() && stderr.contains("not a git repository") {
         "Not a git repository".to_string()
     } else {
-        format_status_output(&stdout)
+        format_status_output(&stdout, &limits)
     };
 
-    println!("{}", formatted);
+    emit(&formatted);
+    if output.status.success() {
+        cache::store("git status", &formatted);
+    }
 
     // Track for statistics
     timer.track("git status", "rtk git status", &raw_output, &formatted);
@@ -585,6 +2052,40 @@ fn run_status(args: &[String], verbose: u8) -> Result<()> {
     Ok(())
 }
 
+/// `rtk git status --format json`: [`StatusSummary`] as one JSON line.
+/// Remaining args (pathspecs, `-uno`) go to the porcelain call.
+fn status_json(args: &[String], timer: &tracking::TimedExecution) -> Result<()> {
+    let output = Command::new("git")
+        .args(["status", "--porcelain", "-b"])
+        .args(args)
+        .output()
+        .context("Failed to run git status")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    if !output.status.success() {
+        return Err(CommandError::new(
+            "git",
+            "status",
+            output.status.code(),
+            &String::from_utf8_lossy(&output.stderr),
+        )
+        .into());
+    }
+
+    let (detached, submodules) = status_context(&stdout);
+    let summary = parse_status(&stdout, detached.as_deref(), &submodules);
+    let filtered = serde_json::to_string(&summary)?;
+    emit_json(&filtered);
+
+    timer.track(
+        &format!("git status --porcelain -b {}", args.join(" ")),
+        &format!("rtk git status --format json {}", args.join(" ")),
+        &stdout,
+        &filtered,
+    );
+    Ok(())
+}
+
 fn run_add(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
@@ -632,7 +2133,7 @@ fn run_add(args: &[String], verbose: u8) -> Result<()> {
             }
         };
 
-        println!("{}", compact);
+        emit(&compact);
 
         timer.track(
             &format!("git add {}", args.join(" ")),
@@ -643,20 +2144,189 @@ fn run_add(args: &[String], verbose: u8) -> Result<()> {
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
         let stdout = String::from_utf8_lossy(&output.stdout);
-        eprintln!("FAILED: git add");
-        if !stderr.trim().is_empty() {
-            eprintln!("{}", stderr);
-        }
-        if !stdout.trim().is_empty() {
-            eprintln!("{}", stdout);
-        }
-        // Propagate git's exit code
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(CommandError::new(
+            "git",
+            "add",
+            output.status.code(),
+            &format!("{}\n{}", stderr, stdout),
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+fn run_rm(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("git rm {}", args.join(" "));
+    }
+
+    let output = Command::new("git")
+        .arg("rm")
+        .args(args)
+        .output()
+        .context("Failed to run git rm")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    if !output.status.success() {
+        let err = match format_rm_refusal(&stderr) {
+            Some(excerpt) => CommandError::with_excerpt("git", "rm", output.status.code(), excerpt),
+            None => CommandError::new("git", "rm", output.status.code(), &stderr),
+        };
+        timer.track(
+            &format!("git rm {}", args.join(" ")),
+            &format!("rtk git rm {}", args.join(" ")),
+            &raw,
+            &err.to_string(),
+        );
+        return Err(err.into());
+    }
+
+    let dry_run = args.iter().any(|a| a == "-n" || a == "--dry-run");
+    let filtered = format_rm_success(&parse_rm_paths(&stdout), dry_run);
+    emit(&filtered);
+
+    timer.track(
+        &format!("git rm {}", args.join(" ")),
+        &format!("rtk git rm {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    Ok(())
+}
+
+/// Paths from git rm's "rm 'path'" lines (one per removed file)
+fn parse_rm_paths(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let path = line.trim().strip_prefix("rm ")?;
+            Some(path.trim_matches('\'').to_string())
+        })
+        .collect()
+}
+
+fn format_rm_success(paths: &[String], dry_run: bool) -> String {
+    let verb = if dry_run { "would remove" } else { "removed" };
+    match paths {
+        [] => "ok (nothing to remove)".to_string(),
+        [path] => format!("ok ✓ {} {}", verb, path),
+        _ => format!("ok ✓ {} {} files", verb, paths.len()),
+    }
+}
+
+/// Compact git rm's multi-line refusal:
+///
+/// "error: the following files have local modifications:\n    a\n    b\n(use ...)"
+/// → "a, b: local modifications (use ...)". None for other errors.
+fn format_rm_refusal(stderr: &str) -> Option<String> {
+    let header = stderr
+        .lines()
+        .find(|l| l.starts_with("error: the following file"))?;
+    let reason = header
+        .split_once(" has ")
+        .or_else(|| header.split_once(" have "))?
+        .1
+        .trim_end_matches(':');
+    let files: Vec<&str> = stderr
+        .lines()
+        .filter(|l| l.starts_with(char::is_whitespace))
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .collect();
+    let mut compact = format!("{}: {}", files.join(", "), reason);
+    if let Some(hint) = stderr.lines().find(|l| l.starts_with('(')) {
+        compact.push(' ');
+        compact.push_str(hint.trim());
+    }
+    Some(compact)
+}
+
+fn run_mv(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("git mv {}", args.join(" "));
+    }
+
+    let output = Command::new("git")
+        .arg("mv")
+        .args(args)
+        .output()
+        .context("Failed to run git mv")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    if !output.status.success() {
+        let err = CommandError::new("git", "mv", output.status.code(), &stderr);
+        timer.track(
+            &format!("git mv {}", args.join(" ")),
+            &format!("rtk git mv {}", args.join(" ")),
+            &raw,
+            &err.to_string(),
+        );
+        return Err(err.into());
     }
 
+    let filtered = format_mv_success(&parse_mv_paths(args, &stdout));
+    emit(&filtered);
+
+    timer.track(
+        &format!("git mv {}", args.join(" ")),
+        &format!("rtk git mv {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
     Ok(())
 }
 
+/// (source, destination) pairs: from "Renaming a to b" lines when git printed
+/// them (-v, -n), otherwise from the positional args (sources..., destination).
+fn parse_mv_paths(args: &[String], output: &str) -> Vec<(String, String)> {
+    let renames: Vec<(String, String)> = output
+        .lines()
+        .filter_map(|line| {
+            let (src, dst) = line.trim().strip_prefix("Renaming ")?.split_once(" to ")?;
+            Some((src.to_string(), dst.to_string()))
+        })
+        .collect();
+    if !renames.is_empty() {
+        return renames;
+    }
+
+    let paths: Vec<&String> = args.iter().filter(|a| !a.starts_with('-')).collect();
+    match paths.split_last() {
+        Some((dst, sources)) => sources
+            .iter()
+            .map(|src| (src.to_string(), dst.to_string()))
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+fn format_mv_success(moves: &[(String, String)]) -> String {
+    match moves {
+        [] => "ok ✓ moved".to_string(),
+        [(src, dst)] => format!("ok ✓ moved {} → {}", src, dst),
+        _ => {
+            let mut lines = vec![format!("ok ✓ moved {} files", moves.len())];
+            lines.extend(
+                moves
+                    .iter()
+                    .map(|(src, dst)| format!("  {} → {}", src, dst)),
+            );
+            lines.join("\n")
+        }
+    }
+}
+
 fn run_commit(message: &str, verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
@@ -690,7 +2360,7 @@ fn run_commit(message: &str, verbose: u8) -> Result<()> {
             "ok ✓".to_string()
         };
 
-        println!("{}", compact);
+        emit(&compact);
 
         timer.track(
             &format!("git commit -m \"{}\"", message),
@@ -700,28 +2370,89 @@ fn run_commit(message: &str, verbose: u8) -> Result<()> {
         );
     } else {
         if stderr.contains("nothing to commit") || stdout.contains("nothing to commit") {
-            println!("ok (nothing to commit)");
+            emit("ok (nothing to commit)");
             timer.track(
                 &format!("git commit -m \"{}\"", message),
                 "rtk git commit",
                 &raw_output,
                 "ok (nothing to commit)",
             );
+        } else if !is_git_own_failure(&stderr) && has_pre_commit_hook() {
+            return Err(CommandError::with_excerpt(
+                "git",
+                "commit",
+                output.status.code(),
+                format_hook_failure(&format!("{}\n{}", stdout, stderr)),
+            )
+            .into());
         } else {
-            eprintln!("FAILED: git commit");
-            if !stderr.trim().is_empty() {
-                eprintln!("{}", stderr);
-            }
-            if !stdout.trim().is_empty() {
-                eprintln!("{}", stdout);
-            }
+            return Err(CommandError::new(
+                "git",
+                "commit",
+                output.status.code(),
+                &format!("{}\n{}", stderr, stdout),
+            )
+            .into());
         }
     }
 
     Ok(())
 }
 
-fn run_push(args: &[String], verbose: u8) -> Result<()> {
+/// Lines of hook output kept by [`format_hook_failure`]
+const HOOK_TAIL_LINES: usize = 10;
+
+/// git's own refusals ("fatal: ...", "error: Committing is not possible ...");
+/// anything else from a failed commit with a pre-commit hook came from the hook
+fn is_git_own_failure(stderr: &str) -> bool {
+    stderr
+        .lines()
+        .any(|l| l.starts_with("fatal: ") || l.starts_with("error: Committing is not possible"))
+}
+
+/// Whether a pre-commit hook is installed (`--git-path` honors core.hooksPath)
+fn has_pre_commit_hook() -> bool {
+    Command::new("git")
+        .args(["rev-parse", "--git-path", "hooks/pre-commit"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| std::path::Path::new(String::from_utf8_lossy(&o.stdout).trim()).is_file())
+        .unwrap_or(false)
+}
+
+/// "✗ pre-commit hook failed:" plus the tail of the hook's output, where
+/// linters and test runners put their verdict
+fn format_hook_failure(output: &str) -> String {
+    let lines: Vec<&str> = output
+        .lines()
+        .map(|l| l.trim_end())
+        .filter(|l| !l.trim().is_empty())
+        .collect();
+    let tail = &lines[lines.len().saturating_sub(HOOK_TAIL_LINES)..];
+
+    let mut result = String::from("✗ pre-commit hook failed:");
+    if lines.len() > tail.len() {
+        result.push_str(&format!(
+            "\n  ... {} earlier lines",
+            lines.len() - tail.len()
+        ));
+    }
+    for line in tail {
+        result.push_str(&format!("\n  {}", line));
+    }
+    result
+}
+
+fn run_push(args: &[String], verbose: u8, yes: bool) -> Result<()> {
+    if !confirm_destructive("push", args, yes)? {
+        return Err(declined(&with_subcommand("push", args)));
+    }
+
+    run_push_with(&SystemRunner, args, verbose)
+}
+
+fn run_push_with(runner: &impl CommandRunner, args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     if verbose > 0 {
@@ -734,34 +2465,43 @@ fn run_push(args: &[String], verbose: u8) -> Result<()> {
         cmd.arg(arg);
     }
 
-    let output = cmd.output().context("Failed to run git push")?;
+    let mut output = runner.output(&mut cmd).context("Failed to run git push")?;
+
+    // New branch without an upstream: retry with `[origin] <branch>` when the
+    // user already asked for `-u`, otherwise point them at it
+    if !output.status.success() && is_no_upstream(&String::from_utf8_lossy(&output.stderr)) {
+        let wants_upstream = args.iter().any(|a| a == "-u" || a == "--set-upstream");
+        let positional = push_positional_args(args);
+        let branch = if wants_upstream && positional.len() < 2 {
+            current_branch(runner)
+        } else {
+            None
+        };
+        match branch {
+            Some(branch) => {
+                let mut retry = Command::new("git");
+                retry.arg("push").args(args);
+                // Only the refspec is missing when the user named a remote
+                if positional.is_empty() {
+                    retry.arg("origin");
+                }
+                retry.arg(&branch);
+                output = runner
+                    .output(&mut retry)
+                    .context("Failed to run git push")?;
+            }
+            None if !wants_upstream => eprintln!("✗ no upstream — run: rtk git push -u"),
+            None => {}
+        }
+    }
 
     let stderr = String::from_utf8_lossy(&output.stderr);
     let stdout = String::from_utf8_lossy(&output.stdout);
     let raw = format!("{}{}", stdout, stderr);
 
     if output.status.success() {
-        let compact = if stderr.contains("Everything up-to-date") {
-            "ok (up-to-date)".to_string()
-        } else {
-            let mut result = String::new();
-            for line in stderr.lines() {
-                if line.contains("->") {
-                    let parts: Vec<&str> = line.split_whitespace().collect();
-                    if parts.len() >= 3 {
-                        result = format!("ok ✓ {}", parts[parts.len() - 1]);
-                        break;
-                    }
-                }
-            }
-            if !result.is_empty() {
-                result
-            } else {
-                "ok ✓".to_string()
-            }
-        };
-
-        println!("{}", compact);
+        let compact = format_push_success(&stderr);
+        emit(&compact);
 
         timer.track(
             &format!("git push {}", args.join(" ")),
@@ -770,19 +2510,23 @@ fn run_push(args: &[String], verbose: u8) -> Result<()> {
             &compact,
         );
     } else {
-        eprintln!("FAILED: git push");
-        if !stderr.trim().is_empty() {
-            eprintln!("{}", stderr);
-        }
-        if !stdout.trim().is_empty() {
-            eprintln!("{}", stdout);
-        }
+        return Err(CommandError::new(
+            "git",
+            "push",
+            output.status.code(),
+            &format!("{}\n{}", stderr, stdout),
+        )
+        .into());
     }
 
     Ok(())
 }
 
 fn run_pull(args: &[String], verbose: u8) -> Result<()> {
+    run_pull_with(&SystemRunner, args, verbose)
+}
+
+fn run_pull_with(runner: &impl CommandRunner, args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     if verbose > 0 {
@@ -795,7 +2539,7 @@ fn run_pull(args: &[String], verbose: u8) -> Result<()> {
         cmd.arg(arg);
     }
 
-    let output = cmd.output().context("Failed to run git pull")?;
+    let output = runner.output(&mut cmd).context("Failed to run git pull")?;
 
     let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
@@ -846,7 +2590,7 @@ fn run_pull(args: &[String], verbose: u8) -> Result<()> {
                 }
             };
 
-        println!("{}", compact);
+        emit(&compact);
 
         timer.track(
             &format!("git pull {}", args.join(" ")),
@@ -855,25 +2599,28 @@ fn run_pull(args: &[String], verbose: u8) -> Result<()> {
             &compact,
         );
     } else {
-        eprintln!("FAILED: git pull");
-        if !stderr.trim().is_empty() {
-            eprintln!("{}", stderr);
-        }
-        if !stdout.trim().is_empty() {
-            eprintln!("{}", stdout);
-        }
+        return Err(CommandError::new(
+            "git",
+            "pull",
+            output.status.code(),
+            &format!("{}\n{}", stderr, stdout),
+        )
+        .into());
     }
 
     Ok(())
 }
 
-fn run_branch(args: &[String], verbose: u8) -> Result<()> {
+fn run_branch(args: &[String], verbose: u8, yes: bool) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     if verbose > 0 {
         eprintln!("git branch");
     }
 
+    let (json, args) = extract_format_json(args);
+    let args = args.as_slice();
+
     let mut cmd = Command::new("git");
     cmd.arg("branch");
 
@@ -883,6 +2630,9 @@ fn run_branch(args: &[String], verbose: u8) -> Result<()> {
         .any(|a| a == "-d" || a == "-D" || a == "-m" || a == "-M" || a == "-c" || a == "-C");
 
     if has_action_flag {
+        if !confirm_destructive("branch", args, yes)? {
+            return Err(declined(&with_subcommand("branch", args)));
+        }
         for arg in args {
             cmd.arg(arg);
         }
@@ -905,16 +2655,49 @@ fn run_branch(args: &[String], verbose: u8) -> Result<()> {
         );
 
         if output.status.success() {
-            println!("ok ✓");
+            emit("ok ✓");
         } else {
-            eprintln!("FAILED: git branch");
-            if !stderr.trim().is_empty() {
-                eprintln!("{}", stderr);
-            }
-            if !stdout.trim().is_empty() {
-                eprintln!("{}", stdout);
-            }
+            return Err(CommandError::new(
+                "git",
+                "branch",
+                output.status.code(),
+                &format!("{}\n{}", stderr, stdout),
+            )
+            .into());
+        }
+        return Ok(());
+    }
+
+    // Cleanup view: local branches (not) merged into HEAD or the given commit
+    let merged = if args.iter().any(|a| a.starts_with("--no-merged")) {
+        Some(false)
+    } else if args.iter().any(|a| a.starts_with("--merged")) {
+        Some(true)
+    } else {
+        None
+    };
+    if let Some(merged) = merged {
+        cmd.arg("--no-color").args(args);
+        let output = cmd.output().context("Failed to run git branch")?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if !output.status.success() {
+            return Err(CommandError::new(
+                "git",
+                "branch",
+                output.status.code(),
+                &String::from_utf8_lossy(&output.stderr),
+            )
+            .into());
         }
+
+        let filtered = filter_merged_branches(&stdout, merged);
+        emit(&filtered);
+        timer.track(
+            &format!("git branch {}", args.join(" ")),
+            &format!("rtk git branch {}", args.join(" ")),
+            &stdout,
+            &filtered,
+        );
         return Ok(());
     }
 
@@ -928,8 +2711,16 @@ fn run_branch(args: &[String], verbose: u8) -> Result<()> {
     let stdout = String::from_utf8_lossy(&output.stdout);
     let raw = stdout.to_string();
 
-    let filtered = filter_branch_output(&stdout);
-    println!("{}", filtered);
+    let filtered = if json {
+        serde_json::to_string(&parse_branches(&stdout))?
+    } else {
+        filter_branch_output(&stdout, &branch_dates())
+    };
+    if json {
+        emit_json(&filtered);
+    } else {
+        emit(&filtered);
+    }
 
     timer.track(
         &format!("git branch {}", args.join(" ")),
@@ -941,7 +2732,132 @@ fn run_branch(args: &[String], verbose: u8) -> Result<()> {
     Ok(())
 }
 
-fn filter_branch_output(output: &str) -> String {
+/// Branches never offered for deletion by the `--merged` listing
+const PROTECTED_BRANCHES: &[&str] = &["main", "master"];
+
+/// `git branch --merged` / `--no-merged` output minus the current branch
+/// (and worktree checkouts) and main/master
+fn merge_candidates(output: &str) -> Vec<&str> {
+    output
+        .lines()
+        .filter(|l| !l.starts_with("* ") && !l.starts_with("+ "))
+        .map(|l| l.trim())
+        .filter(|b| !b.is_empty() && !b.starts_with('(') && !PROTECTED_BRANCHES.contains(b))
+        .collect()
+}
+
+/// "merged (3):" list, plus a delete hint for merged branches
+fn filter_merged_branches(output: &str, merged: bool) -> String {
+    let branches = merge_candidates(output);
+    let label = if merged { "merged" } else { "not merged" };
+    if branches.is_empty() {
+        return format!("no {} branches", label);
+    }
+
+    let mut result = vec![format!("{} ({}):", label, branches.len())];
+    result.extend(branches.iter().map(|b| format!("  {}", b)));
+    if merged {
+        let noun = if branches.len() == 1 {
+            "branch"
+        } else {
+            "branches"
+        };
+        result.push(format!(
+            "{} {} merged — delete with rtk git branch -d <name>",
+            branches.len(),
+            noun
+        ));
+    }
+    result.join("\n")
+}
+
+/// Relative last-commit date per branch ("main" → "3 days ago", remote
+/// branches as "origin/x"), from a single `git for-each-ref` call
+fn branch_dates() -> HashMap<String, String> {
+    Command::new("git")
+        .args([
+            "for-each-ref",
+            "--format=%(refname:short)\t%(committerdate:relative)",
+            "refs/heads",
+            "refs/remotes",
+        ])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| parse_tab_pairs(&String::from_utf8_lossy(&o.stdout)))
+        .unwrap_or_default()
+}
+
+/// Relative commit date per abbreviated hash, from a single `git log --no-walk` call
+fn commit_dates(hashes: &[&str]) -> HashMap<String, String> {
+    if hashes.is_empty() {
+        return HashMap::new();
+    }
+    Command::new("git")
+        .args(["log", "--no-walk=unsorted", "--format=%h\t%cr"])
+        .args(hashes)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| parse_tab_pairs(&String::from_utf8_lossy(&o.stdout)))
+        .unwrap_or_default()
+}
+
+/// Parse "key\tvalue" lines into a map
+fn parse_tab_pairs(output: &str) -> HashMap<String, String> {
+    output
+        .lines()
+        .filter_map(|line| line.split_once('\t'))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .collect()
+}
+
+/// "name" → "name (3 days ago)" when a date is known for `key`
+fn with_date(name: &str, key: &str, dates: &HashMap<String, String>) -> String {
+    match dates.get(key) {
+        Some(date) => format!("{} ({})", name, date),
+        None => name.to_string(),
+    }
+}
+
+fn filter_branch_output(output: &str, dates: &HashMap<String, String>) -> String {
+    let BranchSummary {
+        current,
+        local,
+        remote_only,
+    } = parse_branches(output);
+
+    let mut result = Vec::new();
+    result.push(format!("* {}", with_date(&current, &current, dates)));
+
+    for b in &local {
+        result.push(format!("  {}", with_date(b, b, dates)));
+    }
+
+    if !remote_only.is_empty() {
+        result.push(format!("  remote-only ({}):", remote_only.len()));
+        for b in remote_only.iter().take(10) {
+            let key = format!("origin/{}", b);
+            result.push(format!("    {}", with_date(b, &key, dates)));
+        }
+        if remote_only.len() > 10 {
+            result.push(format!("    ... +{} more", remote_only.len() - 10));
+        }
+    }
+
+    result.join("\n")
+}
+
+/// `git branch -a` split into current, other local, and origin branches
+/// with no local counterpart; printed by `rtk git branch --format json`
+#[derive(Debug, PartialEq, Serialize)]
+struct BranchSummary {
+    current: String,
+    local: Vec<String>,
+    remote_only: Vec<String>,
+}
+
+fn parse_branches(output: &str) -> BranchSummary {
     let mut current = String::new();
     let mut local: Vec<String> = Vec::new();
     let mut remote: Vec<String> = Vec::new();
@@ -966,38 +2882,80 @@ fn filter_branch_output(output: &str) -> String {
         }
     }
 
-    let mut result = Vec::new();
-    result.push(format!("* {}", current));
+    // Filter out remotes that already exist locally
+    let remote_only = remote
+        .into_iter()
+        .filter(|r| *r != current && !local.contains(r))
+        .collect();
 
-    if !local.is_empty() {
-        for b in &local {
-            result.push(format!("  {}", b));
-        }
+    BranchSummary {
+        current,
+        local,
+        remote_only,
     }
+}
 
-    if !remote.is_empty() {
-        // Filter out remotes that already exist locally
-        let remote_only: Vec<&String> = remote
-            .iter()
-            .filter(|r| *r != &current && !local.contains(r))
-            .collect();
-        if !remote_only.is_empty() {
-            result.push(format!("  remote-only ({}):", remote_only.len()));
-            for b in remote_only.iter().take(10) {
-                result.push(format!("    {}", b));
-            }
-            if remote_only.len() > 10 {
-                result.push(format!("    ... +{} more", remote_only.len() - 10));
-            }
+/// "fatal: The current branch feat has no upstream branch." — also printed
+/// for a bare `git push -u`, which has no remote to set
+fn is_no_upstream(stderr: &str) -> bool {
+    stderr.contains("has no upstream branch")
+}
+
+/// `git push` options that take the next arg as their value
+const PUSH_VALUE_FLAGS: &[&str] = &["-o", "--push-option", "--repo", "--receive-pack", "--exec"];
+
+/// Remote and refspecs given to `git push`, without options and their values
+fn push_positional_args(args: &[String]) -> Vec<&str> {
+    let mut positional = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if PUSH_VALUE_FLAGS.contains(&arg.as_str()) {
+            iter.next();
+        } else if !arg.starts_with('-') {
+            positional.push(arg.as_str());
         }
     }
+    positional
+}
 
-    result.join("\n")
+/// Short name of the checked-out branch; None on a detached HEAD
+fn current_branch(runner: &impl CommandRunner) -> Option<String> {
+    let mut cmd = Command::new("git");
+    cmd.args(["symbolic-ref", "--short", "HEAD"]);
+    let output = runner.output(&mut cmd).ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!branch.is_empty()).then_some(branch)
+}
+
+/// "ok ✓ <ref>" from the first "old..new  src -> dst" line git push reports
+fn format_push_success(stderr: &str) -> String {
+    if stderr.contains("Everything up-to-date") {
+        return "ok (up-to-date)".to_string();
+    }
+    stderr
+        .lines()
+        .filter(|line| line.contains("->"))
+        .find_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            (parts.len() >= 3).then(|| format!("ok ✓ {}", parts[parts.len() - 1]))
+        })
+        .unwrap_or_else(|| "ok ✓".to_string())
 }
 
 fn run_fetch(args: &[String], verbose: u8) -> Result<()> {
+    run_fetch_with(&SystemRunner, args, verbose)
+}
+
+fn run_fetch_with(runner: &impl CommandRunner, args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    // rtk's own --status: report ahead/behind against upstream after fetching
+    let with_status = args.iter().any(|a| a == "--status");
+    let args: Vec<String> = args.iter().filter(|a| *a != "--status").cloned().collect();
+
     if verbose > 0 {
         eprintln!("git fetch");
     }
@@ -1008,17 +2966,13 @@ fn run_fetch(args: &[String], verbose: u8) -> Result<()> {
         cmd.arg(arg);
     }
 
-    let output = cmd.output().context("Failed to run git fetch")?;
+    let output = runner.output(&mut cmd).context("Failed to run git fetch")?;
     let stdout = String::from_utf8_lossy(&output.stdout);
     let stderr = String::from_utf8_lossy(&output.stderr);
     let raw = format!("{}{}", stdout, stderr);
 
     if !output.status.success() {
-        eprintln!("FAILED: git fetch");
-        if !stderr.trim().is_empty() {
-            eprintln!("{}", stderr);
-        }
-        return Ok(());
+        return Err(CommandError::new("git", "fetch", output.status.code(), &stderr).into());
     }
 
     // Count new refs from stderr (git fetch outputs to stderr)
@@ -1027,43 +2981,202 @@ fn run_fetch(args: &[String], verbose: u8) -> Result<()> {
         .filter(|l| l.contains("->") || l.contains("[new"))
         .count();
 
-    let msg = if new_refs > 0 {
+    let mut msg = if new_refs > 0 {
         format!("ok fetched ({} new refs)", new_refs)
     } else {
         "ok fetched".to_string()
     };
+    if with_status {
+        if let Some((ahead, behind)) = upstream_ahead_behind(runner) {
+            msg.push_str(&format!(" {}", format_upstream_drift(ahead, behind)));
+        }
+    }
 
-    println!("{}", msg);
+    emit(&msg);
     timer.track("git fetch", "rtk git fetch", &raw, &msg);
 
     Ok(())
 }
 
-fn run_stash(subcommand: Option<&str>, args: &[String], verbose: u8) -> Result<()> {
+/// Commits (ahead, behind) relative to the upstream branch; None without one
+fn upstream_ahead_behind(runner: &impl CommandRunner) -> Option<(usize, usize)> {
+    let mut cmd = Command::new("git");
+    cmd.args(["rev-list", "--left-right", "--count", "HEAD...@{u}"]);
+    let output = runner.output(&mut cmd).ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut counts = stdout.split_whitespace().map(|n| n.parse::<usize>().ok());
+    Some((counts.next()??, counts.next()??))
+}
+
+/// "→ 3 behind upstream", "→ 1 ahead, 3 behind upstream", ...
+fn format_upstream_drift(ahead: usize, behind: usize) -> String {
+    match (ahead, behind) {
+        (0, 0) => "→ up to date with upstream".to_string(),
+        (0, behind) => format!("→ {} behind upstream", behind),
+        (ahead, 0) => format!("→ {} ahead of upstream", ahead),
+        (ahead, behind) => format!("→ {} ahead, {} behind upstream", ahead, behind),
+    }
+}
+
+fn run_restore(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
     if verbose > 0 {
-        eprintln!("git stash {:?}", subcommand);
+        eprintln!("git restore {}", args.join(" "));
     }
 
-    match subcommand {
-        Some("list") => {
-            let output = Command::new("git")
-                .args(["stash", "list"])
-                .output()
-                .context("Failed to run git stash list")?;
-            let stdout = String::from_utf8_lossy(&output.stdout);
+    // Pathspecs like `.` say nothing about how many files changed: compare
+    // the status of tracked files before and after instead
+    let before = tracked_status();
+    let output = Command::new("git")
+        .arg("restore")
+        .args(args)
+        .output()
+        .context("Failed to run git restore")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}{}", stdout, stderr);
+
+    let msg = if output.status.success() {
+        format_restore_success(count_changed_paths(&before, &tracked_status()))
+    } else {
+        CommandError::new("git", "restore", output.status.code(), &stderr).to_string()
+    };
+
+    timer.track(
+        &format!("git restore {}", args.join(" ")),
+        &format!("rtk git restore {}", args.join(" ")),
+        &raw,
+        &msg,
+    );
+
+    if !output.status.success() {
+        return Err(CommandError::new("git", "restore", output.status.code(), &stderr).into());
+    }
+    emit(&msg);
+
+    Ok(())
+}
+
+/// `git status --porcelain` for tracked files; empty if git fails
+fn tracked_status() -> String {
+    Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
+        .unwrap_or_default()
+}
+
+/// Paths whose porcelain status line differs between two snapshots
+fn count_changed_paths(before: &str, after: &str) -> usize {
+    let before: HashSet<&str> = before.lines().collect();
+    let after: HashSet<&str> = after.lines().collect();
+    let paths: HashSet<&str> = before
+        .symmetric_difference(&after)
+        .filter_map(|line| line.get(3..))
+        .collect();
+    paths.len()
+}
+
+/// git restore is silent on success: report how many paths it changed
+fn format_restore_success(paths: usize) -> String {
+    match paths {
+        0 => "ok (nothing to restore)".to_string(),
+        1 => "ok ✓ restored 1 path".to_string(),
+        n => format!("ok ✓ restored {} paths", n),
+    }
+}
+
+fn run_switch(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("git switch {}", args.join(" "));
+    }
+
+    let output = Command::new("git")
+        .arg("switch")
+        .args(args)
+        .output()
+        .context("Failed to run git switch")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}{}", stdout, stderr);
+
+    let msg = if output.status.success() {
+        match parse_switch_target(&stderr) {
+            Some(target) => format!("ok ✓ switched to {}", target),
+            None => "ok ✓".to_string(),
+        }
+    } else {
+        CommandError::new("git", "switch", output.status.code(), &stderr).to_string()
+    };
+
+    timer.track(
+        &format!("git switch {}", args.join(" ")),
+        &format!("rtk git switch {}", args.join(" ")),
+        &raw,
+        &msg,
+    );
+
+    if !output.status.success() {
+        return Err(CommandError::new("git", "switch", output.status.code(), &stderr).into());
+    }
+    emit(&msg);
+
+    Ok(())
+}
+
+/// Branch (or detached commit) from git switch's stderr, e.g. "Switched to branch 'x'"
+fn parse_switch_target(stderr: &str) -> Option<String> {
+    let quoted = |line: &str| line.split('\'').nth(1).map(|s| s.to_string());
+    for line in stderr.lines() {
+        let line = line.trim();
+        if line.starts_with("Switched to branch ")
+            || line.starts_with("Switched to a new branch ")
+            || line.starts_with("Switched to and reset branch ")
+            || line.starts_with("Already on ")
+        {
+            return quoted(line);
+        }
+        if let Some(rest) = line.strip_prefix("HEAD is now at ") {
+            return rest
+                .split_whitespace()
+                .next()
+                .map(|h| format!("{} (detached)", h));
+        }
+    }
+    None
+}
+
+fn run_stash(subcommand: Option<&str>, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("git stash {:?}", subcommand);
+    }
+
+    match subcommand {
+        Some("list") => {
+            let output = Command::new("git")
+                .args(["stash", "list"])
+                .output()
+                .context("Failed to run git stash list")?;
+            let stdout = String::from_utf8_lossy(&output.stdout);
             let raw = stdout.to_string();
 
             if stdout.trim().is_empty() {
                 let msg = "No stashes";
-                println!("{}", msg);
+                emit(msg);
                 timer.track("git stash list", "rtk git stash list", &raw, msg);
                 return Ok(());
             }
 
             let filtered = filter_stash_list(&stdout);
-            println!("{}", filtered);
+            emit(&filtered);
             timer.track("git stash list", "rtk git stash list", &raw, &filtered);
         }
         Some("show") => {
@@ -1078,11 +3191,11 @@ fn run_stash(subcommand: Option<&str>, args: &[String], verbose: u8) -> Result<(
 
             let filtered = if stdout.trim().is_empty() {
                 let msg = "Empty stash";
-                println!("{}", msg);
+                emit(msg);
                 msg.to_string()
             } else {
                 let compacted = compact_diff(&stdout, 100);
-                println!("{}", compacted);
+                emit_diff(&compacted);
                 compacted
             };
 
@@ -1102,13 +3215,9 @@ fn run_stash(subcommand: Option<&str>, args: &[String], verbose: u8) -> Result<(
 
             let msg = if output.status.success() {
                 let msg = format!("ok stash {}", sub);
-                println!("{}", msg);
+                emit(&msg);
                 msg
             } else {
-                eprintln!("FAILED: git stash {}", sub);
-                if !stderr.trim().is_empty() {
-                    eprintln!("{}", stderr);
-                }
                 combined.clone()
             };
 
@@ -1118,6 +3227,13 @@ fn run_stash(subcommand: Option<&str>, args: &[String], verbose: u8) -> Result<(
                 &combined,
                 &msg,
             );
+
+            if !output.status.success() {
+                let subcommand = format!("stash {}", sub);
+                return Err(
+                    CommandError::new("git", &subcommand, output.status.code(), &stderr).into(),
+                );
+            }
         }
         _ => {
             // Default: git stash (push)
@@ -1134,22 +3250,24 @@ fn run_stash(subcommand: Option<&str>, args: &[String], verbose: u8) -> Result<(
             let msg = if output.status.success() {
                 if stdout.contains("No local changes") {
                     let msg = "ok (nothing to stash)";
-                    println!("{}", msg);
+                    emit(msg);
                     msg.to_string()
                 } else {
                     let msg = "ok stashed";
-                    println!("{}", msg);
+                    emit(msg);
                     msg.to_string()
                 }
             } else {
-                eprintln!("FAILED: git stash");
-                if !stderr.trim().is_empty() {
-                    eprintln!("{}", stderr);
-                }
                 combined.clone()
             };
 
             timer.track("git stash", "rtk git stash", &combined, &msg);
+
+            if !output.status.success() {
+                return Err(
+                    CommandError::new("git", "stash", output.status.code(), &stderr).into(),
+                );
+            }
         }
     }
 
@@ -1163,13 +3281,20 @@ fn filter_stash_list(output: &str) -> String {
         if let Some(colon_pos) = line.find(": ") {
             let index = &line[..colon_pos];
             let rest = &line[colon_pos + 2..];
-            // Compact: strip "WIP on branch:" prefix if present
-            let message = if let Some(second_colon) = rest.find(": ") {
-                rest[second_colon + 2..].trim()
-            } else {
-                rest.trim()
+            // Compact: "WIP on branch:" / "On branch:" prefix becomes a [branch] tag
+            let (branch, message) = match rest.split_once(": ") {
+                Some((prefix, message)) => {
+                    let branch = prefix
+                        .strip_prefix("WIP on ")
+                        .or_else(|| prefix.strip_prefix("On "));
+                    (branch, message.trim())
+                }
+                None => (None, rest.trim()),
             };
-            result.push(format!("{}: {}", index, message));
+            match branch {
+                Some(branch) => result.push(format!("{} [{}]: {}", index, branch, message)),
+                None => result.push(format!("{}: {}", index, message)),
+            }
         } else {
             result.push(line.to_string());
         }
@@ -1213,14 +3338,13 @@ fn run_worktree(args: &[String], verbose: u8) -> Result<()> {
             msg,
         );
 
-        if output.status.success() {
-            println!("ok ✓");
-        } else {
-            eprintln!("FAILED: git worktree {}", args.join(" "));
-            if !stderr.trim().is_empty() {
-                eprintln!("{}", stderr);
-            }
+        if !output.status.success() {
+            let subcommand = format!("worktree {}", args.join(" "));
+            return Err(
+                CommandError::new("git", &subcommand, output.status.code(), &stderr).into(),
+            );
         }
+        emit("ok ✓");
         return Ok(());
     }
 
@@ -1233,14 +3357,19 @@ fn run_worktree(args: &[String], verbose: u8) -> Result<()> {
     let stdout = String::from_utf8_lossy(&output.stdout);
     let raw = stdout.to_string();
 
-    let filtered = filter_worktree_list(&stdout);
-    println!("{}", filtered);
+    let hashes: Vec<&str> = stdout
+        .lines()
+        .filter_map(|l| l.split_whitespace().nth(1))
+        .collect();
+    let dates = commit_dates(&hashes);
+    let filtered = filter_worktree_list(&stdout, &dates);
+    emit(&filtered);
     timer.track("git worktree list", "rtk git worktree", &raw, &filtered);
 
     Ok(())
 }
 
-fn filter_worktree_list(output: &str) -> String {
+fn filter_worktree_list(output: &str, dates: &HashMap<String, String>) -> String {
     let home = dirs::home_dir()
         .map(|h| h.to_string_lossy().to_string())
         .unwrap_or_default();
@@ -1259,7 +3388,8 @@ fn filter_worktree_list(output: &str) -> String {
             }
             let hash = parts[1];
             let branch = parts[2..].join(" ");
-            result.push(format!("{} {} {}", path, hash, branch));
+            let line = format!("{} {} {}", path, hash, branch);
+            result.push(with_date(&line, hash, dates));
         } else {
             result.push(line.to_string());
         }
@@ -1267,68 +3397,1843 @@ fn filter_worktree_list(output: &str) -> String {
     result.join("\n")
 }
 
-/// Runs an unsupported git subcommand by passing it through directly
-pub fn run_passthrough(args: &[OsString], verbose: u8) -> Result<()> {
+const CLEAN_PATH_LIMIT: usize = 10;
+
+fn run_clean(args: &[String], verbose: u8, yes: bool) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    let short_flag = |c: char| {
+        args.iter()
+            .any(|a| a.starts_with('-') && !a.starts_with("--") && a[1..].contains(c))
+    };
+    let dry_run = short_flag('n') || args.iter().any(|a| a == "--dry-run");
+    let force = short_flag('f') || args.iter().any(|a| a == "--force");
+
+    // Same rule as git's default clean.requireForce, even if the config relaxes it
+    if !dry_run && !force {
+        let reason = "refusing to clean without -f (preview with -n)".to_string();
+        return Err(CommandError::with_excerpt("git", "clean", Some(1), reason).into());
+    }
+
+    if !confirm_destructive("clean", args, yes)? {
+        return Err(declined(&with_subcommand("clean", args)));
+    }
+
     if verbose > 0 {
-        eprintln!("git passthrough: {:?}", args);
+        eprintln!("git clean {}", args.join(" "));
     }
-    let status = Command::new("git")
+
+    let output = Command::new("git")
+        .arg("clean")
         .args(args)
-        .status()
-        .context("Failed to run git")?;
+        .output()
+        .context("Failed to run git clean")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}{}", stdout, stderr);
 
-    let args_str = tracking::args_display(args);
-    timer.track_passthrough(
-        &format!("git {}", args_str),
-        &format!("rtk git {} (passthrough)", args_str),
+    if !output.status.success() {
+        let err = CommandError::new("git", "clean", output.status.code(), &stderr);
+        timer.track(
+            &format!("git clean {}", args.join(" ")),
+            &format!("rtk git clean {}", args.join(" ")),
+            &raw,
+            &err.to_string(),
+        );
+        return Err(err.into());
+    }
+
+    let filtered = if dry_run {
+        format_clean_preview(&stdout, CLEAN_PATH_LIMIT)
+    } else {
+        let removed = stdout
+            .lines()
+            .filter(|l| l.starts_with("Removing "))
+            .count();
+        if removed == 0 {
+            "ok ✓ nothing to clean".to_string()
+        } else {
+            format!("ok ✓ removed {} items", removed)
+        }
+    };
+    emit(&filtered);
+
+    timer.track(
+        &format!("git clean {}", args.join(" ")),
+        &format!("rtk git clean {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    Ok(())
+}
+
+/// Split `git clean -n` output into (files, dirs) paths; dirs end with '/'
+fn count_would_remove(output: &str) -> (Vec<&str>, Vec<&str>) {
+    output
+        .lines()
+        .filter_map(|l| l.strip_prefix("Would remove "))
+        .partition(|path| !path.ends_with('/'))
+}
+
+fn format_clean_preview(output: &str, limit: usize) -> String {
+    let (files, dirs) = count_would_remove(output);
+    if files.is_empty() && dirs.is_empty() {
+        return "Nothing to clean".to_string();
+    }
+
+    let mut lines = vec![format!(
+        "Would remove {} files, {} dirs",
+        files.len(),
+        dirs.len()
+    )];
+    let paths: Vec<&str> = output
+        .lines()
+        .filter_map(|l| l.strip_prefix("Would remove "))
+        .collect();
+    for path in paths.iter().take(limit) {
+        lines.push(format!("  {}", path));
+    }
+    if paths.len() > limit {
+        lines.push(format!("  ... +{} more", paths.len() - limit));
+    }
+    lines.join("\n")
+}
+
+const SHORTLOG_LIMIT: usize = 15;
+
+fn run_shortlog(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    // Normalize `--since D` so the date isn't mistaken for a revision
+    let mut git_args: Vec<String> = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--since" {
+            if let Some(date) = iter.next() {
+                git_args.push(format!("--since={}", date));
+            }
+        } else {
+            git_args.push(arg.clone());
+        }
+    }
+    // Without a revision, shortlog reads stdin when it isn't a terminal
+    if !git_args.iter().any(|a| !a.starts_with('-')) {
+        git_args.push("HEAD".to_string());
+    }
+
+    if verbose > 0 {
+        eprintln!("git shortlog -sn --no-merges {}", git_args.join(" "));
+    }
+
+    let output = Command::new("git")
+        .args(["shortlog", "-sn", "--no-merges"])
+        .args(&git_args)
+        .output()
+        .context("Failed to run git shortlog")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(CommandError::new("git", "shortlog", output.status.code(), &stderr).into());
+    }
+
+    // Co-authors only appear in `Co-authored-by:` trailers; a git without
+    // `--group` (< 2.29) fails this call and the section is left out
+    let coauthor_stdout = Command::new("git")
+        .args([
+            "shortlog",
+            "-sn",
+            "--no-merges",
+            "--group=trailer:co-authored-by",
+        ])
+        .args(&git_args)
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
+        .unwrap_or_default();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let authors = parse_shortlog(&stdout);
+    let coauthors = parse_shortlog(&coauthor_stdout);
+    let filtered = format_shortlog(&authors, &coauthors, SHORTLOG_LIMIT);
+    emit(&filtered);
+
+    timer.track(
+        &format!("git shortlog -sn --no-merges {}", args.join(" ")),
+        &format!("rtk git shortlog {}", args.join(" ")),
+        &format!("{}{}", stdout, coauthor_stdout),
+        &filtered,
     );
 
-    if !status.success() {
-        std::process::exit(status.code().unwrap_or(1));
+    Ok(())
+}
+
+/// Parse `git shortlog -sn` lines ("   142\tAlice Smith") into (commits, author)
+fn parse_shortlog(output: &str) -> Vec<(usize, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (count, author) = line.trim_start().split_once('\t')?;
+            Some((count.trim().parse().ok()?, author.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Ranked authors under a commit count header, then the co-authors (commits
+/// they're credited on via `Co-authored-by:`) when there are any
+fn format_shortlog(
+    authors: &[(usize, String)],
+    coauthors: &[(usize, String)],
+    limit: usize,
+) -> String {
+    if authors.is_empty() {
+        return "No commits".to_string();
+    }
+
+    let total: usize = authors.iter().map(|(n, _)| n).sum();
+    let plural = |n: usize, word: &str| format!("{} {}{}", n, word, if n == 1 { "" } else { "s" });
+    let mut lines = vec![format!(
+        "{}, {}",
+        plural(total, "commit"),
+        plural(authors.len(), "author")
+    )];
+    push_ranked(&mut lines, authors, limit);
+    if !coauthors.is_empty() {
+        lines.push(format!("Co-authors ({}):", coauthors.len()));
+        push_ranked(&mut lines, coauthors, limit);
+    }
+    lines.join("\n")
+}
+
+/// "142  Alice" rows (counts right-aligned), capped at `limit` with a tail
+fn push_ranked(lines: &mut Vec<String>, ranked: &[(usize, String)], limit: usize) {
+    let width = ranked.first().map_or(1, |(n, _)| n.to_string().len());
+    for (count, name) in ranked.iter().take(limit) {
+        lines.push(format!("{:>width$}  {}", count, name, width = width));
+    }
+    if ranked.len() > limit {
+        lines.push(format!("... +{} more", ranked.len() - limit));
+    }
+}
+
+fn run_bisect(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("git bisect {}", args.join(" "));
+    }
+
+    let output = Command::new("git")
+        .arg("bisect")
+        .args(args)
+        .output()
+        .context("Failed to run git bisect")?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+    let action = args.first().map(|s| s.as_str()).unwrap_or("");
+
+    if !output.status.success() {
+        return Err(CommandError::new(
+            "git",
+            &format!("bisect {}", action),
+            output.status.code(),
+            &raw,
+        )
+        .into());
     }
+
+    let filtered = filter_bisect_output(&raw, action);
+    emit(&filtered);
+
+    timer.track(
+        &format!("git bisect {}", args.join(" ")),
+        &format!("rtk git bisect {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Parse "Bisecting: 675 revisions left to test after this (roughly 10 steps)"
+/// into (revisions, steps)
+fn parse_bisect_progress(line: &str) -> Option<(usize, usize)> {
+    let rest = line.trim().strip_prefix("Bisecting: ")?;
+    let revisions = rest.split_whitespace().next()?.parse().ok()?;
+    let steps = rest
+        .split_once("(roughly ")?
+        .1
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+    Some((revisions, steps))
+}
 
-    #[test]
-    fn test_compact_diff() {
-        let diff = r#"diff --git a/foo.rs b/foo.rs
---- a/foo.rs
-+++ b/foo.rs
-@@ -1,3 +1,4 @@
- fn main() {
-+    println!("hello");
- }
-"#;
-        let result = compact_diff(diff, 100);
-        assert!(result.contains("foo.rs"));
-        assert!(result.contains("+"));
+/// Compact bisect output:
+/// - progress → "bisect: ~10 steps left, now at abc1234 subject"
+/// - done → "✗ first bad commit: abc1234 subject (Author)"
+fn filter_bisect_output(output: &str, action: &str) -> String {
+    let lines: Vec<&str> = output.lines().collect();
+
+    if let Some(idx) = lines
+        .iter()
+        .position(|l| l.trim_end().ends_with(" is the first bad commit"))
+    {
+        let hash = lines[idx].split_whitespace().next().unwrap_or("");
+        let rest = &lines[idx + 1..];
+        let subject = rest
+            .iter()
+            .find(|l| l.starts_with("    ") && !l.trim().is_empty())
+            .map(|l| l.trim())
+            .unwrap_or("");
+        let author = rest
+            .iter()
+            .find_map(|l| l.strip_prefix("Author:"))
+            .map(|a| a.split('<').next().unwrap_or(a).trim());
+        let mut line = format!("✗ first bad commit: {} {}", short_hash(hash), subject);
+        if let Some(author) = author.filter(|a| !a.is_empty()) {
+            line.push_str(&format!(" ({})", author));
+        }
+        return line.trim_end().to_string();
     }
 
-    #[test]
-    fn test_filter_branch_output() {
-        let output = "* main\n  feature/auth\n  fix/bug-123\n  remotes/origin/HEAD -> origin/main\n  remotes/origin/main\n  remotes/origin/feature/auth\n  remotes/origin/release/v2\n";
-        let result = filter_branch_output(output);
-        assert!(result.contains("* main"));
-        assert!(result.contains("feature/auth"));
-        assert!(result.contains("fix/bug-123"));
-        // remote-only should show release/v2 but not main or feature/auth (already local)
-        assert!(result.contains("remote-only"));
-        assert!(result.contains("release/v2"));
+    if let Some(idx) = lines
+        .iter()
+        .rposition(|l| parse_bisect_progress(l).is_some())
+    {
+        let (_, steps) = parse_bisect_progress(lines[idx]).unwrap_or_default();
+        // Checked-out commit follows as "[<full hash>] subject"
+        let current = lines[idx + 1..]
+            .iter()
+            .find_map(|l| l.trim().strip_prefix('['))
+            .and_then(|l| l.split_once(']'))
+            .map(|(hash, subject)| format!("{} {}", short_hash(hash), subject.trim()));
+        let left = format!(
+            "bisect: ~{} step{} left",
+            steps,
+            if steps == 1 { "" } else { "s" }
+        );
+        return match current {
+            Some(current) => format!("{}, now at {}", left, current),
+            None => left,
+        };
+    }
+
+    if action == "reset" {
+        return "ok ✓ bisect reset".to_string();
+    }
+
+    // "status: waiting for good commit(s), bad commit known" and friends
+    let status: Vec<&str> = lines
+        .iter()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .collect();
+    if status.is_empty() {
+        format!("ok ✓ bisect {}", action).trim_end().to_string()
+    } else {
+        status.join("\n")
+    }
+}
+
+fn short_hash(hash: &str) -> &str {
+    &hash[..hash.len().min(7)]
+}
+
+fn run_cherry_pick(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("git cherry-pick {}", args.join(" "));
+    }
+
+    let output = Command::new("git")
+        .arg("cherry-pick")
+        .args(args)
+        .output()
+        .context("Failed to run git cherry-pick")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    if !output.status.success() {
+        let conflicts = parse_conflicted_files(&raw);
+        let err = if conflicts.is_empty() {
+            CommandError::new("git", "cherry-pick", output.status.code(), &stderr)
+        } else {
+            CommandError::with_excerpt(
+                "git",
+                "cherry-pick",
+                output.status.code(),
+                format_cherry_pick_conflict(&conflicts),
+            )
+        };
+        timer.track(
+            &format!("git cherry-pick {}", args.join(" ")),
+            &format!("rtk git cherry-pick {}", args.join(" ")),
+            &raw,
+            &err.to_string(),
+        );
+        return Err(err.into());
+    }
+
+    let filtered = if args.iter().any(|a| a == "--abort") {
+        "ok ✓ cherry-pick aborted".to_string()
+    } else {
+        format_cherry_pick_success(&parse_cherry_pick_commits(&stdout))
+    };
+    emit(&filtered);
+
+    timer.track(
+        &format!("git cherry-pick {}", args.join(" ")),
+        &format!("rtk git cherry-pick {}", args.join(" ")),
+        &raw,
+        &filtered,
+    );
+
+    Ok(())
+}
+
+/// Commits created by cherry-pick, from "[main 1a2b3c4] subject" summary lines
+fn parse_cherry_pick_commits(output: &str) -> Vec<(String, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (refs, subject) = line.strip_prefix('[')?.split_once("] ")?;
+            let hash = refs.split_whitespace().last()?;
+            Some((hash.to_string(), subject.trim().to_string()))
+        })
+        .collect()
+}
+
+fn format_cherry_pick_success(commits: &[(String, String)]) -> String {
+    match commits {
+        [] => "ok ✓ cherry-pick done".to_string(),
+        [(hash, subject)] => format!("ok ✓ cherry-picked {} {}", hash, subject),
+        _ => {
+            let mut lines = vec![format!("ok ✓ cherry-picked {} commits", commits.len())];
+            lines.extend(
+                commits
+                    .iter()
+                    .map(|(hash, subject)| format!("  {} {}", hash, subject)),
+            );
+            lines.join("\n")
+        }
+    }
+}
+
+/// Files from "CONFLICT (content): Merge conflict in src/lib.rs" lines
+fn parse_conflicted_files(output: &str) -> Vec<String> {
+    let mut files: Vec<String> = Vec::new();
+    for line in output.lines() {
+        if !line.starts_with("CONFLICT") {
+            continue;
+        }
+        let file = line
+            .split_once("Merge conflict in ")
+            .map(|(_, f)| f.trim().to_string())
+            .or_else(|| {
+                line.split_once("): ")
+                    .and_then(|(_, rest)| rest.split_whitespace().next())
+                    .map(|f| f.to_string())
+            });
+        if let Some(file) = file.filter(|f| !files.contains(f)) {
+            files.push(file);
+        }
+    }
+    files
+}
+
+fn format_cherry_pick_conflict(files: &[String]) -> String {
+    let mut lines = vec![format!(
+        "conflict in {} file{}:",
+        files.len(),
+        if files.len() == 1 { "" } else { "s" }
+    )];
+    lines.extend(files.iter().map(|f| format!("  {}", f)));
+    lines.push("resolve, then: rtk git cherry-pick --continue (or --abort)".to_string());
+    lines.join("\n")
+}
+
+fn run_describe(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let mut describe_args = vec!["describe".to_string()];
+    if !args.iter().any(|a| a == "--tags" || a == "--all") {
+        describe_args.push("--tags".to_string());
+    }
+    if !args.iter().any(|a| a == "--always") {
+        describe_args.push("--always".to_string());
+    }
+    describe_args.extend(args.iter().cloned());
+
+    if verbose > 0 {
+        eprintln!("git {}", describe_args.join(" "));
+    }
+
+    let output = Command::new("git")
+        .args(&describe_args)
+        .output()
+        .context("Failed to run git describe")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}\n{}", stdout, stderr);
+
+    let descriptor = match first_line(&stdout).filter(|_| output.status.success()) {
+        Some(line) => line.to_string(),
+        // Nothing to describe from (e.g. --no-always without tags): fall back
+        // to the short hash; other errors (bad ref) are reported
+        None if is_describe_no_names(&stderr) => {
+            let head = Command::new("git")
+                .args(["rev-parse", "--short", "HEAD"])
+                .output()
+                .context("Failed to run git rev-parse")?;
+            let head_stdout = String::from_utf8_lossy(&head.stdout);
+            match first_line(&head_stdout).filter(|_| head.status.success()) {
+                Some(hash) => {
+                    let suffix = args_dirty_suffix(args);
+                    let dirty = suffix.is_some() && worktree_is_dirty();
+                    describe_fallback(hash, suffix.as_deref(), dirty)
+                }
+                None => {
+                    return Err(
+                        CommandError::new("git", "describe", output.status.code(), &stderr).into(),
+                    )
+                }
+            }
+        }
+        None => {
+            let err = CommandError::new("git", "describe", output.status.code(), &stderr);
+            timer.track(
+                &format!("git {}", describe_args.join(" ")),
+                &format!("rtk git describe {}", args.join(" ")),
+                &raw,
+                &err.to_string(),
+            );
+            return Err(err.into());
+        }
+    };
+    emit(&descriptor);
+
+    timer.track(
+        &format!("git {}", describe_args.join(" ")),
+        &format!("rtk git describe {}", args.join(" ")),
+        &raw,
+        &descriptor,
+    );
+
+    Ok(())
+}
+
+/// What `git reset` does to the index and working tree
+#[derive(Debug, PartialEq)]
+enum ResetMode {
+    /// Move HEAD only; changes stay staged
+    Soft,
+    /// Move HEAD and reset the index (git's default)
+    Mixed,
+    /// Move HEAD and discard index and working tree changes
+    Hard,
+}
+
+fn reset_mode(args: &[String]) -> ResetMode {
+    // Last mode flag wins, like git's own option parsing
+    args.iter()
+        .rev()
+        .find_map(|a| match a.as_str() {
+            "--soft" => Some(ResetMode::Soft),
+            "--mixed" => Some(ResetMode::Mixed),
+            "--hard" => Some(ResetMode::Hard),
+            _ => None,
+        })
+        .unwrap_or(ResetMode::Mixed)
+}
+
+/// Files listed under "Unstaged changes after reset:" (`M\tpath`)
+fn count_unstaged_after_reset(stdout: &str) -> usize {
+    stdout
+        .lines()
+        .filter(|l| {
+            l.split_once('\t')
+                .is_some_and(|(status, _)| !status.is_empty() && status.len() <= 2)
+        })
+        .count()
+}
+
+/// "ok ✓ reset to abc1234 (3 files unstaged)"; `discarded` is the number of
+/// files with uncommitted changes a hard reset threw away
+fn format_reset_summary(mode: &ResetMode, hash: &str, stdout: &str, discarded: usize) -> String {
+    let files = |n: usize| if n == 1 { "file" } else { "files" };
+    match mode {
+        ResetMode::Soft => format!("ok ✓ reset to {} (changes kept staged)", hash),
+        ResetMode::Mixed => {
+            let n = count_unstaged_after_reset(stdout);
+            format!("ok ✓ reset to {} ({} {} unstaged)", hash, n, files(n))
+        }
+        ResetMode::Hard if discarded > 0 => format!(
+            "ok ✓ hard reset to {} (discarded changes in {} {})",
+            hash,
+            discarded,
+            files(discarded)
+        ),
+        ResetMode::Hard => format!("ok ✓ hard reset to {}", hash),
+    }
+}
+
+/// Tracked files with uncommitted changes (what `reset --hard` discards)
+fn count_tracked_changes() -> usize {
+    Command::new("git")
+        .args(["status", "--porcelain", "--untracked-files=no"])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).lines().count())
+        .unwrap_or(0)
+}
+
+fn run_reset(args: &[String], verbose: u8, yes: bool) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+    let mode = reset_mode(args);
+
+    let discarded = if mode == ResetMode::Hard {
+        let discarded = count_tracked_changes();
+        // The prompt lists the files and the discard warning before asking
+        if !confirm_destructive("reset", args, yes)? {
+            return Err(declined(&with_subcommand("reset", args)));
+        }
+        discarded
+    } else {
+        0
+    };
+
+    if verbose > 0 {
+        eprintln!("git reset {}", args.join(" "));
+    }
+
+    let output = Command::new("git")
+        .arg("reset")
+        .args(args)
+        .output()
+        .context("Failed to run git reset")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let raw = format!("{}{}", stdout, stderr);
+
+    if !output.status.success() {
+        let err = CommandError::new("git", "reset", output.status.code(), &stderr);
+        timer.track(
+            &format!("git reset {}", args.join(" ")),
+            &format!("rtk git reset {}", args.join(" ")),
+            &raw,
+            &err.to_string(),
+        );
+        return Err(err.into());
+    }
+
+    // Report where HEAD actually ended up rather than echoing the argument
+    let head = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .context("Failed to run git rev-parse")?;
+    let head_stdout = String::from_utf8_lossy(&head.stdout);
+    let hash = first_line(&head_stdout).unwrap_or("HEAD");
+
+    let msg = format_reset_summary(&mode, hash, &stdout, discarded);
+    emit(&msg);
+
+    timer.track(
+        &format!("git reset {}", args.join(" ")),
+        &format!("rtk git reset {}", args.join(" ")),
+        &raw,
+        &msg,
+    );
+
+    Ok(())
+}
+
+/// git describe found no tag/name to describe from
+fn is_describe_no_names(stderr: &str) -> bool {
+    stderr.contains("No names found")
+        || stderr.contains("No tags can describe")
+        || stderr.contains("cannot describe")
+}
+
+fn first_line(output: &str) -> Option<&str> {
+    output.lines().map(|l| l.trim()).find(|l| !l.is_empty())
+}
+
+/// Suffix git describe appends for a dirty tree: `--dirty` → "-dirty",
+/// `--dirty=<mark>` → "<mark>"; None without the flag
+fn args_dirty_suffix(args: &[String]) -> Option<String> {
+    args.iter().find_map(|a| {
+        if a == "--dirty" {
+            Some("-dirty".to_string())
+        } else {
+            a.strip_prefix("--dirty=").map(|mark| mark.to_string())
+        }
+    })
+}
+
+/// Short hash standing in for a failed describe, keeping the dirty mark that
+/// `--dirty` would have added
+fn describe_fallback(hash: &str, dirty_suffix: Option<&str>, dirty: bool) -> String {
+    match dirty_suffix {
+        Some(mark) if dirty => format!("{}{}", hash, mark),
+        _ => hash.to_string(),
+    }
+}
+
+/// Tracked files differ from HEAD (what `git describe --dirty` checks)
+fn worktree_is_dirty() -> bool {
+    Command::new("git")
+        .args(["diff", "--quiet", "HEAD"])
+        .status()
+        .map(|s| !s.success())
+        .unwrap_or(false)
+}
+
+/// `git reflog` subcommands that manage entries instead of listing them
+const REFLOG_MANAGE_SUBCOMMANDS: &[&str] = &["expire", "delete", "exists"];
+
+fn run_reflog(args: &[String], verbose: u8) -> Result<()> {
+    // Nothing to compact (and `expire -n` means --dry-run): run them as is
+    if args
+        .first()
+        .is_some_and(|sub| REFLOG_MANAGE_SUBCOMMANDS.contains(&sub.as_str()))
+    {
+        let git_args: Vec<OsString> = with_subcommand("reflog", args)
+            .into_iter()
+            .map(OsString::from)
+            .collect();
+        return run_passthrough(&git_args, verbose, false);
+    }
+
+    let timer = tracking::TimedExecution::start();
+
+    // Extract -n N / -N / --max-count=N override, keep other args for git
+    let mut limit = 20;
+    let mut git_args: Vec<String> = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "-n" {
+            if let Some(n) = iter.next().and_then(|n| n.parse().ok()) {
+                limit = n;
+            }
+        } else if let Some(n) = arg
+            .strip_prefix("--max-count=")
+            .or_else(|| arg.strip_prefix('-').filter(|n| n.parse::<usize>().is_ok()))
+        {
+            limit = n.parse().unwrap_or(limit);
+        } else {
+            git_args.push(arg.clone());
+        }
+    }
+
+    if verbose > 0 {
+        eprintln!("git reflog (limit {})", limit);
+    }
+
+    let output = Command::new("git")
+        .args(reflog_list_args(&git_args, limit))
+        .output()
+        .context("Failed to run git reflog")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(CommandError::new("git", "reflog", output.status.code(), &stderr).into());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let filtered = filter_reflog_output(&stdout);
+    emit(&filtered);
+
+    timer.track(
+        &format!("git reflog {}", args.join(" ")),
+        &format!("rtk git reflog {}", args.join(" ")),
+        &stdout,
+        &filtered,
+    );
+
+    Ok(())
+}
+
+/// `git reflog [show] ...` with the compact flags after the `show` token,
+/// where git parses them as options of the subcommand
+fn reflog_list_args(args: &[String], limit: usize) -> Vec<String> {
+    let (show, rest) = match args.split_first() {
+        Some((first, rest)) if first == "show" => (Some(first), rest),
+        _ => (None, args),
+    };
+    let mut git_args = vec!["reflog".to_string()];
+    git_args.extend(show.cloned());
+    git_args.push("--date=relative".to_string());
+    git_args.push(format!("-n{}", limit));
+    git_args.extend(rest.iter().cloned());
+    git_args
+}
+
+fn filter_reflog_output(output: &str) -> String {
+    let lines: Vec<String> = output
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(format_reflog_line)
+        .collect();
+
+    if lines.is_empty() {
+        "No reflog entries".to_string()
+    } else {
+        lines.join("\n")
+    }
+}
+
+/// Reformat "abc1234 HEAD@{2 hours ago}: commit: message"
+/// into "abc1234 commit message (2 hours ago)"
+fn format_reflog_line(line: &str) -> String {
+    let (hash, rest) = match line.split_once(' ') {
+        Some(parts) => parts,
+        None => return line.to_string(),
+    };
+
+    // Selector: HEAD@{...}: — braces hold a relative date or an index
+    let (when, rest) = match rest.find("}: ") {
+        Some(end) if rest.contains("@{") => {
+            let start = rest.find("@{").unwrap_or(0) + 2;
+            let inner = rest.get(start..end).unwrap_or("");
+            let when = if inner.chars().all(|c| c.is_ascii_digit()) {
+                None
+            } else {
+                Some(inner)
+            };
+            (when, &rest[end + 3..])
+        }
+        _ => return line.to_string(),
+    };
+
+    let (action, message) = rest.split_once(": ").unwrap_or((rest, ""));
+    let mut formatted = format!("{} {}", hash, action);
+    if !message.is_empty() {
+        formatted.push(' ');
+        formatted.push_str(&truncate(message, 60));
+    }
+    if let Some(when) = when {
+        formatted.push_str(&format!(" ({})", when));
+    }
+    formatted
+}
+
+const TAG_LIST_LIMIT: usize = 20;
+
+fn run_tag(args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let is_delete = args.iter().any(|a| a == "-d" || a == "--delete");
+    // --contains HEAD, --sort, -n ... only make sense when listing
+    let (list_flags, rest) = split_tag_list_flags(args);
+    let is_list = list_flags.is_some() || rest.iter().any(|a| a == "-l" || a == "--list");
+    let names = tag_names(&rest);
+
+    // Create/delete: -a, -d, -m, or a bare tag name
+    let has_action = is_delete
+        || rest.iter().any(|a| {
+            a == "-a" || a == "-m" || a == "-s" || a == "-f" || a.starts_with("--annotate")
+        })
+        || (!is_list && !names.is_empty());
+
+    if has_action {
+        if verbose > 0 {
+            eprintln!("git tag {}", args.join(" "));
+        }
+
+        let output = Command::new("git")
+            .arg("tag")
+            .args(args)
+            .output()
+            .context("Failed to run git tag")?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let combined = format!("{}{}", stdout, stderr);
+
+        let msg = if !output.status.success() {
+            combined.clone()
+        } else if is_delete {
+            format!("ok ✓ deleted {}", names.join(" "))
+        } else {
+            format!(
+                "ok ✓ tagged {}",
+                names.first().map(|s| s.as_str()).unwrap_or("")
+            )
+        };
+
+        timer.track(
+            &format!("git tag {}", args.join(" ")),
+            &format!("rtk git tag {}", args.join(" ")),
+            &combined,
+            &msg,
+        );
+
+        if !output.status.success() {
+            let subcommand = format!("tag {}", args.join(" "));
+            return Err(
+                CommandError::new("git", &subcommand, output.status.code(), &stderr).into(),
+            );
+        }
+        emit(&msg);
+        return Ok(());
+    }
+
+    // List mode: newest first, with short hash and relative date
+    if verbose > 0 {
+        eprintln!("git tag list");
+    }
+
+    let mut cmd = Command::new("git");
+    cmd.args(tag_list_args(&list_flags.unwrap_or_default(), &names));
+
+    let output = cmd.output().context("Failed to run git for-each-ref")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(CommandError::new("git", "tag", output.status.code(), &stderr).into());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let filtered = format_tag_list(&stdout, TAG_LIST_LIMIT);
+    emit(&filtered);
+
+    timer.track(
+        &format!("git tag {}", args.join(" ")),
+        &format!("rtk git tag {}", args.join(" ")),
+        &stdout,
+        &filtered,
+    );
+
+    Ok(())
+}
+
+/// Positional (non-flag) arguments of `git tag`: tag names, commits or list patterns
+fn tag_names(args: &[String]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "-m" || arg == "-F" || arg == "-u" || arg == "--sort" {
+            iter.next(); // Skip flag value
+        } else if !arg.starts_with('-') {
+            names.push(arg.clone());
+        }
+    }
+    names
+}
+
+/// `git tag` list options that take a value (optional for all but
+/// `--points-at` and `--sort`, as in git itself)
+const TAG_LIST_VALUE_FLAGS: &[&str] = &[
+    "--contains",
+    "--no-contains",
+    "--points-at",
+    "--merged",
+    "--no-merged",
+    "--sort",
+];
+
+/// Split list-only options out of `git tag` args. Returns the ones for-each-ref
+/// understands (Some even when empty, e.g. for `-n`, so the caller knows the
+/// user asked for a listing) and the remaining args.
+fn split_tag_list_flags(args: &[String]) -> (Option<Vec<String>>, Vec<String>) {
+    let mut flags: Option<Vec<String>> = None;
+    let mut rest = Vec::new();
+    let mut iter = args.iter().peekable();
+    while let Some(arg) = iter.next() {
+        let name = arg.split('=').next().unwrap_or(arg);
+        if TAG_LIST_VALUE_FLAGS.contains(&name) {
+            let forwarded = flags.get_or_insert_with(Vec::new);
+            forwarded.push(arg.clone());
+            if !arg.contains('=') {
+                let takes_next = match iter.peek() {
+                    Some(next) => !next.starts_with('-'),
+                    None => false,
+                };
+                if takes_next {
+                    forwarded.push(iter.next().cloned().unwrap_or_default());
+                }
+            }
+        } else if arg == "-i" || arg == "--ignore-case" {
+            flags
+                .get_or_insert_with(Vec::new)
+                .push("--ignore-case".to_string());
+        } else if arg.starts_with("-n") && arg[2..].chars().all(|c| c.is_ascii_digit()) {
+            // Annotation lines: no for-each-ref equivalent in the compact format
+            flags.get_or_insert_with(Vec::new);
+        } else if arg == "--column" || arg == "--no-column" {
+            flags.get_or_insert_with(Vec::new);
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+    (flags, rest)
+}
+
+/// for-each-ref args for tag list mode: newest first unless the user passed
+/// `--sort` (later keys take precedence), then filters and patterns
+fn tag_list_args(filters: &[String], patterns: &[String]) -> Vec<String> {
+    let mut args = vec![
+        "for-each-ref".to_string(),
+        "--sort=-creatordate".to_string(),
+        "--format=%(refname:short)%09%(objectname:short)%09%(*objectname:short)%09%(creatordate:relative)".to_string(),
+    ];
+    args.extend(filters.iter().cloned());
+    if patterns.is_empty() {
+        args.push("refs/tags".to_string());
+    } else {
+        for pattern in patterns {
+            args.push(format!("refs/tags/{}", pattern));
+        }
+    }
+    args
+}
+
+/// Format tab-separated for-each-ref lines "name\thash\tpeeled\twhen"
+/// into aligned "v2.3.1  abc1234  (2 weeks ago)" rows
+fn format_tag_list(output: &str, limit: usize) -> String {
+    let tags: Vec<(&str, &str, &str)> = output
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|line| {
+            let mut parts = line.split('\t');
+            let name = parts.next().unwrap_or("");
+            let hash = parts.next().unwrap_or("");
+            // Annotated tags: prefer the peeled commit hash over the tag object
+            let hash = match parts.next() {
+                Some(peeled) if !peeled.is_empty() => peeled,
+                _ => hash,
+            };
+            let when = parts.next().unwrap_or("");
+            (name, hash, when)
+        })
+        .collect();
+
+    if tags.is_empty() {
+        return "No tags".to_string();
+    }
+
+    let width = tags
+        .iter()
+        .take(limit)
+        .map(|(name, _, _)| name.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let mut result: Vec<String> = tags
+        .iter()
+        .take(limit)
+        .map(|(name, hash, when)| {
+            if when.is_empty() {
+                format!("{:<width$}  {}", name, hash, width = width)
+            } else {
+                format!("{:<width$}  {}  ({})", name, hash, when, width = width)
+            }
+        })
+        .collect();
+
+    if tags.len() > limit {
+        result.push(format!("... +{} more", tags.len() - limit));
+    }
+
+    result.join("\n")
+}
+
+/// Runs an unsupported git subcommand by passing it through directly
+pub fn run_passthrough(args: &[OsString], verbose: u8, yes: bool) -> Result<()> {
+    run_passthrough_with(
+        &SystemRunner,
+        args,
+        verbose,
+        yes,
+        term::stdout_is_terminal(),
+    )
+}
+
+/// Passthroughs that may open an editor or an interactive prompt; their
+/// stdio is never captured, even when stdout is piped.
+fn is_interactive(args: &[String]) -> bool {
+    let Some((subcommand, rest)) = args.split_first() else {
+        return false;
+    };
+    let has = |flags: &[&str]| rest.iter().any(|a| flags.contains(&a.as_str()));
+    match subcommand.as_str() {
+        "commit" | "merge" | "revert" | "mergetool" | "difftool" => true,
+        "rebase" => has(&["-i", "--interactive", "--continue", "--edit-todo"]),
+        "add" | "checkout" | "reset" | "restore" | "stash" | "clean" => {
+            has(&["-p", "--patch", "-i", "--interactive"])
+        }
+        "cherry-pick" | "tag" => has(&["-e", "--edit", "-a", "--annotate"]),
+        _ => false,
+    }
+}
+
+fn run_passthrough_with(
+    runner: &impl CommandRunner,
+    args: &[OsString],
+    verbose: u8,
+    yes: bool,
+    terminal: bool,
+) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if verbose > 0 {
+        eprintln!("git passthrough: {:?}", args);
+    }
+
+    let confirms = args
+        .first()
+        .is_some_and(|sub| CONFIRMED_SUBCOMMANDS.iter().any(|c| sub == *c));
+    let trailing_yes = confirms && args.iter().any(|a| a == "--yes");
+    let yes = yes || trailing_yes;
+    let args: Vec<OsString> = args
+        .iter()
+        .filter(|a| !(trailing_yes && *a == "--yes"))
+        .cloned()
+        .collect();
+    let str_args: Vec<String> = args
+        .iter()
+        .map(|a| a.to_string_lossy().into_owned())
+        .collect();
+    if let Some((subcommand, rest)) = str_args.split_first() {
+        if !confirm_destructive(subcommand, rest, yes)? {
+            return Err(declined(&str_args));
+        }
+    }
+    let subcommand = str_args.first().map(|s| s.as_str()).unwrap_or("");
+
+    let mut cmd = Command::new("git");
+    if !term::should_colorize() {
+        cmd.args(["-c", "color.ui=never"]);
+    }
+    cmd.args(&args);
+    let args_str = tracking::args_display(&args);
+
+    // On a terminal (or for editors/prompts) git keeps the real stdio:
+    // capturing would drop the TTY and hold back progress until exit
+    if terminal || is_interactive(&str_args) {
+        let status = runner.status(&mut cmd).context("Failed to run git")?;
+        timer.track_passthrough(
+            &format!("git {}", args_str),
+            &format!("rtk git {} (passthrough)", args_str),
+        );
+        if !status.success() {
+            // git already printed its error to the terminal
+            return Err(CommandError::with_excerpt(
+                "git",
+                subcommand,
+                status.code(),
+                String::new(),
+            )
+            .into());
+        }
+        return Ok(());
+    }
+
+    // Piped: prompts still read the terminal; stdout goes through the caps
+    cmd.stdin(Stdio::inherit());
+    let output = runner.output(&mut cmd).context("Failed to run git")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // Progress and warnings first, as git would have shown them
+    if output.status.success() {
+        eprint!("{}", stderr);
+    }
+    // Unfiltered, but still under the global output caps
+    if !stdout.trim().is_empty() {
+        emit(stdout.trim_end());
+    }
+
+    timer.track_passthrough(
+        &format!("git {}", args_str),
+        &format!("rtk git {} (passthrough)", args_str),
+    );
+
+    if !output.status.success() {
+        return Err(CommandError::new("git", subcommand, output.status.code(), &stderr).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exec::mock::MockRunner;
+    use crate::utils::capture;
+
+    #[test]
+    fn test_colorize_diff_wraps_only_hunk_lines() {
+        let compacted = "\n📄 src/a.rs\n  @@ -1,2 +1,2 @@\n  -old\n  +new\n   ctx\n  +1 -1";
+        let colored = colorize_diff(compacted);
+        assert!(colored.contains("\x1b[31m  -old\x1b[0m"));
+        assert!(colored.contains("\x1b[32m  +new\x1b[0m"));
+        assert!(colored.contains("\n  +1 -1"));
+        assert!(!colored.contains("\x1b[32m  +1 -1"));
+        // Plain text is unchanged once the escapes are stripped
+        assert_eq!(crate::utils::strip_ansi(&colored), compacted);
+    }
+
+    #[test]
+    fn test_extract_watch_interval() {
+        let args: Vec<String> = vec!["--watch".into()];
+        assert_eq!(extract_watch_interval(&args), (Some(2), vec![]));
+
+        let args: Vec<String> = vec!["--watch=5".into(), "-s".into()];
+        assert_eq!(
+            extract_watch_interval(&args),
+            (Some(5), vec!["-s".to_string()])
+        );
+
+        // Zero or garbage falls back to the default interval
+        let args: Vec<String> = vec!["--watch=0".into()];
+        assert_eq!(extract_watch_interval(&args).0, Some(2));
+
+        let args: Vec<String> = vec!["-s".into()];
+        assert_eq!(
+            extract_watch_interval(&args),
+            (None, vec!["-s".to_string()])
+        );
+
+        // Space-separated interval; a non-numeric next arg is a pathspec
+        let args: Vec<String> = vec!["--watch".into(), "10".into(), "src/".into()];
+        assert_eq!(
+            extract_watch_interval(&args),
+            (Some(10), vec!["src/".to_string()])
+        );
+        let args: Vec<String> = vec!["--watch".into(), "src/".into()];
+        assert_eq!(
+            extract_watch_interval(&args),
+            (Some(2), vec!["src/".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_watch_status_args_keep_user_args() {
+        let args: Vec<String> = vec!["--watch".into(), "5".into(), "-uno".into(), "src/".into()];
+        let (_, rest) = extract_watch_interval(&args);
+        assert_eq!(
+            watch_status_args(&rest),
+            vec!["status", "--porcelain", "-b", "-uno", "src/"]
+        );
+    }
+
+    #[test]
+    fn test_collapse_log_stats() {
+        let output = "abc1234 Fix parser (2 days ago) <Alice>\n \
+                      src/parser.rs | 12 +++++++++---\n \
+                      src/lib.rs    |  3 +++\n \
+                      2 files changed, 12 insertions(+), 3 deletions(-)\n\
+                      \n\
+                      def5678 Remove dead code (3 days ago) <Bob>\n \
+                      src/old.rs | 40 ----------------------------------------\n \
+                      1 file changed, 40 deletions(-)\n\
+                      \n\
+                      0123456 Empty commit (4 days ago) <Carol>\n";
+        assert_eq!(
+            collapse_log_stats(output, 10),
+            "abc1234 Fix parser (2 days ago) <Alice>  +12 -3 across 2 files\n\
+             def5678 Remove dead code (3 days ago) <Bob>  +0 -40 across 1 file\n\
+             0123456 Empty commit (4 days ago) <Carol>"
+        );
+        assert_eq!(collapse_log_stats(output, 1).lines().count(), 1);
+        assert_eq!(
+            parse_shortstat(" 1 file changed, 5 insertions(+)"),
+            Some((1, 5, 0))
+        );
+        assert_eq!(parse_shortstat(" src/changed.rs | 2 +-"), None);
+    }
+
+    #[test]
+    fn test_describe_dirty_suffix() {
+        let args: Vec<String> = vec!["--dirty".into()];
+        assert_eq!(args_dirty_suffix(&args).as_deref(), Some("-dirty"));
+        let args: Vec<String> = vec!["--dirty=-modified".into(), "--abbrev=10".into()];
+        assert_eq!(args_dirty_suffix(&args).as_deref(), Some("-modified"));
+        assert_eq!(args_dirty_suffix(&[]), None);
+
+        // git's own output already carries the mark and is printed as-is
+        assert_eq!(
+            first_line("v1.2.3-5-gabc1234-dirty\n"),
+            Some("v1.2.3-5-gabc1234-dirty")
+        );
+
+        assert_eq!(
+            describe_fallback("abc1234", Some("-dirty"), true),
+            "abc1234-dirty"
+        );
+        assert_eq!(
+            describe_fallback("abc1234", Some("-dirty"), false),
+            "abc1234"
+        );
+        assert_eq!(describe_fallback("abc1234", None, true), "abc1234");
+        assert!(is_describe_no_names(
+            "fatal: No names found, cannot describe anything.\n"
+        ));
+        assert!(!is_describe_no_names(
+            "fatal: Not a valid object name nope\n"
+        ));
+    }
+
+    #[test]
+    fn test_format_hook_failure() {
+        let mut stderr = String::from("husky - running pre-commit hook\n> lint-staged\n");
+        for i in 1..=12 {
+            stderr.push_str(&format!("src/app.ts:{}:1  error  Unexpected any\n", i));
+        }
+        stderr.push_str(
+            "\n✖ 12 problems (12 errors, 0 warnings)\nhusky - pre-commit script failed (code 1)\n",
+        );
+
+        assert!(!is_git_own_failure(&stderr));
+        let result = format_hook_failure(&stderr);
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "✗ pre-commit hook failed:");
+        assert_eq!(lines[1], "  ... 6 earlier lines");
+        assert_eq!(lines.len(), 2 + HOOK_TAIL_LINES);
+        assert_eq!(
+            lines.last(),
+            Some(&"  husky - pre-commit script failed (code 1)")
+        );
+        assert!(!result.contains("running pre-commit hook"));
+
+        let err = CommandError::with_excerpt("git", "commit", Some(1), result);
+        assert_eq!(crate::utils::exit_code(&err.into()), 1);
+
+        assert!(is_git_own_failure(
+            "error: Committing is not possible because you have unmerged files.\n"
+        ));
+        assert!(is_git_own_failure(
+            "fatal: unable to auto-detect email address\n"
+        ));
+    }
+
+    /// Run a handler against a throwaway tracking database; returns what it
+    /// emitted and the rtk commands it recorded
+    fn run_tracked(f: impl FnOnce()) -> (String, Vec<String>) {
+        let dir = tempfile::tempdir().unwrap();
+        tracking::with_db_path(&dir.path().join("history.db"), || {
+            let out = capture::emitted(None, None, f);
+            let recorded = tracking::Tracker::new()
+                .and_then(|t| t.get_recent(10))
+                .map(|recent| recent.into_iter().map(|r| r.rtk_cmd).collect())
+                .unwrap_or_default();
+            (out, recorded)
+        })
+    }
+
+    #[test]
+    fn test_run_push_reports_pushed_ref() {
+        let stderr = "To github.com:o/r.git\n   1a2b3c4..5d6e7f8  main -> main\n";
+        let runner = MockRunner::new().respond(0, "", stderr);
+        let args = vec!["origin".to_string(), "main".to_string()];
+        let (out, recorded) = run_tracked(|| run_push_with(&runner, &args, 0).unwrap());
+        assert_eq!(runner.calls(), vec!["git push origin main"]);
+        assert_eq!(out, "ok ✓ main\n");
+        assert_eq!(recorded, vec!["rtk git push origin main"]);
+        assert_eq!(format_push_success(stderr), "ok ✓ main");
+        assert_eq!(
+            format_push_success("Everything up-to-date\n"),
+            "ok (up-to-date)"
+        );
+    }
+
+    #[test]
+    fn test_run_push_failure_keeps_git_exit_code() {
+        let stderr = " ! [rejected]        main -> main (fetch first)\n\
+                      error: failed to push some refs to 'github.com:o/r.git'\n\
+                      hint: Updates were rejected because the remote contains work\n";
+        let runner = MockRunner::new().respond(1, "", stderr);
+        let err = run_push_with(&runner, &[], 0).unwrap_err();
+        assert_eq!(crate::utils::exit_code(&err), 1);
+        let message = err.to_string();
+        assert!(message.starts_with("FAILED: git push\n"));
+        assert!(message.contains("[rejected]"));
+        assert!(!message.contains("hint:"));
+    }
+
+    #[test]
+    fn test_run_push_no_upstream() {
+        let stderr = "fatal: The current branch feat has no upstream branch.\n\
+                      To push the current branch and set the remote as upstream, use\n\
+                      \n    git push --set-upstream origin feat\n";
+        assert!(is_no_upstream(stderr));
+        assert!(!is_no_upstream(
+            "error: failed to push some refs to 'github.com:o/r.git'\n"
+        ));
+
+        // Without -u: report the failure, no retry
+        let runner = MockRunner::new().respond(128, "", stderr);
+        assert!(run_push_with(&runner, &[], 0).is_err());
+        assert_eq!(runner.calls(), vec!["git push"]);
+
+        // With -u: retry against origin/<current branch>
+        let runner = MockRunner::new()
+            .respond(128, "", stderr)
+            .respond(0, "feat\n", "")
+            .respond(
+                0,
+                "",
+                "To github.com:o/r.git\n * [new branch]      feat -> feat\n",
+            );
+        let args = vec!["-u".to_string()];
+        let (out, recorded) = run_tracked(|| run_push_with(&runner, &args, 0).unwrap());
+        assert_eq!(out, "ok ✓ feat\n");
+        assert_eq!(recorded, vec!["rtk git push -u"]);
+        assert_eq!(
+            runner.calls(),
+            vec![
+                "git push -u",
+                "git symbolic-ref --short HEAD",
+                "git push -u origin feat"
+            ]
+        );
+
+        // A named remote is kept; only the branch is added
+        let runner = MockRunner::new()
+            .respond(128, "", stderr)
+            .respond(0, "feat\n", "")
+            .respond(
+                0,
+                "",
+                "To fork:o/r.git\n * [new branch]      feat -> feat\n",
+            );
+        let args = sargs(&["-u", "-o", "ci.skip", "fork"]);
+        run_tracked(|| run_push_with(&runner, &args, 0).unwrap());
+        assert_eq!(
+            runner.calls(),
+            vec![
+                "git push -u -o ci.skip fork",
+                "git symbolic-ref --short HEAD",
+                "git push -u -o ci.skip fork feat"
+            ]
+        );
+
+        // Remote and refspec given: nothing to add, no retry
+        let runner = MockRunner::new().respond(128, "", stderr);
+        let args = sargs(&["-u", "fork", "HEAD"]);
+        assert!(run_push_with(&runner, &args, 0).is_err());
+        assert_eq!(runner.calls(), vec!["git push -u fork HEAD"]);
+    }
+
+    #[test]
+    fn test_run_fetch_failure_and_success() {
+        let runner = MockRunner::new()
+            .respond(
+                128,
+                "",
+                "fatal: 'nope' does not appear to be a git repository\n",
+            )
+            .respond(
+                0,
+                "",
+                "From github.com:o/r\n * [new branch]      feat       -> origin/feat\n",
+            );
+
+        let (out, recorded) = run_tracked(|| {
+            let err = run_fetch_with(&runner, &["nope".to_string()], 0).unwrap_err();
+            assert_eq!(crate::utils::exit_code(&err), 128);
+            assert!(err
+                .to_string()
+                .contains("does not appear to be a git repository"));
+        });
+        assert_eq!(out, "");
+        assert!(recorded.is_empty());
+
+        let (out, recorded) = run_tracked(|| run_fetch_with(&runner, &[], 0).unwrap());
+        assert_eq!(out, "ok fetched (1 new refs)\n");
+        assert_eq!(recorded, vec!["rtk git fetch"]);
+        assert_eq!(runner.calls(), vec!["git fetch nope", "git fetch"]);
+    }
+
+    #[test]
+    fn test_run_pull_summarizes_changes() {
+        let runner = MockRunner::new()
+            .respond(
+                0,
+                "Updating 1a2b3c4..5d6e7f8\nFast-forward\n src/a.rs | 3 ++-\n \
+                 2 files changed, 10 insertions(+), 2 deletions(-)\n",
+                "",
+            )
+            .respond(0, "Already up to date.\n", "")
+            .respond(
+                1,
+                "",
+                "error: Your local changes would be overwritten by merge\n",
+            );
+        let args = vec!["--ff-only".to_string()];
+
+        let (out, recorded) = run_tracked(|| run_pull_with(&runner, &args, 0).unwrap());
+        assert_eq!(out, "ok ✓ 2 files +10 -2\n");
+        assert_eq!(recorded, vec!["rtk git pull --ff-only"]);
+
+        let (out, _) = run_tracked(|| run_pull_with(&runner, &[], 0).unwrap());
+        assert_eq!(out, "ok (up-to-date)\n");
+
+        let (out, recorded) = run_tracked(|| {
+            let err = run_pull_with(&runner, &[], 0).unwrap_err();
+            assert_eq!(crate::utils::exit_code(&err), 1);
+            assert!(err.to_string().contains("would be overwritten"));
+        });
+        assert_eq!(out, "");
+        assert!(recorded.is_empty());
+        assert_eq!(
+            runner.calls(),
+            vec!["git pull --ff-only", "git pull", "git pull"]
+        );
+    }
+
+    #[test]
+    fn test_passthrough_output_is_capped() {
+        let stdout = "abc1234 commit subject\n".repeat(10);
+        let runner = MockRunner::new().respond(0, &stdout, "");
+        let args: Vec<OsString> = vec!["log".into(), "--oneline".into()];
+        let dir = tempfile::tempdir().unwrap();
+        let out = tracking::with_db_path(&dir.path().join("history.db"), || {
+            capture::emitted(None, Some(50), || {
+                run_passthrough_with(&runner, &args, 0, false, false).unwrap();
+            })
+        });
+        assert!(out.starts_with("abc1234 commit subject\nabc1234 commit subject\n"));
+        assert!(out.contains("... [output truncated: 184 bytes hidden"));
+
+        // Failures keep git's exit code and stderr excerpt
+        let runner = MockRunner::new().respond(128, "", "fatal: bad revision 'nope'\n");
+        let args: Vec<OsString> = vec!["log".into(), "nope".into()];
+        let err = run_passthrough_with(&runner, &args, 0, false, false).unwrap_err();
+        assert_eq!(crate::utils::exit_code(&err), 128);
+        assert!(err.to_string().contains("bad revision"));
+    }
+
+    #[test]
+    fn test_passthrough_keeps_stdio_on_terminal_or_interactive() {
+        let dir = tempfile::tempdir().unwrap();
+        tracking::with_db_path(&dir.path().join("history.db"), || {
+            // Terminal: git writes straight to it, nothing is captured
+            let runner = MockRunner::new().respond(0, "", "");
+            let args: Vec<OsString> = vec!["log".into(), "--oneline".into()];
+            let out = capture::emitted(None, None, || {
+                run_passthrough_with(&runner, &args, 0, false, true).unwrap();
+            });
+            assert_eq!(runner.inherited(), runner.calls());
+            assert_eq!(out, "");
+
+            // Editors need the TTY even when stdout is piped
+            let runner = MockRunner::new().respond(1, "", "");
+            let args: Vec<OsString> = vec!["rebase".into(), "-i".into(), "HEAD~2".into()];
+            let err = run_passthrough_with(&runner, &args, 0, false, false).unwrap_err();
+            assert!(runner.inherited()[0].ends_with(" rebase -i HEAD~2"));
+            assert_eq!(crate::utils::exit_code(&err), 1);
+
+            // Piped and non-interactive: captured and routed through emit
+            let runner = MockRunner::new().respond(0, "abc1234 subject\n", "");
+            let args: Vec<OsString> = vec!["log".into(), "--oneline".into()];
+            let out = capture::emitted(None, None, || {
+                run_passthrough_with(&runner, &args, 0, false, false).unwrap();
+            });
+            assert!(runner.inherited().is_empty());
+            assert_eq!(out, "abc1234 subject\n");
+        });
+    }
+
+    #[test]
+    fn test_is_interactive() {
+        assert!(is_interactive(&sargs(&["commit", "--amend"])));
+        assert!(is_interactive(&sargs(&["add", "-p", "src"])));
+        assert!(is_interactive(&sargs(&["rebase", "--continue"])));
+        assert!(!is_interactive(&sargs(&["rebase", "main"])));
+        assert!(!is_interactive(&sargs(&["log", "-p"])));
+        assert!(!is_interactive(&[]));
+    }
+
+    #[test]
+    fn test_fetch_status_ahead_behind() {
+        assert_eq!(format_upstream_drift(0, 3), "→ 3 behind upstream");
+        assert_eq!(format_upstream_drift(2, 0), "→ 2 ahead of upstream");
+        assert_eq!(format_upstream_drift(1, 3), "→ 1 ahead, 3 behind upstream");
+        assert_eq!(format_upstream_drift(0, 0), "→ up to date with upstream");
+
+        let runner = MockRunner::new()
+            .respond(0, "", " * [new branch]      feat       -> origin/feat\n")
+            .respond(0, "1\t3\n", "");
+        run_fetch_with(&runner, &sargs(&["--status"]), 0).unwrap();
+        assert_eq!(
+            runner.calls(),
+            vec!["git fetch", "git rev-list --left-right --count HEAD...@{u}"]
+        );
+
+        // No upstream: the annotation is omitted, not an error
+        let runner = MockRunner::new().respond(128, "", "fatal: no upstream configured\n");
+        assert_eq!(upstream_ahead_behind(&runner), None);
+        let runner = MockRunner::new().respond(0, "1\t3\n", "");
+        assert_eq!(upstream_ahead_behind(&runner), Some((1, 3)));
+    }
+
+    #[test]
+    fn test_format_hunk_header_keeps_function_context() {
+        assert_eq!(
+            format_hunk_header("@@ -10,6 +10,7 @@ fn process()"),
+            "  @@ -10,6 +10,7 @@ fn process()"
+        );
+        assert_eq!(format_hunk_header("@@ -1 +1 @@"), "  @@ -1 +1 @@");
+    }
+
+    #[test]
+    fn test_compact_diff_keeps_function_context() {
+        let diff = "diff --git a/src/a.rs b/src/a.rs\n\
+                    --- a/src/a.rs\n\
+                    +++ b/src/a.rs\n\
+                    @@ -10,6 +10,7 @@ fn process(input: &str) -> Result<()> {\n\
+                    +    validate(input)?;\n";
+        let result = compact_diff(diff, 100);
+        assert!(result.contains("  @@ -10,6 +10,7 @@ fn process(input: &str) -> Result<()> {\n"));
+    }
+
+    #[test]
+    fn test_compact_diff_zero_hunk_budget_lists_files_and_totals() {
+        let diff = "diff --git a/src/a.rs b/src/a.rs\n\
+                    --- a/src/a.rs\n\
+                    +++ b/src/a.rs\n\
+                    @@ -1,3 +1,3 @@ fn main()\n \
+                    ctx\n\
+                    -old\n\
+                    +new\n\
+                    +more\n\
+                    diff --git a/src/b.rs b/src/b.rs\n\
+                    --- a/src/b.rs\n\
+                    +++ b/src/b.rs\n\
+                    @@ -5,2 +5,1 @@\n\
+                    -gone\n";
+        let result = compact_diff_with(diff, 100, 0, Glyphs::get(false));
+        assert_eq!(result, "\n📄 src/a.rs\n  +2 -1\n\n📄 src/b.rs\n  +0 -1");
+    }
+
+    #[test]
+    fn test_show_dispatches_on_object_type() {
+        // Commits fall through to the regular summary/stat/diff path
+        let runner = MockRunner::new().respond(0, "commit\n", "");
+        assert_eq!(show_non_commit(&runner, &sargs(&["HEAD~1"])), None);
+        assert_eq!(runner.calls(), vec!["git cat-file -t HEAD~1"]);
+
+        let runner = MockRunner::new().respond(0, "tree\n", "").respond(
+            0,
+            "040000 tree 1111111111111111111111111111111111111111       -\tsrc\n\
+             100644 blob 2222222222222222222222222222222222222222    1234\tCargo.toml\n",
+            "",
+        );
+        assert_eq!(
+            show_non_commit(&runner, &sargs(&["HEAD^{tree}"])).unwrap(),
+            "tree HEAD^{tree}: 1 dirs, 1 files\nsrc/\nCargo.toml  1.2K"
+        );
+        assert_eq!(runner.calls()[1], "git ls-tree -l HEAD^{tree}");
+
+        let runner = MockRunner::new().respond(0, "tag\n", "").respond(
+            0,
+            "object 0123456789abcdef0123456789abcdef01234567\n\
+                 type commit\n\
+                 tag v1.0\n\
+                 tagger Alice <alice@example.com> 1700000000 +0000\n\
+                 \n\
+                 Release 1.0\n\
+                 -----BEGIN PGP SIGNATURE-----\n\
+                 abc\n",
+            "",
+        );
+        assert_eq!(
+            show_non_commit(&runner, &sargs(&["v1.0"])).unwrap(),
+            "tag v1.0 → commit 0123456 (Alice)\n  Release 1.0"
+        );
+
+        let content = (1..=12)
+            .map(|i| format!("line {}\n", i))
+            .collect::<String>();
+        let runner = MockRunner::new()
+            .respond(0, "blob\n", "")
+            .respond(0, &content, "");
+        let rendered = show_non_commit(&runner, &sargs(&["HEAD:notes.txt"])).unwrap();
+        assert!(rendered.starts_with("blob HEAD:notes.txt: 87B, 12 lines\nline 1\n"));
+        assert!(rendered.ends_with("line 10\n... +2 more lines"));
+
+        // Unknown ref: let git show report the error
+        let runner = MockRunner::new().respond(128, "", "fatal: Not a valid object name");
+        assert_eq!(show_non_commit(&runner, &sargs(&["nope"])), None);
+    }
+
+    #[test]
+    fn test_markdown_diff_fences_each_file() {
+        let diff = "diff --git a/src/a.rs b/src/a.rs\n\
+                    --- a/src/a.rs\n\
+                    +++ b/src/a.rs\n\
+                    @@ -1,3 +1,3 @@ fn main()\n \
+                    ctx\n\
+                    -old\n\
+                    +new\n\
+                    diff --git a/src/b.rs b/src/b.rs\n\
+                    --- a/src/b.rs\n\
+                    +++ b/src/b.rs\n\
+                    @@ -5,2 +5,1 @@\n\
+                    -gone\n";
+        let glyphs = Glyphs::get(false);
+        let compacted = compact_diff_with(diff, 100, DIFF_HUNK_LINES, glyphs);
+        let result = markdown_diff(&compacted, glyphs);
+        assert_eq!(
+            result,
+            "### src/a.rs (+1 -1)\n\n```diff\n@@ -1,3 +1,3 @@ fn main()\n-old\n+new\n```\n\n\
+             ### src/b.rs (+0 -1)\n\n```diff\n@@ -5,2 +5,1 @@\n-gone\n```"
+        );
+        assert_eq!(result.matches("```diff").count(), 2);
+        assert!(!result.contains("📄"));
+    }
+
+    #[test]
+    fn test_compact_diff_suppresses_lockfiles() {
+        let diff = "diff --git a/pnpm-lock.yaml b/pnpm-lock.yaml\n\
+                    --- a/pnpm-lock.yaml\n\
+                    +++ b/pnpm-lock.yaml\n\
+                    @@ -10,3 +10,4 @@ packages:\n\
+                    -  zod@3.22.0:\n\
+                    +  zod@3.23.8:\n\
+                    +    resolution: {integrity: sha512-abc}\n\
+                    diff --git a/src/a.rs b/src/a.rs\n\
+                    --- a/src/a.rs\n\
+                    +++ b/src/a.rs\n\
+                    @@ -1,2 +1,2 @@\n\
+                    -old\n\
+                    +new\n";
+        let result = compact_diff_with(diff, 100, DIFF_HUNK_LINES, Glyphs::get(false));
+        assert_eq!(
+            result,
+            "\n📄 pnpm-lock.yaml (+2 -1, suppressed)\n\
+             \n📄 src/a.rs\n  @@ -1,2 +1,2 @@\n  -old\n  +new\n  +1 -1"
+        );
+
+        assert!(is_noisy_diff_path("web/package-lock.json"));
+        assert!(is_noisy_diff_path("dist/app.min.js"));
+        assert!(is_noisy_diff_path("tests/__snapshots__/view.test.ts.snap"));
+        assert!(!is_noisy_diff_path("src/lock.rs"));
+    }
+
+    #[test]
+    fn test_compact_diff() {
+        let diff = r#"diff --git a/foo.rs b/foo.rs
+--- a/foo.rs
++++ b/foo.rs
+@@ -1,3 +1,4 @@
+ fn main() {
++    println!("hello");
+ }
+"#;
+        let result = compact_diff(diff, 100);
+        assert!(result.contains("foo.rs"));
+        assert!(result.contains("+"));
+    }
+
+    #[test]
+    fn test_compact_diff_pure_rename() {
+        let diff = "diff --git a/src/old.rs b/src/new.rs
+similarity index 100%
+rename from src/old.rs
+rename to src/new.rs
+";
+        let result = compact_diff(diff, 100);
+        assert!(result.contains("📄 src/old.rs → src/new.rs (rename 100%)"));
+        assert!(!result.contains("similarity index"));
+        assert!(!result.contains("rename from"));
+        assert!(!result.contains("+0 -0"));
+    }
+
+    #[test]
+    fn test_compact_diff_rename_with_edits() {
+        let diff = "diff --git a/lib/a.rs b/lib/b.rs
+similarity index 92%
+rename from lib/a.rs
+rename to lib/b.rs
+index 1234567..89abcde 100644
+--- a/lib/a.rs
++++ b/lib/b.rs
+@@ -1,3 +1,3 @@
+ fn main() {
+-    old();
++    new();
+ }
+";
+        let result = compact_diff(diff, 100);
+        assert!(result.contains("📄 lib/a.rs → lib/b.rs (rename 92%)"));
+        assert!(result.contains("+    new();"));
+        assert!(result.contains("+1 -1"));
+        assert!(!result.contains("rename to"));
+    }
+
+    #[test]
+    fn test_filter_branch_output() {
+        let output = "* main\n  feature/auth\n  fix/bug-123\n  remotes/origin/HEAD -> origin/main\n  remotes/origin/main\n  remotes/origin/feature/auth\n  remotes/origin/release/v2\n";
+        let result = filter_branch_output(output, &HashMap::new());
+        assert!(result.contains("* main"));
+        assert!(result.contains("feature/auth"));
+        assert!(result.contains("fix/bug-123"));
+        // remote-only should show release/v2 but not main or feature/auth (already local)
+        assert!(result.contains("remote-only"));
+        assert!(result.contains("release/v2"));
+    }
+
+    #[test]
+    fn test_filter_branch_no_remotes() {
+        let output = "* main\n  develop\n";
+        let result = filter_branch_output(output, &HashMap::new());
+        assert!(result.contains("* main"));
+        assert!(result.contains("develop"));
+        assert!(!result.contains("remote-only"));
+    }
+
+    #[test]
+    fn test_filter_merged_branches_skips_protected() {
+        let output = "  feat/login\n  fix/typo\n  main\n* master\n+ wt-branch\n";
+        assert_eq!(merge_candidates(output), vec!["feat/login", "fix/typo"]);
+        assert_eq!(
+            filter_merged_branches(output, true),
+            "merged (2):\n  feat/login\n  fix/typo\n\
+             2 branches merged — delete with rtk git branch -d <name>"
+        );
+        assert_eq!(
+            filter_merged_branches("* feat/wip\n  spike/cache\n", false),
+            "not merged (1):\n  spike/cache"
+        );
+        assert_eq!(
+            filter_merged_branches("* main\n", true),
+            "no merged branches"
+        );
+    }
+
+    #[test]
+    fn test_branch_summary_json_shape() {
+        let output = "* main\n  fix/bug-123\n  remotes/origin/HEAD -> origin/main\n  remotes/origin/main\n  remotes/origin/release/v2\n";
+        assert_eq!(
+            serde_json::to_string(&parse_branches(output)).unwrap(),
+            r#"{"current":"main","local":["fix/bug-123"],"remote_only":["release/v2"]}"#
+        );
     }
 
     #[test]
-    fn test_filter_branch_no_remotes() {
-        let output = "* main\n  develop\n";
-        let result = filter_branch_output(output);
-        assert!(result.contains("* main"));
-        assert!(result.contains("develop"));
-        assert!(!result.contains("remote-only"));
+    fn test_branch_and_worktree_date_annotation() {
+        let dates = parse_tab_pairs(
+            "main\t2 hours ago\nfeature/auth\t3 days ago\norigin/release/v2\t5 weeks ago\n",
+        );
+        let output = "* main\n  feature/auth\n  fix/bug-123\n  remotes/origin/release/v2\n";
+        assert_eq!(
+            filter_branch_output(output, &dates),
+            "* main (2 hours ago)\n  feature/auth (3 days ago)\n  fix/bug-123\n  \
+             remote-only (1):\n    release/v2 (5 weeks ago)"
+        );
+
+        let commits = parse_tab_pairs("abc1234\t10 minutes ago\n");
+        let output =
+            "/home/user/project  abc1234 [main]\n/home/user/worktrees/feat  def5678 [feature]\n";
+        let result = filter_worktree_list(output, &commits);
+        assert!(result.contains("abc1234 [main] (10 minutes ago)"));
+        assert!(result.ends_with("def5678 [feature]"));
     }
 
     #[test]
@@ -1336,31 +5241,146 @@ mod tests {
         let output =
             "stash@{0}: WIP on main: abc1234 fix login\nstash@{1}: On feature: def5678 wip\n";
         let result = filter_stash_list(output);
-        assert!(result.contains("stash@{0}: abc1234 fix login"));
-        assert!(result.contains("stash@{1}: def5678 wip"));
+        assert!(result.contains("stash@{0} [main]: abc1234 fix login"));
+        assert!(result.contains("stash@{1} [feature]: def5678 wip"));
+    }
+
+    #[test]
+    fn test_filter_stash_list_keeps_branch_per_stash() {
+        let output = "stash@{0}: WIP on feature/auth: abc1234 add token refresh\n\
+                      stash@{1}: On main: try new layout\n\
+                      stash@{2}: WIP on main: def5678 fix login\n";
+        let result = filter_stash_list(output);
+        assert_eq!(
+            result,
+            "stash@{0} [feature/auth]: abc1234 add token refresh\n\
+             stash@{1} [main]: try new layout\n\
+             stash@{2} [main]: def5678 fix login"
+        );
     }
 
     #[test]
     fn test_filter_worktree_list() {
         let output =
             "/home/user/project  abc1234 [main]\n/home/user/worktrees/feat  def5678 [feature]\n";
-        let result = filter_worktree_list(output);
+        let result = filter_worktree_list(output, &HashMap::new());
         assert!(result.contains("abc1234"));
         assert!(result.contains("[main]"));
         assert!(result.contains("[feature]"));
     }
 
+    #[test]
+    fn test_compact_diff_ascii_has_no_non_ascii_bytes() {
+        let diff = "diff --git a/src/a.rs b/src/a.rs\n\
+                    --- a/src/a.rs\n\
+                    +++ b/src/a.rs\n\
+                    @@ -1,2 +1,2 @@\n\
+                    -old\n\
+                    +new\n\
+                    diff --git a/old.rs b/new.rs\n\
+                    similarity index 90%\n\
+                    rename from old.rs\n\
+                    rename to new.rs\n";
+        let result = compact_diff_with(diff, 100, DIFF_HUNK_LINES, Glyphs::get(true));
+        assert!(result.is_ascii(), "non-ascii output: {}", result);
+        assert!(result.contains("[file] src/a.rs"));
+        assert!(result.contains("[file] old.rs -> new.rs (rename 90%)"));
+
+        let result = compact_diff_with(diff, 100, DIFF_HUNK_LINES, Glyphs::get(false));
+        assert!(result.contains("📄 old.rs → new.rs (rename 90%)"));
+    }
+
+    #[test]
+    fn test_format_status_ascii_has_no_non_ascii_bytes() {
+        let porcelain =
+            "## main...origin/main\nM  staged.rs\n M modified.rs\n?? new.rs\nUU conflict.rs\n";
+        let result = format_status_with(
+            porcelain,
+            &StatusLimits::default(),
+            Glyphs::get(true),
+            None,
+            &[],
+        );
+        assert!(result.is_ascii(), "non-ascii output: {}", result);
+        assert!(result.contains("[branch] main...origin/main"));
+        assert!(result.contains("[staged] Staged: 1 files"));
+        assert!(result.contains("[conflict] Conflicts: 1 files"));
+    }
+
+    #[test]
+    fn test_format_status_submodule_section() {
+        let v2 = "1 .M SC.. 160000 160000 160000 abc1234 abc1234 libs/core\n\
+                  1 .M S.M. 160000 160000 160000 def5678 def5678 vendor/ui\n\
+                  1 .M N... 100644 100644 100644 1111111 1111111 src/main.rs\n\
+                  1 M. SC.. 160000 160000 160000 9999999 8888888 libs/staged\n";
+        let submodules = parse_submodule_changes(v2);
+        assert_eq!(submodules.len(), 2);
+        assert_eq!(submodules[0].describe(), "new commits");
+        assert_eq!(submodules[1].describe(), "modified content");
+
+        let porcelain = "## main\n M libs/core\n M vendor/ui\n M src/main.rs\n";
+        let result = format_status_with(
+            porcelain,
+            &StatusLimits::default(),
+            Glyphs::get(false),
+            None,
+            &submodules,
+        );
+        assert!(result.contains("📝 Modified: 1 files\n   src/main.rs\n"));
+        assert!(result.contains(
+            "📦 Submodules: 2 changed\n   libs/core (new commits)\n   vendor/ui (modified content)"
+        ));
+    }
+
+    #[test]
+    fn test_format_status_detached_head() {
+        let porcelain = "## HEAD (no branch)\n M src/lib.rs\n";
+        let label = format_detached_label("abc1234", None);
+        let result = format_status_with(
+            porcelain,
+            &StatusLimits::default(),
+            Glyphs::get(false),
+            Some(&label),
+            &[],
+        );
+        assert!(result.starts_with("📌 detached @ abc1234\n"));
+        assert!(!result.contains("no branch"));
+
+        let result = format_status_with(
+            porcelain,
+            &StatusLimits::default(),
+            Glyphs::get(false),
+            None,
+            &[],
+        );
+        assert!(result.starts_with("📌 detached HEAD\n"));
+
+        assert_eq!(
+            format_detached_label("abc1234", Some("rebasing feature/auth")),
+            "detached @ abc1234 (rebasing feature/auth)"
+        );
+    }
+
+    #[test]
+    fn test_status_summary_json_shape() {
+        let porcelain = "## main...origin/main [ahead 1]\nM  src/lib.rs\nMM src/main.rs\n?? notes.md\nUU Cargo.lock\n";
+        assert_eq!(
+            serde_json::to_string(&parse_status(porcelain, None, &[])).unwrap(),
+            r#"{"branch":"main...origin/main [ahead 1]","staged":["src/lib.rs","src/main.rs"],"modified":["src/main.rs"],"untracked":["notes.md"],"conflicts":["Cargo.lock"],"submodules":[]}"#
+        );
+    }
+
     #[test]
     fn test_format_status_output_clean() {
         let porcelain = "";
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, &StatusLimits::default());
         assert_eq!(result, "Clean working tree");
     }
 
     #[test]
     fn test_format_status_output_modified_files() {
         let porcelain = "## main...origin/main\n M src/main.rs\n M src/lib.rs\n";
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, &StatusLimits::default());
         assert!(result.contains("📌 main...origin/main"));
         assert!(result.contains("📝 Modified: 2 files"));
         assert!(result.contains("src/main.rs"));
@@ -1372,7 +5392,7 @@ mod tests {
     #[test]
     fn test_format_status_output_untracked_files() {
         let porcelain = "## feature/new\n?? temp.txt\n?? debug.log\n?? test.sh\n";
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, &StatusLimits::default());
         assert!(result.contains("📌 feature/new"));
         assert!(result.contains("❓ Untracked: 3 files"));
         assert!(result.contains("temp.txt"));
@@ -1389,7 +5409,7 @@ M  staged.rs
 A  added.rs
 ?? untracked.txt
 "#;
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, &StatusLimits::default());
         assert!(result.contains("📌 main"));
         assert!(result.contains("✅ Staged: 2 files"));
         assert!(result.contains("staged.rs"));
@@ -1412,7 +5432,7 @@ M  file5.rs
 M  file6.rs
 M  file7.rs
 "#;
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, &StatusLimits::default());
         assert!(result.contains("✅ Staged: 7 files"));
         assert!(result.contains("file1.rs"));
         assert!(result.contains("file5.rs"));
@@ -1421,6 +5441,344 @@ M  file7.rs
         assert!(!result.contains("file7.rs"));
     }
 
+    #[test]
+    fn test_format_status_output_raised_limit() {
+        let porcelain = r#"## main
+M  file1.rs
+M  file2.rs
+M  file3.rs
+M  file4.rs
+M  file5.rs
+M  file6.rs
+M  file7.rs
+"#;
+        let result = format_status_output(porcelain, &StatusLimits::uniform(10));
+        assert!(result.contains("✅ Staged: 7 files"));
+        assert!(result.contains("file6.rs"));
+        assert!(result.contains("file7.rs"));
+        assert!(!result.contains("more"));
+    }
+
+    #[test]
+    fn test_extract_status_limits() {
+        let (limits, rest) = extract_status_limits(&[]);
+        assert_eq!(limits, StatusLimits::default());
+        assert!(rest.is_empty());
+
+        let (limits, rest) = extract_status_limits(&sargs(&["--max-files", "10"]));
+        assert_eq!(limits, StatusLimits::uniform(10));
+        assert!(rest.is_empty());
+
+        let (limits, rest) = extract_status_limits(&sargs(&["-s", "--max-files=2"]));
+        assert_eq!(limits.untracked, 2);
+        assert_eq!(rest, vec!["-s"]);
+    }
+
+    #[test]
+    fn test_format_status_output_conflicts() {
+        let porcelain = r#"## main
+UU both_modified.rs
+AA both_added.rs
+DD both_deleted.rs
+AU added_by_us.rs
+UA added_by_them.rs
+DU deleted_by_us.rs
+UD deleted_by_them.rs
+M  staged.rs
+"#;
+        let result = format_status_output(porcelain, &StatusLimits::default());
+        assert!(result.contains("⚠️  Conflicts: 7 files"));
+        assert!(result.contains("both_modified.rs"));
+        assert!(result.contains("both_added.rs"));
+        assert!(result.contains("both_deleted.rs"));
+        assert!(result.contains("added_by_us.rs"));
+        assert!(result.contains("added_by_them.rs"));
+        assert!(result.contains("... +2 more"));
+        assert!(!result.contains("deleted_by_us.rs"));
+        // Conflicted paths are not double-counted as staged/modified
+        assert!(result.contains("✅ Staged: 1 files"));
+        assert!(!result.contains("📝 Modified"));
+    }
+
+    fn sargs(v: &[&str]) -> Vec<String> {
+        v.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_classify_destructive() {
+        // Force-delete branches lists the branches as targets
+        let op = classify_destructive("branch", &sargs(&["-D", "feat", "old"])).unwrap();
+        assert_eq!(op.action, "git branch -D");
+        assert_eq!(op.targets, sargs(&["feat", "old"]));
+        assert!(classify_destructive("branch", &sargs(&["-d", "-f", "feat"])).is_some());
+        assert!(classify_destructive("branch", &sargs(&["-d", "feat"])).is_none());
+        assert!(classify_destructive("branch", &sargs(&["-m", "a", "b"])).is_none());
+
+        // git clean: forced is destructive, dry-run is not
+        assert!(classify_destructive("clean", &sargs(&["-f"])).is_some());
+        assert!(classify_destructive("clean", &sargs(&["-xdf"])).is_some());
+        assert!(classify_destructive("clean", &sargs(&["-fdn"])).is_none());
+        assert!(classify_destructive("clean", &sargs(&["-n"])).is_none());
+
+        // git reset: only --hard
+        let op = classify_destructive("reset", &sargs(&["--hard"])).unwrap();
+        assert_eq!(op.targets, sargs(&["HEAD"]));
+        let op = classify_destructive("reset", &sargs(&["--hard", "origin/main"])).unwrap();
+        assert_eq!(op.targets, sargs(&["origin/main"]));
+        assert!(classify_destructive("reset", &sargs(&["--soft", "HEAD~1"])).is_none());
+
+        // git push: --force but not --force-with-lease
+        assert!(classify_destructive("push", &sargs(&["--force", "origin", "main"])).is_some());
+        assert!(classify_destructive("push", &sargs(&["--force-with-lease"])).is_none());
+        assert!(classify_destructive("push", &sargs(&["-u", "origin", "main"])).is_none());
+
+        assert!(classify_destructive("log", &sargs(&["-D"])).is_none());
+    }
+
+    #[test]
+    fn test_confirmation_notice_lists_reset_discards() {
+        let op = classify_destructive("reset", &sargs(&["--hard"])).unwrap();
+        let affected = sargs(&["discard: src/a.rs", "discard: src/b.rs"]);
+        assert_eq!(
+            confirmation_notice("reset", &op, &affected, Glyphs::get(true)),
+            "[warning] git reset --hard is destructive\n\
+             \x20  discard: src/a.rs\n\
+             \x20  discard: src/b.rs\n\
+             [warning] reset --hard would discard changes in 2 tracked files\n"
+        );
+
+        let op = classify_destructive("branch", &sargs(&["-D", "old"])).unwrap();
+        let affected: Vec<String> = (0..12).map(|i| format!("b{}", i)).collect();
+        let notice = confirmation_notice("branch", &op, &affected, Glyphs::get(true));
+        assert!(notice.ends_with("   b9\n   ... +2 more\n"));
+        assert!(!notice.contains("discard"));
+    }
+
+    #[test]
+    fn test_clean_dry_run_args_drop_force_only() {
+        assert_eq!(clean_dry_run_args(&sargs(&["-f"])), sargs(&[]));
+        assert_eq!(clean_dry_run_args(&sargs(&["-xdf"])), sargs(&["-xd"]));
+        assert_eq!(
+            clean_dry_run_args(&sargs(&["--force", "-d", "build/"])),
+            sargs(&["-d", "build/"])
+        );
+        // Patterns and paths may contain an f
+        assert_eq!(
+            clean_dry_run_args(&sargs(&["-fe", "*.conf", "-f", "--", "-foo/"])),
+            sargs(&["-e", "*.conf", "--", "-foo/"])
+        );
+        assert_eq!(
+            clean_dry_run_args(&sargs(&["-fdefoo", "--exclude", "-f", "src/f"])),
+            sargs(&["-defoo", "--exclude", "-f", "src/f"])
+        );
+    }
+
+    #[test]
+    fn test_passthrough_yes_only_for_confirmed_subcommands() {
+        // `--yes` belongs to rtk only where a confirmation can happen
+        let runner = MockRunner::new().respond(0, "", "");
+        let args: Vec<OsString> = vec!["branch".into(), "-d".into(), "old".into(), "--yes".into()];
+        capture::emitted(None, None, || {
+            run_passthrough_with(&runner, &args, 0, false, false).unwrap();
+        });
+        assert!(runner.calls()[0].ends_with(" branch -d old"));
+
+        let runner = MockRunner::new().respond(0, "", "");
+        let args: Vec<OsString> = vec!["notes".into(), "list".into(), "--yes".into()];
+        capture::emitted(None, None, || {
+            run_passthrough_with(&runner, &args, 0, false, false).unwrap();
+        });
+        assert!(runner.calls()[0].ends_with(" notes list --yes"));
+    }
+
+    #[test]
+    fn test_reflog_list_args_follow_subcommand() {
+        assert_eq!(
+            reflog_list_args(&sargs(&["show", "feature"]), 20),
+            sargs(&["reflog", "show", "--date=relative", "-n20", "feature"])
+        );
+        assert_eq!(
+            reflog_list_args(&sargs(&["main"]), 5),
+            sargs(&["reflog", "--date=relative", "-n5", "main"])
+        );
+        assert_eq!(
+            reflog_list_args(&[], 20),
+            sargs(&["reflog", "--date=relative", "-n20"])
+        );
+    }
+
+    #[test]
+    fn test_format_reflog_line() {
+        assert_eq!(
+            format_reflog_line("abc1234 HEAD@{2 hours ago}: commit: fix login bug"),
+            "abc1234 commit fix login bug (2 hours ago)"
+        );
+        assert_eq!(
+            format_reflog_line(
+                "def5678 HEAD@{3 days ago}: rebase (finish): returning to refs/heads/main"
+            ),
+            "def5678 rebase (finish) returning to refs/heads/main (3 days ago)"
+        );
+        assert_eq!(
+            format_reflog_line(
+                "0a1b2c3 HEAD@{5 minutes ago}: checkout: moving from main to feature"
+            ),
+            "0a1b2c3 checkout moving from main to feature (5 minutes ago)"
+        );
+        // Index selectors carry no date
+        assert_eq!(
+            format_reflog_line("abc1234 HEAD@{0}: commit (amend): tweak"),
+            "abc1234 commit (amend) tweak"
+        );
+    }
+
+    #[test]
+    fn test_format_tag_list() {
+        let output = "v2.3.1\tabc1234\t\t2 weeks ago\n\
+                      v2.3.0\t1111111\tdef5678\t1 month ago\n\
+                      v10.0.0-rc1\t9999999\t\t3 months ago\n";
+        let result = format_tag_list(output, 20);
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "v2.3.1       abc1234  (2 weeks ago)");
+        // Annotated tag shows the peeled commit, not the tag object
+        assert_eq!(lines[1], "v2.3.0       def5678  (1 month ago)");
+        assert_eq!(lines[2], "v10.0.0-rc1  9999999  (3 months ago)");
+    }
+
+    #[test]
+    fn test_format_tag_list_limit() {
+        let output: String = (0..25)
+            .map(|i| format!("v1.{}\tabc{:04}\t\t{} days ago\n", i, i, i))
+            .collect();
+        let result = format_tag_list(&output, 20);
+        assert_eq!(result.lines().count(), 21);
+        assert!(result.contains("v1.19"));
+        assert!(!result.contains("v1.20"));
+        assert!(result.ends_with("... +5 more"));
+    }
+
+    #[test]
+    fn test_format_tag_list_empty() {
+        assert_eq!(format_tag_list("", 20), "No tags");
+    }
+
+    #[test]
+    fn test_tag_names() {
+        assert_eq!(
+            tag_names(&sargs(&["-a", "v1.0", "-m", "Release 1.0"])),
+            vec!["v1.0"]
+        );
+        assert_eq!(
+            tag_names(&sargs(&["-d", "v1.0", "v1.1"])),
+            vec!["v1.0", "v1.1"]
+        );
+        assert!(tag_names(&sargs(&["--list"])).is_empty());
+    }
+
+    #[test]
+    fn test_tag_contains_is_list_mode() {
+        let (flags, rest) = split_tag_list_flags(&sargs(&["--contains", "HEAD"]));
+        assert_eq!(flags, Some(sargs(&["--contains", "HEAD"])));
+        // HEAD is the filter's value, not a tag to create
+        assert!(rest.is_empty());
+        assert!(tag_names(&rest).is_empty());
+
+        let args = tag_list_args(&flags.unwrap(), &[]);
+        assert_eq!(
+            &args[3..],
+            sargs(&["--contains", "HEAD", "refs/tags"]).as_slice()
+        );
+    }
+
+    #[test]
+    fn test_tag_points_at_is_list_mode() {
+        let (flags, rest) = split_tag_list_flags(&sargs(&[
+            "--points-at",
+            "v1.0",
+            "--sort=version:refname",
+            "v1*",
+        ]));
+        assert_eq!(
+            flags,
+            Some(sargs(&["--points-at", "v1.0", "--sort=version:refname"]))
+        );
+        assert_eq!(rest, sargs(&["v1*"]));
+
+        let args = tag_list_args(&flags.unwrap(), &tag_names(&rest));
+        assert_eq!(
+            &args[3..],
+            sargs(&[
+                "--points-at",
+                "v1.0",
+                "--sort=version:refname",
+                "refs/tags/v1*"
+            ])
+            .as_slice()
+        );
+
+        // -n and --merged without a value still select list mode
+        let (flags, rest) = split_tag_list_flags(&sargs(&["-n5", "--merged"]));
+        assert_eq!(flags, Some(sargs(&["--merged"])));
+        assert!(rest.is_empty());
+
+        // Plain creation has no list flags
+        let (flags, rest) = split_tag_list_flags(&sargs(&["v2.0", "HEAD~1"]));
+        assert_eq!(flags, None);
+        assert_eq!(rest, sargs(&["v2.0", "HEAD~1"]));
+    }
+
+    #[test]
+    fn test_declined_is_command_error() {
+        let err = declined(&with_subcommand("push", &sargs(&["--force", "origin"])));
+        assert_eq!(crate::utils::exit_code(&err), 1);
+        assert_eq!(
+            err.to_string(),
+            "FAILED: git push --force origin\naborted: not confirmed"
+        );
+    }
+
+    #[test]
+    fn test_reset_mode() {
+        assert_eq!(reset_mode(&[]), ResetMode::Mixed);
+        assert_eq!(reset_mode(&sargs(&["HEAD~1"])), ResetMode::Mixed);
+        assert_eq!(reset_mode(&sargs(&["--soft", "HEAD~1"])), ResetMode::Soft);
+        assert_eq!(
+            reset_mode(&sargs(&["--hard", "origin/main"])),
+            ResetMode::Hard
+        );
+        assert_eq!(reset_mode(&sargs(&["--hard", "--soft"])), ResetMode::Soft);
+    }
+
+    #[test]
+    fn test_format_reset_summary() {
+        assert_eq!(
+            format_reset_summary(&ResetMode::Soft, "abc1234", "", 0),
+            "ok ✓ reset to abc1234 (changes kept staged)"
+        );
+        let unstaged = "Unstaged changes after reset:\nM\tsrc/main.rs\nD\tsrc/old.rs\n";
+        assert_eq!(
+            format_reset_summary(&ResetMode::Mixed, "abc1234", unstaged, 0),
+            "ok ✓ reset to abc1234 (2 files unstaged)"
+        );
+        assert_eq!(
+            format_reset_summary(&ResetMode::Mixed, "abc1234", "", 0),
+            "ok ✓ reset to abc1234 (0 files unstaged)"
+        );
+        assert_eq!(
+            format_reset_summary(
+                &ResetMode::Hard,
+                "def5678",
+                "HEAD is now at def5678 Fix\n",
+                0
+            ),
+            "ok ✓ hard reset to def5678"
+        );
+        assert_eq!(
+            format_reset_summary(&ResetMode::Hard, "def5678", "", 1),
+            "ok ✓ hard reset to def5678 (discarded changes in 1 file)"
+        );
+    }
+
     #[test]
     fn test_run_passthrough_accepts_args() {
         // Test that run_passthrough compiles and has correct signature
@@ -1428,6 +5786,292 @@ M  file7.rs
         // Compile-time verification that the function exists with correct signature
     }
 
+    #[test]
+    fn test_build_log_args_date_flags_suppress_default_limit() {
+        let (args, limit) = build_log_args(&sargs(&["--today"]));
+        assert!(args.contains(&"--since=midnight".to_string()));
+        assert!(!args.contains(&"-10".to_string()));
+        assert_eq!(limit, usize::MAX);
+
+        let (args, _) = build_log_args(&sargs(&["--week"]));
+        assert!(args.contains(&"--since=1.week.ago".to_string()));
+        assert!(!args.contains(&"-10".to_string()));
+
+        let (args, _) = build_log_args(&sargs(&["--since", "2026-01-01"]));
+        assert!(args.contains(&"--since=2026-01-01".to_string()));
+        assert!(!args.contains(&"-10".to_string()));
+
+        // Native git start flags count as a bound too
+        let (args, _) = build_log_args(&sargs(&["--after=2026-01-01"]));
+        assert!(!args.contains(&"-10".to_string()));
+    }
+
+    #[test]
+    fn test_build_log_args_until_keeps_default_limit() {
+        let (args, limit) = build_log_args(&sargs(&["--until", "yesterday"]));
+        assert!(args.contains(&"-10".to_string()));
+        assert_eq!(limit, 10);
+
+        let (args, _) = build_log_args(&sargs(&["--before=2026-01-01"]));
+        assert!(args.contains(&"-10".to_string()));
+    }
+
+    #[test]
+    fn test_build_log_args_date_flag_with_explicit_limit() {
+        let (args, limit) = build_log_args(&sargs(&["--week", "-5"]));
+        assert!(args.contains(&"--since=1.week.ago".to_string()));
+        assert!(args.contains(&"-5".to_string()));
+        assert_eq!(limit, 5);
+    }
+
+    #[test]
+    fn test_diff_mode_staged_is_compacted() {
+        assert_eq!(diff_mode(&sargs(&["--staged"])), DiffMode::Compact);
+        assert_eq!(diff_mode(&sargs(&["--cached"])), DiffMode::Compact);
+        assert_eq!(diff_mode(&sargs(&["--cached", "src/"])), DiffMode::Compact);
+        assert_eq!(diff_mode(&[]), DiffMode::Compact);
+
+        assert_eq!(
+            diff_mode(&sargs(&["--staged", "--stat"])),
+            DiffMode::Passthrough
+        );
+        assert_eq!(diff_mode(&sargs(&["--no-compact"])), DiffMode::Passthrough);
+    }
+
+    #[test]
+    fn test_format_blame_line() {
+        assert_eq!(
+            format_blame_line("3b1ed86a7f (Jane Doe   2024-03-01  42)     let x = 1;").unwrap(),
+            "3b1ed86 Jane Doe 2024-03-01 │     let x = 1;"
+        );
+        // Boundary commit and renamed-file column
+        assert_eq!(
+            format_blame_line("^a1b2c3d4 src/old.rs (Bob 2023-11-20 7) fn main() {}").unwrap(),
+            "a1b2c3d Bob 2023-11-20 │ fn main() {}"
+        );
+        // Code containing parentheses is kept intact
+        assert_eq!(
+            format_blame_line("0000000000 (Not Committed Yet 2026-10-15 3) foo(bar)").unwrap(),
+            "0000000 Not Committed Yet 2026-10-15 │ foo(bar)"
+        );
+        assert!(format_blame_line("not a blame line").is_none());
+    }
+
+    #[test]
+    fn test_filter_blame_output_caps_lines() {
+        let input = (1..=5)
+            .map(|n| format!("abcdef12 (Ann 2024-01-01 {}) line {}", n, n))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let out = filter_blame_output(&input, 3);
+        assert_eq!(out.lines().count(), 4);
+        assert!(out.starts_with("abcdef1 Ann 2024-01-01 │ line 1"));
+        assert!(out.ends_with("... +2 more lines"));
+    }
+
+    #[test]
+    fn test_parse_switch_target() {
+        assert_eq!(
+            parse_switch_target("Switched to branch 'main'\n"),
+            Some("main".to_string())
+        );
+        assert_eq!(
+            parse_switch_target("Switched to a new branch 'feat/x'\n"),
+            Some("feat/x".to_string())
+        );
+        assert_eq!(
+            parse_switch_target("Already on 'main'\nYour branch is up to date.\n"),
+            Some("main".to_string())
+        );
+        assert_eq!(
+            parse_switch_target("HEAD is now at a1b2c3d fix: thing\n"),
+            Some("a1b2c3d (detached)".to_string())
+        );
+        assert_eq!(parse_switch_target(""), None);
+    }
+
+    #[test]
+    fn test_format_restore_success() {
+        // `git restore .` with three modified files, one left staged
+        let before = " M src/a.rs\n M src/b.rs\nM  src/c.rs\n M README.md\n";
+        let after = "M  src/c.rs\n";
+        assert_eq!(count_changed_paths(before, after), 3);
+        // `--staged`: the index entry moves to the worktree column
+        assert_eq!(count_changed_paths("M  src/c.rs\n", " M src/c.rs\n"), 1);
+        // `--source`: a clean file becomes modified
+        assert_eq!(count_changed_paths("", " M src/a.rs\n"), 1);
+        assert_eq!(count_changed_paths(before, before), 0);
+
+        assert_eq!(format_restore_success(1), "ok ✓ restored 1 path");
+        assert_eq!(format_restore_success(3), "ok ✓ restored 3 paths");
+        assert_eq!(format_restore_success(0), "ok (nothing to restore)");
+    }
+
+    #[test]
+    fn test_parse_shortlog() {
+        let output = "   142\tAlice\n    88\tBob van der Berg\n     3\tCarol\n";
+        let authors = parse_shortlog(output);
+        assert_eq!(
+            authors,
+            vec![
+                (142, "Alice".to_string()),
+                (88, "Bob van der Berg".to_string()),
+                (3, "Carol".to_string()),
+            ]
+        );
+
+        let formatted = format_shortlog(&authors, &[], 2);
+        assert_eq!(
+            formatted,
+            "233 commits, 3 authors\n142  Alice\n 88  Bob van der Berg\n... +1 more"
+        );
+        assert_eq!(format_shortlog(&[], &[], 15), "No commits");
+
+        // `--group=trailer:co-authored-by` output gets its own section
+        let coauthors = parse_shortlog("    12\tDana Lee\n     1\tAlice\n");
+        assert_eq!(
+            format_shortlog(&authors[..1], &coauthors, 15),
+            "142 commits, 1 author\n142  Alice\nCo-authors (2):\n12  Dana Lee\n 1  Alice"
+        );
+    }
+
+    #[test]
+    fn test_with_rename_detection() {
+        assert_eq!(with_rename_detection(&[]), vec!["-M"]);
+        assert_eq!(
+            with_rename_detection(&sargs(&["--staged"])),
+            vec!["-M", "--staged"]
+        );
+        // Not duplicated or overridden when the user chose a rename mode
+        for user in ["-M", "-M50%", "--find-renames=40%", "-C", "--no-renames"] {
+            let args = sargs(&[user, "HEAD~1"]);
+            assert_eq!(with_rename_detection(&args), args);
+        }
+    }
+
+    #[test]
+    fn test_count_would_remove() {
+        let output = "Would remove build/\nWould remove notes.txt\nWould remove tmp/\nWould remove a.log\nWould remove b.log\n";
+        let (files, dirs) = count_would_remove(output);
+        assert_eq!(files, vec!["notes.txt", "a.log", "b.log"]);
+        assert_eq!(dirs, vec!["build/", "tmp/"]);
+
+        let preview = format_clean_preview(output, 2);
+        assert!(preview.starts_with("Would remove 3 files, 2 dirs"));
+        assert!(preview.contains("  build/\n  notes.txt"));
+        assert!(preview.ends_with("... +3 more"));
+        assert_eq!(format_clean_preview("", 10), "Nothing to clean");
+    }
+
+    #[test]
+    fn test_subcommand_args() {
+        assert_eq!(subcommand_args(&GitCommand::Diff), vec!["diff"]);
+        assert_eq!(
+            subcommand_args(&GitCommand::Commit {
+                message: "fix: x".to_string()
+            }),
+            vec!["commit", "-m", "fix: x"]
+        );
+        assert_eq!(
+            subcommand_args(&GitCommand::Stash {
+                subcommand: Some("pop".to_string())
+            }),
+            vec!["stash", "pop"]
+        );
+        assert_eq!(
+            subcommand_args(&GitCommand::Stash { subcommand: None }),
+            vec!["stash"]
+        );
+    }
+
+    #[test]
+    fn test_build_log_args_defaults() {
+        let (args, limit) = build_log_args(&[]);
+        assert_eq!(limit, 10);
+        assert!(args.contains(&"--pretty=format:%h %s (%ar) <%an>".to_string()));
+        assert!(args.contains(&"-10".to_string()));
+        assert!(args.contains(&"--no-merges".to_string()));
+    }
+
+    #[test]
+    fn test_log_follow_rename_annotation() {
+        let input: Vec<String> = vec!["--follow".into(), "--".into(), "src/parser.rs".into()];
+        let (args, _) = build_log_args(&input);
+        assert!(args.contains(&FOLLOW_PRETTY.to_string()));
+        assert!(args.contains(&"--name-status".to_string()));
+
+        let output = "\x1ea1b2c3d Handle empty input (2 days ago) <Alice>\n\n\
+                      M\tsrc/parser.rs\n\
+                      \x1ee4f5a6b Move parser into src/ (3 weeks ago) <Bob>\n\n\
+                      R094\tlib/parse.rs\tsrc/parser.rs\n\
+                      \x1e7c8d9e0 Add parse() (2 months ago) <Alice>\n\n\
+                      A\tlib/parse.rs\n";
+        assert_eq!(
+            annotate_follow_renames(output, 10),
+            "a1b2c3d Handle empty input (2 days ago) <Alice>\n\
+             e4f5a6b Move parser into src/ (3 weeks ago) <Bob>\n\
+             \x20 ↳ renamed from lib/parse.rs\n\
+             7c8d9e0 Add parse() (2 months ago) <Alice>"
+        );
+        assert_eq!(
+            annotate_follow_renames(output, 1),
+            "a1b2c3d Handle empty input (2 days ago) <Alice>"
+        );
+    }
+
+    #[test]
+    fn test_log_limit_precedence() {
+        assert_eq!(resolve_log_limit(None, None), 10);
+        assert_eq!(resolve_log_limit(Some("20"), None), 20);
+        assert_eq!(resolve_log_limit(Some("0"), None), 10);
+        assert_eq!(resolve_log_limit(Some("lots"), None), 10);
+        // Env beats the config file, which beats the built-in default
+        assert_eq!(resolve_log_limit(Some("20"), Some(30)), 20);
+        assert_eq!(resolve_log_limit(None, Some(30)), 30);
+
+        // Env default applies without an explicit count...
+        let (args, limit) = build_log_args_with(&[], 20);
+        assert_eq!(limit, 20);
+        assert!(args.contains(&"-20".to_string()));
+
+        // ...but an explicit -N still wins
+        let (args, limit) = build_log_args_with(&["-5".to_string()], 20);
+        assert_eq!(limit, 5);
+        assert!(!args.contains(&"-20".to_string()));
+    }
+
+    #[test]
+    fn test_build_log_args_author() {
+        let input = vec!["--author".to_string(), "alice".to_string()];
+        let (args, _) = build_log_args(&input);
+        assert!(args.contains(&"--author=alice".to_string()));
+        assert!(!args.contains(&"--author".to_string()));
+        assert!(!args.contains(&"alice".to_string()));
+    }
+
+    #[test]
+    fn test_build_log_args_graph_skips_pretty() {
+        let input = vec!["--graph".to_string()];
+        let (args, _) = build_log_args(&input);
+        assert!(args.contains(&"--graph".to_string()));
+        assert!(args.contains(&"--oneline".to_string()));
+        assert!(!args.iter().any(|a| a.starts_with("--pretty")));
+        assert_eq!(args.iter().filter(|a| *a == "--graph").count(), 1);
+    }
+
+    #[test]
+    fn test_build_log_args_no_double_defaults() {
+        let input = vec![
+            "--graph".to_string(),
+            "--oneline".to_string(),
+            "-5".to_string(),
+        ];
+        let (args, limit) = build_log_args(&input);
+        assert_eq!(limit, 5);
+        assert_eq!(args.iter().filter(|a| *a == "--oneline").count(), 1);
+        assert!(!args.contains(&"-10".to_string()));
+    }
+
     #[test]
     fn test_filter_log_output() {
         let output = "abc1234 This is a commit message (2 days ago) <author>\ndef5678 Another commit (1 week ago) <other>\n";
@@ -1508,7 +6152,7 @@ no changes added to commit (use "git add" and/or "git commit -a")
     #[test]
     fn test_format_status_output_thai_filename() {
         let porcelain = "## main\n M สวัสดี.txt\n?? ทดสอบ.rs\n";
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, &StatusLimits::default());
         // Should not panic
         assert!(result.contains("📌 main"));
         assert!(result.contains("สวัสดี.txt"));
@@ -1518,7 +6162,173 @@ no changes added to commit (use "git add" and/or "git commit -a")
     #[test]
     fn test_format_status_output_emoji_filename() {
         let porcelain = "## main\nA  🎉-party.txt\n M 日本語ファイル.rs\n";
-        let result = format_status_output(porcelain);
+        let result = format_status_output(porcelain, &StatusLimits::default());
         assert!(result.contains("📌 main"));
     }
+
+    #[test]
+    fn test_failing_git_returns_command_error_with_code() {
+        let err = run(
+            GitCommand::Switch,
+            &sargs(&["rtk-nonexistent-branch-xyz"]),
+            None,
+            0,
+            false,
+            false,
+        )
+        .unwrap_err();
+        let cmd_err = err.downcast_ref::<CommandError>().expect("CommandError");
+        assert_eq!(cmd_err.tool, "git");
+        assert_eq!(cmd_err.subcommand, "switch");
+        assert_eq!(crate::utils::exit_code(&err), 128);
+        assert!(err.to_string().starts_with("FAILED: git switch"));
+    }
+
+    #[test]
+    fn test_resolve_max_lines_precedence() {
+        assert_eq!(resolve_max_lines(Some(20), Some("50"), 100), 20);
+        assert_eq!(resolve_max_lines(None, Some("50"), 100), 50);
+        assert_eq!(resolve_max_lines(None, None, 100), 100);
+        assert_eq!(resolve_max_lines(None, Some("lots"), 100), 100);
+        assert_eq!(resolve_max_lines(Some(5), None, 100), 5);
+    }
+
+    #[test]
+    fn test_parse_bisect_progress() {
+        assert_eq!(
+            parse_bisect_progress(
+                "Bisecting: 675 revisions left to test after this (roughly 10 steps)"
+            ),
+            Some((675, 10))
+        );
+        assert_eq!(
+            parse_bisect_progress(
+                "Bisecting: 0 revisions left to test after this (roughly 0 steps)"
+            ),
+            Some((0, 0))
+        );
+        assert_eq!(
+            parse_bisect_progress("Bisecting: 1 revision left to test after this (roughly 1 step)"),
+            Some((1, 1))
+        );
+        assert_eq!(
+            parse_bisect_progress("status: waiting for good commit(s)"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_filter_bisect_output() {
+        let progress = "Bisecting: 675 revisions left to test after this (roughly 10 steps)\n\
+                        [3f2a9c1d8e7b6a5f4e3d2c1b0a9f8e7d6c5b4a39] Add retry to fetch client\n";
+        assert_eq!(
+            filter_bisect_output(progress, "good"),
+            "bisect: ~10 steps left, now at 3f2a9c1 Add retry to fetch client"
+        );
+
+        let done = "9c8b7a6f5e4d3c2b1a09f8e7d6c5b4a3f2e1d0c9 is the first bad commit\n\
+                    commit 9c8b7a6f5e4d3c2b1a09f8e7d6c5b4a3f2e1d0c9\n\
+                    Author: Alice Smith <alice@example.com>\n\
+                    Date:   Mon Jan 15 10:00:00 2024 +0100\n\
+                    \n    Switch parser to streaming mode\n\
+                    \n src/parser.rs | 42 ++++++++++++++++++++++++++--------------\n\
+                    1 file changed, 26 insertions(+), 16 deletions(-)\n";
+        assert_eq!(
+            filter_bisect_output(done, "bad"),
+            "✗ first bad commit: 9c8b7a6 Switch parser to streaming mode (Alice Smith)"
+        );
+
+        let reset = "Previous HEAD position was 3f2a9c1 Add retry\nSwitched to branch 'main'\n";
+        assert_eq!(filter_bisect_output(reset, "reset"), "ok ✓ bisect reset");
+        assert_eq!(
+            filter_bisect_output("status: waiting for both good and bad commits\n", "start"),
+            "status: waiting for both good and bad commits"
+        );
+        assert_eq!(filter_bisect_output("\n", "start"), "ok ✓ bisect start");
+    }
+
+    #[test]
+    fn test_parse_cherry_pick_commits() {
+        let output = "[main 1a2b3c4] Fix login redirect\n \
+                      Date: Mon Jan 15 10:00:00 2024 +0100\n \
+                      1 file changed, 3 insertions(+), 1 deletion(-)\n";
+        let commits = parse_cherry_pick_commits(output);
+        assert_eq!(
+            commits,
+            vec![("1a2b3c4".to_string(), "Fix login redirect".to_string())]
+        );
+        assert_eq!(
+            format_cherry_pick_success(&commits),
+            "ok ✓ cherry-picked 1a2b3c4 Fix login redirect"
+        );
+
+        let range = "[feature/x 5e6f7a8] First\n 1 file changed\n\
+                     [feature/x 9b0c1d2] Second\n 2 files changed\n";
+        assert_eq!(
+            format_cherry_pick_success(&parse_cherry_pick_commits(range)),
+            "ok ✓ cherry-picked 2 commits\n  5e6f7a8 First\n  9b0c1d2 Second"
+        );
+    }
+
+    #[test]
+    fn test_cherry_pick_conflict_summary() {
+        let output = "Auto-merging src/lib.rs\n\
+                      CONFLICT (content): Merge conflict in src/lib.rs\n\
+                      CONFLICT (modify/delete): src/old.rs deleted in HEAD and modified in 1a2b3c4.\n\
+                      error: could not apply 1a2b3c4... Fix login redirect\n\
+                      hint: after resolving the conflicts, mark the corrected paths\n";
+        let files = parse_conflicted_files(output);
+        assert_eq!(files, vec!["src/lib.rs", "src/old.rs"]);
+        assert_eq!(
+            format_cherry_pick_conflict(&files),
+            "conflict in 2 files:\n  src/lib.rs\n  src/old.rs\n\
+             resolve, then: rtk git cherry-pick --continue (or --abort)"
+        );
+    }
+
+    #[test]
+    fn test_parse_rm_paths() {
+        let output = "rm 'src/a.rs'\nrm 'src/b.rs'\nrm 'docs/old dir/c.md'\n";
+        let paths = parse_rm_paths(output);
+        assert_eq!(paths, vec!["src/a.rs", "src/b.rs", "docs/old dir/c.md"]);
+        assert_eq!(format_rm_success(&paths, false), "ok ✓ removed 3 files");
+        assert_eq!(
+            format_rm_success(&paths[..1], false),
+            "ok ✓ removed src/a.rs"
+        );
+        assert_eq!(format_rm_success(&paths, true), "ok ✓ would remove 3 files");
+        assert_eq!(format_rm_success(&[], false), "ok (nothing to remove)");
+    }
+
+    #[test]
+    fn test_format_rm_refusal() {
+        let stderr = "error: the following files have local modifications:\n    a.txt\n    b.txt\n\
+                      (use --cached to keep the file, or -f to force removal)\n";
+        assert_eq!(
+            format_rm_refusal(stderr).unwrap(),
+            "a.txt, b.txt: local modifications \
+             (use --cached to keep the file, or -f to force removal)"
+        );
+        assert_eq!(
+            format_rm_refusal("fatal: pathspec 'zz' did not match any files\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_mv_paths() {
+        let args: Vec<String> = vec!["-f".into(), "old.rs".into(), "new.rs".into()];
+        let moves = parse_mv_paths(&args, "");
+        assert_eq!(format_mv_success(&moves), "ok ✓ moved old.rs → new.rs");
+
+        // -v output wins over args
+        let moves = parse_mv_paths(&args, "Renaming old.rs to src/new.rs\n");
+        assert_eq!(format_mv_success(&moves), "ok ✓ moved old.rs → src/new.rs");
+
+        let args: Vec<String> = vec!["a.rs".into(), "b.rs".into(), "lib/".into()];
+        assert_eq!(
+            format_mv_success(&parse_mv_paths(&args, "")),
+            "ok ✓ moved 2 files\n  a.rs → lib/\n  b.rs → lib/"
+        );
+    }
 }
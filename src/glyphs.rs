@@ -0,0 +1,154 @@
+//! Semantic glyphs for compact output.
+//!
+//! Filters ask for a meaning ("staged", "failure") instead of hard-coding an
+//! emoji, so status and state markers can switch to plain ASCII:
+//! - `--ascii`, `-u`, `RTK_ASCII=1`, `NO_COLOR` or `rtk git ... --no-emoji`
+//!   selects [`Glyphs::ASCII`]
+//! - otherwise [`Glyphs::EMOJI`]
+//!
+//! Only markers go through here; decorative section headers (e.g.
+//! "📋 Pull Requests") and text marks like `✓` are left as written.
+
+/// One glyph per semantic slot. Trailing spaces are part of the glyph where
+/// the emoji renders narrower than its terminal cell (e.g. "⚠️").
+#[derive(Debug)]
+pub struct Glyphs {
+    pub branch: &'static str,
+    pub staged: &'static str,
+    pub modified: &'static str,
+    pub untracked: &'static str,
+    pub conflict: &'static str,
+    pub warning: &'static str,
+    pub submodule: &'static str,
+    pub success: &'static str,
+    pub failure: &'static str,
+    pub cancelled: &'static str,
+    pub pending: &'static str,
+    pub open: &'static str,
+    pub merged: &'static str,
+    pub closed: &'static str,
+    pub unknown: &'static str,
+    pub file: &'static str,
+    pub arrow: &'static str,
+}
+
+impl Glyphs {
+    pub const EMOJI: Glyphs = Glyphs {
+        branch: "📌",
+        staged: "✅",
+        modified: "📝",
+        untracked: "❓",
+        conflict: "⚠️ ",
+        warning: "⚠️ ",
+        submodule: "📦",
+        success: "✅",
+        failure: "❌",
+        cancelled: "🚫",
+        pending: "⏳",
+        open: "🟢",
+        merged: "🟣",
+        closed: "🔴",
+        unknown: "⚪",
+        file: "📄",
+        arrow: "→",
+    };
+
+    /// Bracketed words, one per slot, so each stays unambiguous in plain text
+    pub const ASCII: Glyphs = Glyphs {
+        branch: "[branch]",
+        staged: "[staged]",
+        modified: "[modified]",
+        untracked: "[untracked]",
+        conflict: "[conflict]",
+        warning: "[warning]",
+        submodule: "[submodule]",
+        success: "[ok]",
+        failure: "[fail]",
+        cancelled: "[cancelled]",
+        pending: "[pending]",
+        open: "[open]",
+        merged: "[merged]",
+        closed: "[closed]",
+        unknown: "[unknown]",
+        file: "[file]",
+        arrow: "->",
+    };
+
+    /// Glyph set for the given mode.
+    pub fn get(ascii: bool) -> &'static Glyphs {
+        if ascii {
+            &Self::ASCII
+        } else {
+            &Self::EMOJI
+        }
+    }
+
+    /// Glyph set selected by [`ascii_mode`].
+    pub fn current() -> &'static Glyphs {
+        Self::get(ascii_mode())
+    }
+}
+
+/// Returns true when plain ASCII output was requested: `RTK_ASCII` (set by
+/// `--ascii` and `-u`) or `NO_COLOR`. The one place every module asks.
+pub fn ascii_mode() -> bool {
+    std::env::var("RTK_ASCII")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+        || crate::term::no_color()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every slot; the exhaustive destructure stops compiling when a field
+    /// is added but not listed here
+    fn all(g: &Glyphs) -> Vec<&'static str> {
+        let Glyphs {
+            branch,
+            staged,
+            modified,
+            untracked,
+            conflict,
+            warning,
+            submodule,
+            success,
+            failure,
+            cancelled,
+            pending,
+            open,
+            merged,
+            closed,
+            unknown,
+            file,
+            arrow,
+        } = *g;
+        vec![
+            branch, staged, modified, untracked, conflict, warning, submodule, success, failure,
+            cancelled, pending, open, merged, closed, unknown, file, arrow,
+        ]
+    }
+
+    #[test]
+    fn test_ascii_glyphs_are_ascii() {
+        for glyph in all(Glyphs::get(true)) {
+            assert!(glyph.is_ascii(), "non-ascii glyph: {:?}", glyph);
+            assert!(!glyph.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_ascii_glyphs_are_unique() {
+        let glyphs = all(Glyphs::get(true));
+        for (i, glyph) in glyphs.iter().enumerate() {
+            assert!(!glyphs[i + 1..].contains(glyph), "duplicate: {:?}", glyph);
+        }
+    }
+
+    #[test]
+    fn test_emoji_glyphs_selected_by_default() {
+        assert_eq!(Glyphs::get(false).staged, "✅");
+        assert_eq!(Glyphs::get(true).staged, "[staged]");
+    }
+}
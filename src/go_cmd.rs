@@ -1,5 +1,5 @@
 use crate::tracking;
-use crate::utils::truncate;
+use crate::utils::{emit, truncate};
 use anyhow::{Context, Result};
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -60,7 +60,7 @@ pub fn run_test(args: &[String], verbose: u8) -> Result<()> {
 
     let filtered = filter_go_test_json(&stdout);
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     // Include stderr if present (build errors, etc.)
     if !stderr.trim().is_empty() {
@@ -107,7 +107,7 @@ pub fn run_build(args: &[String], verbose: u8) -> Result<()> {
     let filtered = filter_go_build(&raw);
 
     if !filtered.is_empty() {
-        println!("{}", filtered);
+        emit(&filtered);
     }
 
     timer.track(
@@ -150,7 +150,7 @@ pub fn run_vet(args: &[String], verbose: u8) -> Result<()> {
     let filtered = filter_go_vet(&raw);
 
     if !filtered.is_empty() {
-        println!("{}", filtered);
+        emit(&filtered);
     }
 
     timer.track(
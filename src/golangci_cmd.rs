@@ -1,5 +1,5 @@
 use crate::tracking;
-use crate::utils::truncate;
+use crate::utils::{emit, truncate};
 use anyhow::{Context, Result};
 use serde::Deserialize;
 use std::collections::HashMap;
@@ -65,7 +65,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
 
     let filtered = filter_golangci_json(&stdout);
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     // Include stderr if present (config errors, etc.)
     if !stderr.trim().is_empty() && verbose > 0 {
@@ -1,4 +1,5 @@
 use crate::tracking;
+use crate::utils::emit;
 use anyhow::{Context, Result};
 use regex::Regex;
 use std::collections::HashMap;
@@ -42,7 +43,7 @@ pub fn run(
 
     if stdout.trim().is_empty() {
         let msg = format!("🔍 0 for '{}'", pattern);
-        println!("{}", msg);
+        emit(&msg);
         timer.track(
             &format!("grep -rn '{}' {}", pattern, path),
             "rtk grep",
@@ -1,4 +1,5 @@
 use crate::tracking;
+use crate::utils::emit;
 use anyhow::{Context, Result};
 use serde_json::Value;
 use std::fs;
@@ -17,7 +18,7 @@ pub fn run(file: &Path, max_depth: usize, verbose: u8) -> Result<()> {
         .with_context(|| format!("Failed to read file: {}", file.display()))?;
 
     let schema = filter_json_string(&content, max_depth)?;
-    println!("{}", schema);
+    emit(&schema);
     timer.track(
         &format!("cat {}", file.display()),
         "rtk json",
@@ -42,7 +43,7 @@ pub fn run_stdin(max_depth: usize, verbose: u8) -> Result<()> {
         .context("Failed to read from stdin")?;
 
     let schema = filter_json_string(&content, max_depth)?;
-    println!("{}", schema);
+    emit(&schema);
     timer.track("cat - (stdin)", "rtk json -", &content, &schema);
     Ok(())
 }
@@ -1,6 +1,6 @@
 use crate::ruff_cmd;
 use crate::tracking;
-use crate::utils::{package_manager_exec, truncate};
+use crate::utils::{emit, package_manager_exec, truncate};
 use anyhow::{Context, Result};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -173,7 +173,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         _ => filter_generic_lint(&raw),
     };
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track(
         &format!("{} {}", linter, args.join(" ")),
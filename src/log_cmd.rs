@@ -1,4 +1,5 @@
 use crate::tracking;
+use crate::utils::emit;
 use anyhow::Result;
 use regex::Regex;
 use std::collections::HashMap;
@@ -16,7 +17,7 @@ pub fn run_file(file: &Path, verbose: u8) -> Result<()> {
 
     let content = fs::read_to_string(file)?;
     let result = analyze_logs(&content);
-    println!("{}", result);
+    emit(&result);
     timer.track(
         &format!("cat {}", file.display()),
         "rtk log",
@@ -38,7 +39,7 @@ pub fn run_stdin(_verbose: u8) -> Result<()> {
     }
 
     let result = analyze_logs(&content);
-    println!("{}", result);
+    emit(&result);
 
     timer.track("log (stdin)", "rtk log (stdin)", &content, &result);
 
@@ -121,7 +121,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
 }
 
 /// Format bytes into human-readable size
-fn human_size(bytes: u64) -> String {
+pub(crate) fn human_size(bytes: u64) -> String {
     if bytes >= 1_048_576 {
         format!("{:.1}M", bytes as f64 / 1_048_576.0)
     } else if bytes >= 1024 {
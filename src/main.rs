@@ -1,3 +1,4 @@
+mod cache;
 mod cargo_cmd;
 mod cc_economics;
 mod ccusage;
@@ -9,13 +10,17 @@ mod deps;
 mod diff_cmd;
 mod discover;
 mod display_helpers;
+mod doctor;
 mod env_cmd;
+mod exec;
+mod explain;
 mod filter;
 mod find_cmd;
 mod format_cmd;
 mod gain;
 mod gh_cmd;
 mod git;
+mod glyphs;
 mod go_cmd;
 mod golangci_cmd;
 mod grep_cmd;
@@ -41,6 +46,8 @@ mod ruff_cmd;
 mod runner;
 mod summary;
 mod supabase_cmd;
+mod term;
+mod tokens;
 mod tracking;
 mod tree;
 mod tsc_cmd;
@@ -75,6 +82,40 @@ struct Cli {
     /// Set SKIP_ENV_VALIDATION=1 for child processes (Next.js, tsc, lint, prisma)
     #[arg(long = "skip-env", global = true)]
     skip_env: bool,
+
+    /// Skip confirmation for destructive operations (also RTK_YES=1)
+    #[arg(long, global = true)]
+    yes: bool,
+
+    /// Cap every command's output at N lines (also RTK_MAX_OUTPUT_LINES).
+    /// Output is also capped at 50 KB; set RTK_MAX_OUTPUT=<bytes> (0 = off)
+    #[arg(long = "max-output", global = true)]
+    max_output: Option<usize>,
+
+    /// Plain ASCII glyphs instead of emoji (also RTK_ASCII=1, `rtk git ... --no-emoji`)
+    #[arg(long, global = true)]
+    ascii: bool,
+
+    /// Print a per-command savings trailer to stderr (also RTK_STATS=1)
+    #[arg(long, global = true)]
+    stats: bool,
+
+    /// Print which kinds of lines were suppressed to stderr (also RTK_EXPLAIN=1)
+    #[arg(long, global = true)]
+    explain: bool,
+
+    /// Use the config file's `[profiles.<NAME>]` on top of `[defaults]` (also RTK_PROFILE)
+    #[arg(long, global = true, value_name = "NAME")]
+    profile: Option<String>,
+
+    /// Skip filtering and print the tool's output verbatim (place before the subcommand: `rtk --raw git diff`)
+    #[arg(long)]
+    raw: bool,
+
+    /// Write the filtered output to a file and print only "wrote N bytes to <path>"
+    /// (place before the subcommand: `rtk --out diff.txt git diff`)
+    #[arg(long, value_name = "PATH")]
+    out: Option<PathBuf>,
 }
 
 #[derive(Subcommand)]
@@ -128,9 +169,11 @@ enum Commands {
 
     /// GitHub CLI (gh) commands with token-optimized output
     Gh {
-        /// Subcommand: pr, issue, run, repo
+        /// Subcommand: pr, issue, run, workflow, repo, api
         subcommand: String,
-        /// Additional arguments
+        /// Additional arguments. `list` accepts `--limit N` / `-n N`
+        /// (`pr list` also `--fields labels,isDraft`);
+        /// `pr view` and `issue view` accept `--json-summary`
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
@@ -320,6 +363,9 @@ enum Commands {
         args: Vec<String>,
     },
 
+    /// Check that wrapped tools are installed and tracking works
+    Doctor,
+
     /// Show token savings summary and history
     Gain {
         /// Show ASCII graph of daily savings
@@ -351,6 +397,12 @@ enum Commands {
         format: String,
     },
 
+    /// Raw tracking data for external tools
+    Tracking {
+        #[command(subcommand)]
+        command: TrackingCommands,
+    },
+
     /// Claude Code economics: spending (ccusage) vs savings (rtk) analysis
     CcEconomics {
         /// Show detailed daily breakdown
@@ -365,9 +417,16 @@ enum Commands {
         /// Show all time breakdowns (daily + weekly + monthly)
         #[arg(short, long)]
         all: bool,
-        /// Output format: text, json, csv
+        /// Output format: text, json, csv, tsv
         #[arg(short, long, default_value = "text")]
         format: String,
+        /// Manual cost-per-token (USD) for periods without ccusage data
+        #[arg(long)]
+        cpt: Option<f64>,
+        /// Monthly savings goal in USD, shown as progress in the summary
+        /// (also `savings_goal` under [defaults] in the config file)
+        #[arg(long)]
+        goal: Option<f64>,
     },
 
     /// Show or create configuration file
@@ -570,25 +629,39 @@ enum Commands {
 enum GitCommands {
     /// Condensed diff output
     Diff {
-        /// Git arguments (supports all git diff flags like --stat, --cached, etc)
+        /// Cap the compacted diff at N lines (also RTK_DIFF_MAX_LINES, default 100)
+        #[arg(long = "max-lines")]
+        max_lines: Option<usize>,
+        /// Git arguments (supports all git diff flags like --stat, --cached, etc).
+        /// `--summary-only` lists changed files with +/- totals and no hunks,
+        /// `--markdown` renders each file as a heading plus a fenced diff block
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
     /// One-line commit history
     Log {
-        /// Git arguments (supports all git log flags like --oneline, --graph, --all)
+        /// Git arguments (supports all git log flags like --oneline, --graph, --all, --author \<name\>).
+        /// `--today`, `--week` and `--since \<date\>` bound by date instead of the default -10
+        /// (RTK_LOG_LIMIT changes that default; an explicit -N still wins)
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
     /// Compact status (supports all git status flags)
     Status {
-        /// Git arguments (supports all git status flags like --porcelain, --short, -s)
+        /// Git arguments (supports all git status flags like --porcelain, --short, -s).
+        /// `--max-files N` caps how many files are listed per category,
+        /// `--watch [N]` reprints the compact status every N seconds (default 2),
+        /// `--format json` prints the files by state as one JSON line
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
     /// Compact show (commit summary + stat + compacted diff)
     Show {
-        /// Git arguments (supports all git show flags)
+        /// Cap the compacted diff at N lines (also RTK_DIFF_MAX_LINES, default 100)
+        #[arg(long = "max-lines")]
+        max_lines: Option<usize>,
+        /// Git arguments (supports all git show flags).
+        /// `--markdown` renders each file as a heading plus a fenced diff block
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
@@ -598,6 +671,18 @@ enum GitCommands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
+    /// Remove files → "ok ✓ removed N files"
+    Rm {
+        /// Paths and flags to remove (supports all git rm flags like -r, --cached, -f)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Move or rename → "ok ✓ moved \<src\> → \<dst\>"
+    Mv {
+        /// Source path(s) and destination, plus git mv flags
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
     /// Commit → "ok ✓ \<hash\>"
     Commit {
         /// Commit message
@@ -618,13 +703,15 @@ enum GitCommands {
     },
     /// Compact branch listing (current/local/remote)
     Branch {
-        /// Git branch arguments (supports -d, -D, -m, etc.)
+        /// Git branch arguments (supports -d, -D, -m, etc.).
+        /// `--format json` prints current/local/remote-only branches as JSON
+        /// `--merged` / `--no-merged` list local branches for cleanup
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
     /// Fetch → "ok fetched (N new refs)"
     Fetch {
-        /// Git fetch arguments
+        /// Git fetch arguments. `--status` appends ahead/behind vs upstream
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
@@ -642,6 +729,77 @@ enum GitCommands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
+    /// Compact reflog: "hash action message (when)"
+    Reflog {
+        /// Git reflog arguments (supports -n N, ref names)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Compact tag list: "v2.3.1  abc1234  (2 weeks ago)"
+    Tag {
+        /// Git tag arguments (-a, -d, -m pass through; empty or -l for list)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Restore paths → "ok ✓ restored N paths"
+    Restore {
+        /// Git restore arguments (--staged, --source, paths)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Switch branches → "ok ✓ switched to <branch>"
+    Switch {
+        /// Git switch arguments (-c, --detach, branch)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Clean untracked files: "Would remove N files, M dirs" / "ok ✓ removed N items"
+    Clean {
+        /// Git clean arguments (-n, -f, -d, -x, paths)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Reset → "ok ✓ reset to <hash> (N files unstaged)"; --hard asks for confirmation
+    Reset {
+        /// Git reset arguments (--soft, --mixed, --hard, commit-ish, paths)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Describe → just the descriptor, e.g. "v1.2.3-5-gabc1234" (short hash without tags)
+    Describe {
+        /// Git describe arguments (--dirty, --abbrev, commit-ish ...)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Bisect: "bisect: ~M steps left, now at <hash> <subject>"
+    Bisect {
+        /// Git bisect arguments (start, good, bad, skip, reset, run ...)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Cherry-pick → "ok ✓ cherry-picked <hash> <subject>", conflicts as a file list
+    CherryPick {
+        /// Commits or ranges to pick, or --continue / --abort / --skip
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Ranked contributors: "142  Alice" (top 15, no merges)
+    Shortlog {
+        /// Git shortlog arguments (supports --since, revision ranges)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Compact blame: "abc1234 author date │ code"
+    Blame {
+        /// File to blame
+        file: String,
+        /// Line range (start,end or :funcname), passed to git as -L
+        #[arg(short = 'L')]
+        range: Option<String>,
+        /// Additional git blame arguments (e.g. -w, -M)
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
     /// Passthrough: runs any unsupported git subcommand directly
     #[command(external_subcommand)]
     Other(Vec<OsString>),
@@ -660,7 +818,7 @@ enum PnpmCommands {
     },
     /// Show outdated packages (condensed: "pkg: old → new")
     Outdated {
-        /// Additional pnpm arguments
+        /// Additional pnpm arguments. `--format json` prints rtk's summary as JSON
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
@@ -672,6 +830,22 @@ enum PnpmCommands {
         #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
         args: Vec<String>,
     },
+    /// Run a package.json script (strips lifecycle banner; `rtk pnpm <script>` works too)
+    Run {
+        /// Script name
+        script: String,
+        /// Additional script arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Why a package is installed (flattened "a > b > pkg" paths)
+    Why {
+        /// Package name
+        package: String,
+        /// Additional pnpm arguments
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
     /// Build (delegates to next build filter)
     Build {
         /// Additional build arguments
@@ -689,6 +863,19 @@ enum PnpmCommands {
     Other(Vec<OsString>),
 }
 
+#[derive(Subcommand)]
+enum TrackingCommands {
+    /// Stream each tracked event as newline-delimited JSON
+    Export {
+        /// Only events at or after this date (YYYY-MM-DD or RFC 3339)
+        #[arg(long)]
+        since: Option<String>,
+        /// Output format: jsonl
+        #[arg(short, long, default_value = "jsonl")]
+        format: String,
+    },
+}
+
 #[derive(Subcommand)]
 enum DockerCommands {
     /// List running containers
@@ -849,9 +1036,48 @@ enum GoCommands {
     Other(Vec<OsString>),
 }
 
-fn main() -> Result<()> {
+fn main() {
+    let result = run_cli();
+    utils::report_out();
+    if let Err(err) = result {
+        match err.downcast_ref::<utils::CommandError>() {
+            // The tool's output was already compacted; report it uniformly
+            Some(failure) => eprintln!("{}", failure),
+            None => eprintln!("Error: {:?}", err),
+        }
+        std::process::exit(utils::exit_code(&err));
+    }
+}
+
+fn run_cli() -> Result<()> {
     let cli = Cli::parse();
 
+    // Before anything reads the config's defaults
+    if let Some(profile) = &cli.profile {
+        std::env::set_var("RTK_PROFILE", profile);
+    }
+    // Expose the output cap to all modules through the shared emit path
+    if let Some(n) = cli.max_output {
+        std::env::set_var("RTK_MAX_OUTPUT_LINES", n.to_string());
+    }
+    if cli.stats {
+        std::env::set_var("RTK_STATS", "1");
+    }
+    // `ascii = true` in the config file applies unless RTK_ASCII says otherwise;
+    // ultra-compact mode implies ASCII icons
+    if cli.ascii
+        || cli.ultra_compact
+        || (std::env::var_os("RTK_ASCII").is_none() && config::defaults().ascii == Some(true))
+    {
+        std::env::set_var("RTK_ASCII", "1");
+    }
+    if cli.explain {
+        std::env::set_var("RTK_EXPLAIN", "1");
+    }
+    if let Some(path) = &cli.out {
+        std::env::set_var("RTK_OUT", path);
+    }
+
     match cli.command {
         Commands::Ls { args } => {
             ls::run(&args, cli.verbose)?;
@@ -883,35 +1109,125 @@ fn main() -> Result<()> {
         }
 
         Commands::Git { command } => match command {
-            GitCommands::Diff { args } => {
-                git::run(git::GitCommand::Diff, &args, None, cli.verbose)?;
+            GitCommands::Diff { max_lines, args } => {
+                git::run(
+                    git::GitCommand::Diff,
+                    &args,
+                    max_lines,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
             }
             GitCommands::Log { args } => {
-                git::run(git::GitCommand::Log, &args, None, cli.verbose)?;
+                git::run(
+                    git::GitCommand::Log,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
             }
             GitCommands::Status { args } => {
-                git::run(git::GitCommand::Status, &args, None, cli.verbose)?;
+                git::run(
+                    git::GitCommand::Status,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
             }
-            GitCommands::Show { args } => {
-                git::run(git::GitCommand::Show, &args, None, cli.verbose)?;
+            GitCommands::Show { max_lines, args } => {
+                git::run(
+                    git::GitCommand::Show,
+                    &args,
+                    max_lines,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
             }
             GitCommands::Add { args } => {
-                git::run(git::GitCommand::Add, &args, None, cli.verbose)?;
+                git::run(
+                    git::GitCommand::Add,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::Rm { args } => {
+                git::run(
+                    git::GitCommand::Rm,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::Mv { args } => {
+                git::run(
+                    git::GitCommand::Mv,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
             }
             GitCommands::Commit { message } => {
-                git::run(git::GitCommand::Commit { message }, &[], None, cli.verbose)?;
+                git::run(
+                    git::GitCommand::Commit { message },
+                    &[],
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
             }
             GitCommands::Push { args } => {
-                git::run(git::GitCommand::Push, &args, None, cli.verbose)?;
+                git::run(
+                    git::GitCommand::Push,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
             }
             GitCommands::Pull { args } => {
-                git::run(git::GitCommand::Pull, &args, None, cli.verbose)?;
+                git::run(
+                    git::GitCommand::Pull,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
             }
             GitCommands::Branch { args } => {
-                git::run(git::GitCommand::Branch, &args, None, cli.verbose)?;
+                git::run(
+                    git::GitCommand::Branch,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
             }
             GitCommands::Fetch { args } => {
-                git::run(git::GitCommand::Fetch, &args, None, cli.verbose)?;
+                git::run(
+                    git::GitCommand::Fetch,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
             }
             GitCommands::Stash { subcommand, args } => {
                 git::run(
@@ -919,32 +1235,173 @@ fn main() -> Result<()> {
                     &args,
                     None,
                     cli.verbose,
+                    cli.yes,
+                    cli.raw,
                 )?;
             }
             GitCommands::Worktree { args } => {
-                git::run(git::GitCommand::Worktree, &args, None, cli.verbose)?;
+                git::run(
+                    git::GitCommand::Worktree,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::Reflog { args } => {
+                git::run(
+                    git::GitCommand::Reflog,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::Tag { args } => {
+                git::run(
+                    git::GitCommand::Tag,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::Restore { args } => {
+                git::run(
+                    git::GitCommand::Restore,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::Switch { args } => {
+                git::run(
+                    git::GitCommand::Switch,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::Clean { args } => {
+                git::run(
+                    git::GitCommand::Clean,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::Reset { args } => {
+                git::run(
+                    git::GitCommand::Reset,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::Describe { args } => {
+                git::run(
+                    git::GitCommand::Describe,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::Bisect { args } => {
+                git::run(
+                    git::GitCommand::Bisect,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::CherryPick { args } => {
+                git::run(
+                    git::GitCommand::CherryPick,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::Shortlog { args } => {
+                git::run(
+                    git::GitCommand::Shortlog,
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
+            }
+            GitCommands::Blame { file, range, args } => {
+                git::run(
+                    git::GitCommand::Blame { file, range },
+                    &args,
+                    None,
+                    cli.verbose,
+                    cli.yes,
+                    cli.raw,
+                )?;
             }
             GitCommands::Other(args) => {
-                git::run_passthrough(&args, cli.verbose)?;
+                git::run_passthrough(&args, cli.verbose, cli.yes)?;
             }
         },
 
         Commands::Gh { subcommand, args } => {
-            gh_cmd::run(&subcommand, &args, cli.verbose, cli.ultra_compact)?;
+            gh_cmd::run(&subcommand, &args, cli.verbose, cli.ultra_compact, cli.raw)?;
         }
 
         Commands::Pnpm { command } => match command {
             PnpmCommands::List { depth, args } => {
-                pnpm_cmd::run(pnpm_cmd::PnpmCommand::List { depth }, &args, cli.verbose)?;
+                pnpm_cmd::run(
+                    pnpm_cmd::PnpmCommand::List { depth },
+                    &args,
+                    cli.verbose,
+                    cli.raw,
+                )?;
             }
             PnpmCommands::Outdated { args } => {
-                pnpm_cmd::run(pnpm_cmd::PnpmCommand::Outdated, &args, cli.verbose)?;
+                pnpm_cmd::run(pnpm_cmd::PnpmCommand::Outdated, &args, cli.verbose, cli.raw)?;
             }
             PnpmCommands::Install { packages, args } => {
                 pnpm_cmd::run(
                     pnpm_cmd::PnpmCommand::Install { packages },
                     &args,
                     cli.verbose,
+                    cli.raw,
+                )?;
+            }
+            PnpmCommands::Run { script, args } => {
+                pnpm_cmd::run(
+                    pnpm_cmd::PnpmCommand::Run { script },
+                    &args,
+                    cli.verbose,
+                    cli.raw,
+                )?;
+            }
+            PnpmCommands::Why { package, args } => {
+                pnpm_cmd::run(
+                    pnpm_cmd::PnpmCommand::Why { package },
+                    &args,
+                    cli.verbose,
+                    cli.raw,
                 )?;
             }
             PnpmCommands::Build { args } => {
@@ -953,9 +1410,17 @@ fn main() -> Result<()> {
             PnpmCommands::Typecheck { args } => {
                 tsc_cmd::run(&args, cli.verbose)?;
             }
-            PnpmCommands::Other(args) => {
-                pnpm_cmd::run_passthrough(&args, cli.verbose)?;
-            }
+            PnpmCommands::Other(args) => match pnpm_cmd::script_shorthand(&args) {
+                Some((script, args)) => {
+                    pnpm_cmd::run(
+                        pnpm_cmd::PnpmCommand::Run { script },
+                        &args,
+                        cli.verbose,
+                        cli.raw,
+                    )?;
+                }
+                None => pnpm_cmd::run_passthrough(&args, cli.verbose)?,
+            },
         },
 
         Commands::Err { command } => {
@@ -1022,7 +1487,7 @@ fn main() -> Result<()> {
                         ignore_space_change,
                         unified,
                         &extra_args,
-                        cli.verbose
+                        cli.verbose,
                     )?;
                 } else {
                     diff_cmd::run(
@@ -1031,7 +1496,7 @@ fn main() -> Result<()> {
                         cli.verbose,
                         is_quiet,
                         ignore_all_space,
-                        ignore_space_change
+                        ignore_space_change,
                     )?;
                 }
             } else {
@@ -1155,6 +1620,10 @@ fn main() -> Result<()> {
             }
         }
 
+        Commands::Doctor => {
+            doctor::run(cli.verbose)?;
+        }
+
         Commands::Gain {
             graph,
             history,
@@ -1180,14 +1649,22 @@ fn main() -> Result<()> {
             )?;
         }
 
+        Commands::Tracking { command } => match command {
+            TrackingCommands::Export { since, format } => {
+                gain::run_events_export(since.as_deref(), &format)?;
+            }
+        },
+
         Commands::CcEconomics {
             daily,
             weekly,
             monthly,
             all,
             format,
+            cpt,
+            goal,
         } => {
-            cc_economics::run(daily, weekly, monthly, all, &format, cli.verbose)?;
+            cc_economics::run(daily, weekly, monthly, all, &format, cpt, goal, cli.verbose)?;
         }
 
         Commands::Config { create } => {
@@ -1405,15 +1882,15 @@ fn main() -> Result<()> {
         }
 
         Commands::Deno { args } => {
-            deno_cmd::run(&args, cli.verbose)?;
+            deno_cmd::run(&args, cli.verbose, cli.raw)?;
         }
 
         Commands::Nx { args } => {
-            nx_cmd::run(&args, cli.verbose)?;
+            nx_cmd::run(&args, cli.verbose, cli.raw)?;
         }
 
         Commands::Supabase { args } => {
-            supabase_cmd::run(&args, cli.verbose)?;
+            supabase_cmd::run(&args, cli.verbose, cli.raw)?;
         }
 
         Commands::Ruff { args } => {
@@ -1,5 +1,5 @@
 use crate::tracking;
-use crate::utils::{strip_ansi, truncate};
+use crate::utils::{emit, strip_ansi, truncate};
 use anyhow::{Context, Result};
 use regex::Regex;
 use std::process::Command;
@@ -42,7 +42,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
 
     let filtered = filter_next_build(&raw);
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track("next build", "rtk next build", &raw, &filtered);
 
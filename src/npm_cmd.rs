@@ -1,4 +1,5 @@
 use crate::tracking;
+use crate::utils::emit;
 use anyhow::{Context, Result};
 use std::process::Command;
 
@@ -26,7 +27,7 @@ pub fn run(args: &[String], verbose: u8, skip_env: bool) -> Result<()> {
     let raw = format!("{}\n{}", stdout, stderr);
 
     let filtered = filter_npm_output(&raw);
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track(
         &format!("npm run {}", args.join(" ")),
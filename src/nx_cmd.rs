@@ -1,10 +1,19 @@
+use crate::term;
 use crate::tracking;
+use crate::utils::{emit, run_raw, run_streaming, CommandError};
 use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 use std::process::Command;
 
-pub fn run(args: &[String], verbose: u8) -> Result<()> {
+pub fn run(args: &[String], verbose: u8, raw: bool) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    // `affected --dry-run` would be forwarded to executors; ask for the task graph instead
+    let preview = is_affected_preview(args);
+    let args = &with_graph_preview(args);
+
     // Detect if this is an npx nx call
     let is_npx = args.first().map(|s| s.as_str()) == Some("nx");
 
@@ -23,12 +32,52 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         c
     };
 
+    if !term::should_colorize() {
+        cmd.env("NO_COLOR", "1").env("FORCE_COLOR", "0");
+    }
+
     if verbose > 0 {
-        eprintln!("Running: {}", if is_npx {
-            format!("npx nx {}", args[1..].join(" "))
-        } else {
-            format!("nx {}", args.join(" "))
-        });
+        eprintln!(
+            "Running: {}",
+            if is_npx {
+                format!("npx nx {}", args[1..].join(" "))
+            } else {
+                format!("nx {}", args.join(" "))
+            }
+        );
+    }
+
+    let cmd_str = if is_npx {
+        format!("npx nx {}", args[1..].join(" "))
+    } else {
+        format!("nx {}", args.join(" "))
+    };
+
+    if raw {
+        return run_raw(&mut cmd, &cmd_str);
+    }
+
+    // Dev servers run until stopped: filter and print as lines arrive
+    if is_serve_command(args) {
+        let streamed = run_streaming(&mut cmd, keep_nx_serve_line).context("Failed to run nx")?;
+
+        timer.track(
+            &cmd_str,
+            &format!("rtk {}", cmd_str),
+            &streamed.raw,
+            &streamed.filtered,
+        );
+
+        if !streamed.status.success() {
+            return Err(CommandError::with_excerpt(
+                "nx",
+                nx_target(args),
+                streamed.status.code(),
+                String::new(),
+            )
+            .into());
+        }
+        return Ok(());
     }
 
     let output = cmd.output().context("Failed to run nx")?;
@@ -36,25 +85,36 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
     let stderr = String::from_utf8_lossy(&output.stderr);
     let raw = format!("{}\n{}", stdout, stderr);
 
-    let filtered = filter_nx_output(&raw, args);
-
-    println!("{}", filtered.trim());
-
-    let cmd_str = if is_npx {
-        format!("npx nx {}", args[1..].join(" "))
+    let filtered = if preview {
+        filter_nx_affected_preview(&stdout)
+    } else if is_lint(args) {
+        filter_nx_lint(&raw, output.status.success())
+    } else if is_run_many(args) {
+        filter_nx_run_many(&raw)
     } else {
-        format!("nx {}", args.join(" "))
+        filter_nx_output(&raw, args)
+    };
+
+    let filtered = match (output.status.success(), nx_build_project(args)) {
+        (true, Some(project)) => match record_bundle_size(&project, &raw) {
+            Some(line) => format!("{}\n{}", filtered.trim_end(), line),
+            None => filtered,
+        },
+        _ => filtered,
     };
 
-    timer.track(
-        &cmd_str,
-        &format!("rtk {}", cmd_str),
-        &raw,
-        &filtered,
-    );
+    emit(filtered.trim());
+
+    timer.track(&cmd_str, &format!("rtk {}", cmd_str), &raw, &filtered);
 
     if !output.status.success() {
-        std::process::exit(output.status.code().unwrap_or(1));
+        return Err(CommandError::with_excerpt(
+            "nx",
+            nx_target(args),
+            output.status.code(),
+            String::new(),
+        )
+        .into());
     }
 
     Ok(())
@@ -68,12 +128,16 @@ fn filter_nx_output(output: &str, args: &[String]) -> String {
     // Detect command type from args
     let is_test = args.iter().any(|a| a == "test" || a == "e2e");
     let is_build = args.iter().any(|a| a == "build");
-    let is_serve = args.iter().any(|a| a == "serve" || a == "dev" || a == "start" || a.starts_with("start:"));
-    let is_affected = args.iter().any(|a| a == "affected" || a.starts_with("affected:"));
+    let is_serve = is_serve_command(args);
+    let is_affected = args
+        .iter()
+        .any(|a| a == "affected" || a.starts_with("affected:"));
 
     for line in output.lines() {
         // Skip task graph visualization
-        if line.contains("Tasks to run for affected projects") || line.starts_with(" >") && line.contains(":") {
+        if line.contains("Tasks to run for affected projects")
+            || line.starts_with(" >") && line.contains(":")
+        {
             skip_task_graph = true;
             continue;
         }
@@ -89,11 +153,7 @@ fn filter_nx_output(output: &str, args: &[String]) -> String {
         }
 
         // Skip Nx Cloud ads and prompts
-        if line.contains("Nx Cloud")
-            || line.contains("nx.app")
-            || line.contains("faster remote builds")
-            || line.contains("run-many")
-            || line.contains("NX   Nx ") {
+        if is_nx_noise(line) {
             continue;
         }
 
@@ -104,13 +164,7 @@ fn filter_nx_output(output: &str, args: &[String]) -> String {
 
         // For serve/dev commands, only keep essential startup info
         if is_serve {
-            if line.contains("Application bundle generation complete")
-                || line.contains("Compiled successfully")
-                || line.contains("Local:")
-                || line.contains("ready -")
-                || line.contains("started")
-                || line.contains("ERROR")
-                || line.contains("WARNING") {
+            if keep_nx_serve_line(line) {
                 result.push(line.to_string());
             }
             continue;
@@ -123,7 +177,8 @@ fn filter_nx_output(output: &str, args: &[String]) -> String {
                 || line.contains("Test Suites:")
                 || line.contains("Tests:")
                 || line.contains("Snapshots:")
-                || line.contains("ERROR") {
+                || line.contains("ERROR")
+            {
                 result.push(line.to_string());
             }
             continue;
@@ -138,7 +193,8 @@ fn filter_nx_output(output: &str, args: &[String]) -> String {
                 || line.contains("ERROR")
                 || line.contains("WARNING")
                 || line.contains("Bundle")
-                || line.contains("Initial Chunk Files") {
+                || line.contains("Initial Chunk Files")
+            {
                 result.push(line.to_string());
             }
             continue;
@@ -148,7 +204,8 @@ fn filter_nx_output(output: &str, args: &[String]) -> String {
         if is_affected {
             if line.contains("Affected projects:")
                 || line.starts_with("  - ")
-                || line.contains("NX   Running target") {
+                || line.contains("NX   Running target")
+            {
                 result.push(line.to_string());
             }
             continue;
@@ -162,7 +219,8 @@ fn filter_nx_output(output: &str, args: &[String]) -> String {
             || line.contains("FAILED")
             || line.contains("Warning")
             || line.starts_with("NX   Successfully ran target")
-            || line.starts_with("NX   Ran target") {
+            || line.starts_with("NX   Ran target")
+        {
             result.push(line.to_string());
         }
     }
@@ -174,10 +232,466 @@ fn filter_nx_output(output: &str, args: &[String]) -> String {
     }
 }
 
+/// Severity lines kept per project by `filter_nx_lint`
+const LINT_LINES_PER_PROJECT: usize = 20;
+
+/// Raw output lines shown when nx lint fails without ESLint rows to report
+const LINT_FAILURE_TAIL: usize = 15;
+
+/// `nx lint`, `nx run web:lint` or `nx run-many -t lint`
+fn is_lint(args: &[String]) -> bool {
+    args.iter().any(|a| a == "lint" || a.ends_with(":lint"))
+}
+
+/// ESLint problems for one project under `nx lint`
+#[derive(Default)]
+struct LintProject {
+    name: String,
+    errors: usize,
+    warnings: usize,
+    lines: Vec<String>,
+}
+
+/// Filter `nx lint` output - per project, error/warning counts and only the
+/// ESLint "line:col  error|warning  message" rows under their file.
+/// Pass/fail follows nx's exit status; a failure with no rows to show (config
+/// error, crashed executor) keeps the tail of the raw output instead.
+fn filter_nx_lint(output: &str, success: bool) -> String {
+    let mut projects: Vec<LintProject> = Vec::new();
+    let mut file: Option<&str> = None;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+
+        // "> nx run web:lint" starts a project's output
+        if let Some(task) = trimmed.strip_prefix("> nx run ") {
+            let name = task.split(':').next().unwrap_or(task).to_string();
+            projects.push(LintProject {
+                name,
+                ..Default::default()
+            });
+            file = None;
+            continue;
+        }
+
+        // ESLint stylish format: unindented file path, then indented problems
+        if !line.starts_with(' ') && (trimmed.starts_with('/') || trimmed.starts_with("./")) {
+            file = Some(trimmed);
+            continue;
+        }
+
+        let mut words = trimmed.split_whitespace();
+        let position = words.next().unwrap_or("");
+        let severity = words.next().unwrap_or("");
+        let is_position = position.split(':').count() == 2
+            && position
+                .split(':')
+                .all(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+        if !is_position || !matches!(severity, "error" | "warning") {
+            continue;
+        }
+
+        if projects.is_empty() {
+            projects.push(LintProject {
+                name: "lint".to_string(),
+                ..Default::default()
+            });
+        }
+        let project = projects.last_mut().expect("pushed above");
+        if severity == "error" {
+            project.errors += 1;
+        } else {
+            project.warnings += 1;
+        }
+        if let Some(path) = file.take() {
+            project.lines.push(format!("  {}", path));
+        }
+        project.lines.push(format!("    {}", trimmed));
+    }
+
+    let mut result = Vec::new();
+    let failing = projects.iter().filter(|p| p.errors > 0).count();
+    let total_errors: usize = projects.iter().map(|p| p.errors).sum();
+    let total_warnings: usize = projects.iter().map(|p| p.warnings).sum();
+
+    for p in projects.iter().filter(|p| p.errors + p.warnings > 0) {
+        result.push(format!(
+            "{}: {} errors, {} warnings",
+            p.name, p.errors, p.warnings
+        ));
+        result.extend(p.lines.iter().take(LINT_LINES_PER_PROJECT).cloned());
+        if p.lines.len() > LINT_LINES_PER_PROJECT {
+            result.push(format!(
+                "    ... +{} more",
+                p.lines.len() - LINT_LINES_PER_PROJECT
+            ));
+        }
+    }
+
+    if failing > 0 {
+        result.push(format!(
+            "✗ {} projects with lint errors ({} total)",
+            failing, total_errors
+        ));
+    } else if !success {
+        if total_warnings == 0 {
+            let lines: Vec<&str> = output
+                .lines()
+                .filter(|l| !l.trim().is_empty() && !is_nx_noise(l))
+                .collect();
+            let start = lines.len().saturating_sub(LINT_FAILURE_TAIL);
+            result.extend(lines[start..].iter().map(|l| l.trim_end().to_string()));
+            result.push("✗ lint failed".to_string());
+        } else {
+            result.push(format!("✗ lint failed ({} warnings)", total_warnings));
+        }
+    } else if total_warnings > 0 {
+        result.push(format!("✓ lint passed ({} warnings)", total_warnings));
+    } else {
+        result.push("✓ lint passed".to_string());
+    }
+
+    result.join("\n")
+}
+
+/// First nx argument after the optional `npx`-style leading "nx" (e.g. "build")
+fn nx_target(args: &[String]) -> &str {
+    let args = match args.first().map(|s| s.as_str()) {
+        Some("nx") => &args[1..],
+        _ => args,
+    };
+    args.first().map(|s| s.as_str()).unwrap_or("")
+}
+
+/// `nx affected ... --dry-run` (or `--graph=stdout`): list what would run
+fn is_affected_preview(args: &[String]) -> bool {
+    args.iter()
+        .any(|a| a == "affected" || a.starts_with("affected:"))
+        && args
+            .iter()
+            .any(|a| a == "--dry-run" || a == "--graph=stdout")
+}
+
+/// Swap rtk's `--dry-run` for `--graph=stdout`, which prints the task graph
+/// as JSON without running anything
+fn with_graph_preview(args: &[String]) -> Vec<String> {
+    if !is_affected_preview(args) {
+        return args.to_vec();
+    }
+    let mut swapped: Vec<String> = args.iter().filter(|a| *a != "--dry-run").cloned().collect();
+    if !swapped.iter().any(|a| a == "--graph=stdout") {
+        swapped.push("--graph=stdout".to_string());
+    }
+    swapped
+}
+
+/// Summarize the `--graph=stdout` task graph: "Affected: api, web (targets: build, test)"
+fn filter_nx_affected_preview(output: &str) -> String {
+    let json: Option<Value> = output
+        .find('{')
+        .and_then(|start| serde_json::from_str(&output[start..]).ok());
+    let Some(json) = json else {
+        return filter_nx_output(output, &["affected".to_string()]);
+    };
+
+    let mut projects: Vec<String> = Vec::new();
+    let mut targets: Vec<String> = Vec::new();
+    if let Some(tasks) = json["tasks"]["tasks"].as_object() {
+        for (id, task) in tasks {
+            let (project, target) = match (
+                task["target"]["project"].as_str(),
+                task["target"]["target"].as_str(),
+            ) {
+                (Some(p), Some(t)) => (p, t),
+                _ => match id.split_once(':') {
+                    Some(pair) => pair,
+                    None => continue,
+                },
+            };
+            if !projects.iter().any(|p| p == project) {
+                projects.push(project.to_string());
+            }
+            if !targets.iter().any(|t| t == target) {
+                targets.push(target.to_string());
+            }
+        }
+    }
+
+    if projects.is_empty() {
+        return "Affected: none".to_string();
+    }
+    projects.sort();
+    format!(
+        "Affected: {} (targets: {})",
+        projects.join(", "),
+        targets.join(", ")
+    )
+}
+
+/// `nx run-many -t <target>` runs one target across several projects
+fn is_run_many(args: &[String]) -> bool {
+    args.iter().any(|a| a == "run-many")
+}
+
+/// Filter `nx run-many`: keep per-project ✔/✖ lines and errors, then summarize
+fn filter_nx_run_many(output: &str) -> String {
+    let mut result = Vec::new();
+    let mut succeeded = 0;
+    let mut failed = 0;
+
+    for line in output.lines() {
+        let trimmed = line.trim();
+        if is_nx_noise(line) {
+            continue;
+        }
+
+        // Per-project result: "✔  nx run api:build (2s)" / "✖  nx run web:build"
+        if trimmed.contains("nx run ") {
+            if trimmed.starts_with('✔') || trimmed.starts_with('✓') {
+                succeeded += 1;
+                result.push(trimmed.to_string());
+                continue;
+            }
+            if trimmed.starts_with('✖') || trimmed.starts_with('✗') {
+                failed += 1;
+                result.push(trimmed.to_string());
+                continue;
+            }
+        }
+
+        if line.contains("ERROR") || trimmed.starts_with("error") || line.contains("FAILED") {
+            result.push(line.to_string());
+        }
+    }
+
+    let total = succeeded + failed;
+    if total > 0 {
+        let mark = if failed > 0 { '✗' } else { '✓' };
+        result.push(format!(
+            "{} {}/{} projects succeeded",
+            mark, succeeded, total
+        ));
+    }
+
+    if result.is_empty() {
+        "ok ✓".to_string()
+    } else {
+        result.join("\n")
+    }
+}
+
+/// serve/dev/start targets are long-running and get streamed
+fn is_serve_command(args: &[String]) -> bool {
+    args.iter()
+        .any(|a| a == "serve" || a == "dev" || a == "start" || a.starts_with("start:"))
+}
+
+/// Nx Cloud ads and prompts
+fn is_nx_noise(line: &str) -> bool {
+    line.contains("Nx Cloud")
+        || line.contains("nx.app")
+        || line.contains("faster remote builds")
+        || is_run_many_suggestion(line)
+        || line.contains("NX   Nx ")
+}
+
+/// Promotional tips like "Try `nx run-many` to ...", not the command itself
+fn is_run_many_suggestion(line: &str) -> bool {
+    if !line.contains("run-many") {
+        return false;
+    }
+    let lower = line.to_lowercase();
+    lower.contains("try ")
+        || lower.contains("tip:")
+        || lower.contains("hint:")
+        || lower.contains("you can")
+}
+
+/// Per-line filter for serve/dev: keep essential startup info and problems
+fn keep_nx_serve_line(line: &str) -> bool {
+    if is_nx_noise(line) {
+        return false;
+    }
+
+    line.contains("Application bundle generation complete")
+        || line.contains("Compiled successfully")
+        || line.contains("Local:")
+        || line.contains("ready -")
+        || line.contains("started")
+        || line.contains("ERROR")
+        || line.contains("WARNING")
+}
+
+/// Project built by `nx build <project>` / `nx run <project>:build`;
+/// "default" when `nx build` picks the workspace default project
+fn nx_build_project(args: &[String]) -> Option<String> {
+    let args = match args.first().map(|s| s.as_str()) {
+        Some("nx") => &args[1..],
+        _ => args,
+    };
+    let mut positional = args.iter().filter(|a| !a.starts_with('-'));
+    match positional.next().map(|s| s.as_str()) {
+        Some("build") => Some(
+            positional
+                .next()
+                .map(|p| p.to_string())
+                .unwrap_or_else(|| "default".to_string()),
+        ),
+        Some("run") => positional
+            .next()
+            .and_then(|t| {
+                t.strip_suffix(":build")
+                    .or_else(|| t.split_once(":build:").map(|(p, _)| p))
+            })
+            .map(|p| p.to_string()),
+        _ => None,
+    }
+}
+
+/// Sidecar file holding the last bundle size (bytes) per project
+fn bundle_sizes_path() -> PathBuf {
+    let data_dir = dirs::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    data_dir.join("rtk").join("nx-bundle-sizes.json")
+}
+
+/// Compare the bundle size in `output` with the last build of `project`,
+/// remember the new size, and return the "Bundle: ..." line
+fn record_bundle_size(project: &str, output: &str) -> Option<String> {
+    let current = parse_bundle_size(output)?;
+    let path = bundle_sizes_path();
+    let mut sizes: BTreeMap<String, u64> = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default();
+    let previous = sizes.insert(project.to_string(), current);
+
+    // Best effort: a read-only data dir only costs the next delta
+    if let Some(dir) = path.parent() {
+        let _ = std::fs::create_dir_all(dir);
+    }
+    if let Ok(json) = serde_json::to_string_pretty(&sizes) {
+        let _ = std::fs::write(&path, json);
+    }
+
+    Some(bundle_delta_line(previous, current))
+}
+
+/// Initial bundle size in bytes: Angular's "Initial Total" row, webpack's
+/// "Entrypoint main 245 KiB", or any other "Bundle ... 245 kB" line
+fn parse_bundle_size(output: &str) -> Option<u64> {
+    let find = |pred: &dyn Fn(&str) -> bool| {
+        output
+            .lines()
+            .filter(|l| pred(l))
+            .find_map(parse_size_in_line)
+    };
+    find(&|l| l.contains("Initial Total"))
+        .or_else(|| find(&|l| l.trim_start().starts_with("Entrypoint main")))
+        .or_else(|| find(&|l| l.contains("Bundle")))
+}
+
+/// First "<number> <unit>" pair in the line, in bytes
+fn parse_size_in_line(line: &str) -> Option<u64> {
+    let words: Vec<&str> = line
+        .split(|c: char| c.is_whitespace() || c == '|')
+        .filter(|w| !w.is_empty())
+        .collect();
+    words.windows(2).find_map(|pair| {
+        let value: f64 = pair[0].parse().ok()?;
+        let unit = match pair[1] {
+            "B" | "bytes" => 1.0,
+            "kB" | "KB" => 1_000.0,
+            "KiB" => 1_024.0,
+            "MB" => 1_000_000.0,
+            "MiB" => 1_048_576.0,
+            _ => return None,
+        };
+        Some((value * unit).round() as u64)
+    })
+}
+
+/// "Bundle: 245 kB (+3 kB since last build)"
+fn bundle_delta_line(previous: Option<u64>, current: u64) -> String {
+    let size = format_bundle_size(current);
+    match previous {
+        None => format!("Bundle: {}", size),
+        Some(prev) if prev == current => format!("Bundle: {} (unchanged since last build)", size),
+        Some(prev) => {
+            let (sign, delta) = if current > prev {
+                ('+', current - prev)
+            } else {
+                ('-', prev - current)
+            };
+            format!(
+                "Bundle: {} ({}{} since last build)",
+                size,
+                sign,
+                format_bundle_size(delta)
+            )
+        }
+    }
+}
+
+fn format_bundle_size(bytes: u64) -> String {
+    if bytes >= 1_000_000 {
+        format!("{:.2} MB", bytes as f64 / 1_000_000.0)
+    } else if bytes >= 1_000 {
+        format!("{:.0} kB", bytes as f64 / 1_000.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_bundle_size_parse_and_delta() {
+        let previous = r#"
+Initial Chunk Files   | Names         |  Raw Size | Estimated Transfer Size
+main-ABCD1234.js      | main          | 200.10 kB |                50.00 kB
+polyfills-EFGH5678.js | polyfills     |  41.90 kB |                13.00 kB
+                      | Initial Total | 242.00 kB |                63.00 kB
+"#;
+        let current = previous.replace("242.00 kB", "245.32 kB");
+        let previous = parse_bundle_size(previous);
+        let current = parse_bundle_size(&current).unwrap();
+        assert_eq!(previous, Some(242_000));
+        assert_eq!(current, 245_320);
+
+        assert_eq!(
+            bundle_delta_line(previous, current),
+            "Bundle: 245 kB (+3 kB since last build)"
+        );
+        assert_eq!(bundle_delta_line(None, current), "Bundle: 245 kB");
+        assert_eq!(
+            bundle_delta_line(Some(250_000), current),
+            "Bundle: 245 kB (-5 kB since last build)"
+        );
+
+        assert_eq!(
+            parse_bundle_size("Entrypoint main 1.5 MiB = main.js"),
+            Some(1_572_864)
+        );
+        assert_eq!(parse_bundle_size("Successfully ran target build"), None);
+    }
+
+    #[test]
+    fn test_nx_build_project() {
+        let args = |s: &str| s.split(' ').map(String::from).collect::<Vec<_>>();
+        assert_eq!(nx_build_project(&args("build web")).as_deref(), Some("web"));
+        assert_eq!(
+            nx_build_project(&args("nx build")).as_deref(),
+            Some("default")
+        );
+        assert_eq!(
+            nx_build_project(&args("run api:build:production")).as_deref(),
+            Some("api")
+        );
+        assert_eq!(nx_build_project(&args("test web")), None);
+    }
+
     #[test]
     fn test_filter_nx_test_output() {
         let output = r#"
@@ -216,6 +730,87 @@ NX   Successfully ran target build
         assert!(result.contains("Bundle"));
     }
 
+    #[test]
+    fn test_filter_nx_lint_multi_project() {
+        let output = r#"
+> nx run web:lint
+
+Linting "web"...
+
+/repo/apps/web/src/app.tsx
+  12:5  error    'user' is assigned a value but never used  @typescript-eslint/no-unused-vars
+  20:1  warning  Unexpected console statement               no-console
+
+/repo/apps/web/src/main.tsx
+  3:10  error  'React' is defined but never used  @typescript-eslint/no-unused-vars
+
+✖ 3 problems (2 errors, 1 warning)
+
+> nx run api:lint
+
+Linting "api"...
+All files pass linting.
+
+> nx run admin:lint
+
+/repo/apps/admin/src/index.ts
+  7:1  error  Unexpected var, use let or const instead  no-var
+
+✖ 1 problem (1 error, 0 warnings)
+
+ NX   Ran target lint for 3 projects (4s)
+
+   ✖    2/3 failed
+   ✔    1/3 succeeded [0 read from cache]
+"#;
+        let args = vec!["run-many".to_string(), "-t".to_string(), "lint".to_string()];
+        assert!(is_lint(&args));
+        let result = filter_nx_lint(output, false);
+        assert_eq!(
+            result,
+            "web: 2 errors, 1 warnings\n\
+             \x20 /repo/apps/web/src/app.tsx\n\
+             \x20   12:5  error    'user' is assigned a value but never used  @typescript-eslint/no-unused-vars\n\
+             \x20   20:1  warning  Unexpected console statement               no-console\n\
+             \x20 /repo/apps/web/src/main.tsx\n\
+             \x20   3:10  error  'React' is defined but never used  @typescript-eslint/no-unused-vars\n\
+             admin: 1 errors, 0 warnings\n\
+             \x20 /repo/apps/admin/src/index.ts\n\
+             \x20   7:1  error  Unexpected var, use let or const instead  no-var\n\
+             ✗ 2 projects with lint errors (3 total)"
+        );
+        assert!(!result.contains("Linting"));
+        assert!(!result.contains("api"));
+    }
+
+    #[test]
+    fn test_filter_nx_lint_failure_without_rows() {
+        let output = r#"
+> nx run web:lint
+
+Linting "web"...
+
+Oops! Something went wrong! :(
+
+ESLint: 8.57.0
+
+Error: Failed to load config "next/core-web-vitals" to extend from.
+Referenced from: /repo/apps/web/.eslintrc.json
+
+ NX   Running target lint for project web failed
+"#;
+        let result = filter_nx_lint(output, false);
+        assert!(!result.contains("lint passed"));
+        assert!(result.contains("Failed to load config"));
+        assert!(result.ends_with("✗ lint failed"));
+
+        // A clean run with nothing to report passes
+        assert_eq!(
+            filter_nx_lint("All files pass linting.\n", true),
+            "✓ lint passed"
+        );
+    }
+
     #[test]
     fn test_filter_nx_affected() {
         let output = r#"
@@ -232,4 +827,136 @@ NX   Running target test for 3 projects
         assert!(result.contains("- api"));
         assert!(result.contains("- player-web"));
     }
+
+    #[test]
+    fn test_affected_dry_run_preview() {
+        let args: Vec<String> = ["affected", "-t", "build", "test", "--dry-run"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert!(is_affected_preview(&args));
+        assert_eq!(
+            with_graph_preview(&args),
+            vec!["affected", "-t", "build", "test", "--graph=stdout"]
+        );
+        assert!(!is_affected_preview(&[
+            "build".to_string(),
+            "--dry-run".to_string()
+        ]));
+
+        let output = r#"
+ NX   Affected criteria defaulted to --base=main --head=HEAD
+
+{
+  "graph": {"nodes": {}, "dependencies": {}},
+  "tasks": {
+    "tasks": {
+      "web:build": {"id": "web:build", "target": {"project": "web", "target": "build"}},
+      "api:build": {"id": "api:build", "target": {"project": "api", "target": "build"}},
+      "api:test": {"id": "api:test", "target": {"project": "api", "target": "test"}}
+    },
+    "dependencies": {},
+    "roots": ["api:build", "web:build"]
+  }
+}
+"#;
+        assert_eq!(
+            filter_nx_affected_preview(output),
+            "Affected: api, web (targets: build, test)"
+        );
+        assert_eq!(
+            filter_nx_affected_preview(r#"{"tasks": {"tasks": {}}}"#),
+            "Affected: none"
+        );
+    }
+
+    #[test]
+    fn test_filter_nx_output_capped() {
+        let output = (0..50)
+            .map(|i| format!("PASS  apps/api/test/file{}.test.ts", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let args = vec!["test".to_string(), "api".to_string()];
+        let result = crate::utils::capture::emitted(Some(10), None, || {
+            emit(&filter_nx_output(&output, &args));
+        });
+        assert_eq!(result.lines().count(), 11);
+        assert!(result.ends_with("... (rtk capped at 10 lines)\n"));
+    }
+
+    #[test]
+    fn test_filter_nx_run_many_with_failure() {
+        let output = r#"
+ >  NX   Running target build for 3 projects:
+
+    - api
+    - web
+    - shared
+
+ ——————————————————————————————————————————————
+
+> nx run shared:build
+
+✔  nx run shared:build (1s)
+
+> nx run api:build
+
+✔  nx run api:build (3s)
+
+> nx run web:build
+
+ERROR in apps/web/src/main.ts: Cannot find module './app'
+✖  nx run web:build
+
+ ——————————————————————————————————————————————
+
+ >  NX   Ran target build for 3 projects (5s)
+
+    ✔  2/3 succeeded [0 read from cache]
+
+    ✖  1/3 targets failed, including the following:
+
+       - nx run web:build
+
+Tip: try `nx run-many -t build --parallel=5` to speed this up
+"#;
+        let args = vec![
+            "run-many".to_string(),
+            "-t".to_string(),
+            "build".to_string(),
+        ];
+        assert!(is_run_many(&args));
+        let result = filter_nx_run_many(output);
+        assert!(result.contains("✔  nx run shared:build (1s)"));
+        assert!(result.contains("✔  nx run api:build (3s)"));
+        assert!(result.contains("✖  nx run web:build"));
+        assert!(result.contains("ERROR in apps/web/src/main.ts"));
+        assert!(result.ends_with("✗ 2/3 projects succeeded"));
+        assert!(!result.contains("Tip:"));
+        assert!(!result.contains("Running target"));
+    }
+
+    #[test]
+    fn test_filter_nx_run_many_all_failed() {
+        let output = "✖  nx run api:build\n✖  nx run web:build\n";
+        assert!(filter_nx_run_many(output).ends_with("✗ 0/2 projects succeeded"));
+    }
+
+    #[test]
+    fn test_run_many_command_not_noise() {
+        assert!(!is_nx_noise("> nx run-many -t build"));
+        assert!(is_nx_noise(
+            "Tip: try `nx run-many` to run targets in parallel"
+        ));
+    }
+
+    #[test]
+    fn test_keep_nx_serve_line() {
+        assert!(keep_nx_serve_line("  ➜  Local:   http://localhost:4200/"));
+        assert!(keep_nx_serve_line("ERROR in src/app.ts"));
+        assert!(!keep_nx_serve_line("> nx run web:serve"));
+        assert!(!keep_nx_serve_line("Nx Cloud started remote caching"));
+        assert!(is_serve_command(&["serve".to_string(), "web".to_string()]));
+        assert!(!is_serve_command(&["build".to_string()]));
+    }
 }
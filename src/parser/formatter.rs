@@ -203,12 +203,17 @@ impl TokenFormatter for DependencyState {
             self.outdated_count, self.total_packages
         )];
 
-        for dep in self.dependencies.iter().take(10) {
+        // Majors first; unclassifiable versions last, original order otherwise
+        let mut deps: Vec<&Dependency> = self.dependencies.iter().collect();
+        deps.sort_by_key(|dep| (dep.bump().is_none(), dep.bump()));
+
+        for dep in deps.into_iter().take(10) {
             if let Some(latest) = &dep.latest_version {
                 if &dep.current_version != latest {
+                    let prefix = dep.bump().map_or("?", VersionBump::label);
                     lines.push(format!(
-                        "{}: {} → {}",
-                        dep.name, dep.current_version, latest
+                        "{:<5} {}: {} → {}",
+                        prefix, dep.name, dep.current_version, latest
                     ));
                 }
             }
@@ -66,6 +66,55 @@ pub struct Dependency {
     pub dev_dependency: bool,
 }
 
+/// Size of an upgrade, ordered from riskiest to safest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VersionBump {
+    Major,
+    Minor,
+    Patch,
+}
+
+impl VersionBump {
+    pub fn label(self) -> &'static str {
+        match self {
+            VersionBump::Major => "major",
+            VersionBump::Minor => "minor",
+            VersionBump::Patch => "patch",
+        }
+    }
+}
+
+impl Dependency {
+    /// Classify the upgrade from current to latest; `None` when either side is
+    /// not a plain semver (git urls, dist-tags, workspace links).
+    pub fn bump(&self) -> Option<VersionBump> {
+        let current = parse_semver(&self.current_version)?;
+        let latest = parse_semver(self.latest_version.as_deref()?)?;
+        if latest.0 != current.0 {
+            Some(VersionBump::Major)
+        } else if latest.1 != current.1 {
+            Some(VersionBump::Minor)
+        } else {
+            // Same x.y: a patch or a pre-release change
+            Some(VersionBump::Patch)
+        }
+    }
+}
+
+/// Parse "^1.2.3", "~1.2", "v2.0.0-beta.1" into (major, minor, patch)
+fn parse_semver(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.trim().trim_start_matches(['^', '~', '=', 'v']);
+    let core = version.split(['-', '+']).next()?;
+    let mut parts = core.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+    let patch = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((major, minor, patch))
+}
+
 /// Build output (next, webpack, vite, cargo, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BuildOutput {
@@ -1,4 +1,5 @@
 use crate::tracking;
+use crate::utils::emit;
 use anyhow::{Context, Result};
 use serde::Deserialize;
 use std::process::Command;
@@ -76,7 +77,7 @@ fn run_list(base_cmd: &str, args: &[String], verbose: u8) -> Result<(String, Str
     let raw = format!("{}\n{}", stdout, stderr);
 
     let filtered = filter_pip_list(&stdout);
-    println!("{}", filtered);
+    emit(&filtered);
 
     if !output.status.success() {
         std::process::exit(output.status.code().unwrap_or(1));
@@ -111,7 +112,7 @@ fn run_outdated(base_cmd: &str, args: &[String], verbose: u8) -> Result<(String,
     let raw = format!("{}\n{}", stdout, stderr);
 
     let filtered = filter_pip_outdated(&stdout);
-    println!("{}", filtered);
+    emit(&filtered);
 
     if !output.status.success() {
         std::process::exit(output.status.code().unwrap_or(1));
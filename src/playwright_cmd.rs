@@ -1,5 +1,5 @@
 use crate::tracking;
-use crate::utils::{package_manager_exec, strip_ansi};
+use crate::utils::{emit, package_manager_exec, strip_ansi};
 use anyhow::{Context, Result};
 use regex::Regex;
 use serde::Deserialize;
@@ -263,7 +263,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
         }
     };
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track(
         &format!("playwright {}", args.join(" ")),
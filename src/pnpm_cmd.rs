@@ -1,8 +1,11 @@
+use crate::explain::{self, DropTally};
 use crate::tracking;
+use crate::utils::{emit, emit_json, extract_format_json, run_raw, run_streaming, CommandError};
 use anyhow::{Context, Result};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::ffi::OsString;
+use std::path::Path;
 use std::process::Command;
 
 use crate::parser::{
@@ -118,12 +121,7 @@ fn extract_list_text(output: &str) -> Option<DependencyState> {
 
     for line in output.lines() {
         // Skip box-drawing and metadata
-        if line.contains('│')
-            || line.contains('├')
-            || line.contains('└')
-            || line.contains("Legend:")
-            || line.trim().is_empty()
-        {
+        if is_tree_line(line) || is_tree_metadata(line) {
             continue;
         }
 
@@ -159,6 +157,16 @@ fn extract_list_text(output: &str) -> Option<DependencyState> {
     }
 }
 
+/// Nested entry of a pnpm dependency tree ("├── debug 2.6.9")
+fn is_tree_line(line: &str) -> bool {
+    line.contains('│') || line.contains('├') || line.contains('└')
+}
+
+/// Legend and blank lines around pnpm dependency trees
+fn is_tree_metadata(line: &str) -> bool {
+    line.contains("Legend:") || line.trim().is_empty()
+}
+
 /// Parser for pnpm outdated output
 pub struct PnpmOutdatedParser;
 
@@ -276,18 +284,62 @@ fn is_valid_package_name(name: &str) -> bool {
         .all(|c| c.is_alphanumeric() || matches!(c, '@' | '/' | '-' | '_' | '.'))
 }
 
+/// Validates a package.json script name (e.g. `build`, `test:unit`)
+fn is_valid_script_name(name: &str) -> bool {
+    if name.is_empty() || name.len() > 214 || name.starts_with('-') {
+        return false;
+    }
+
+    // No path traversal
+    if name.contains("..") {
+        return false;
+    }
+
+    // Only safe characters
+    name.chars()
+        .all(|c| c.is_alphanumeric() || matches!(c, ':' | '@' | '/' | '-' | '_' | '.'))
+}
+
 #[derive(Debug, Clone)]
 pub enum PnpmCommand {
     List { depth: usize },
     Outdated,
     Install { packages: Vec<String> },
+    Run { script: String },
+    Why { package: String },
 }
 
-pub fn run(cmd: PnpmCommand, args: &[String], verbose: u8) -> Result<()> {
+pub fn run(cmd: PnpmCommand, args: &[String], verbose: u8, raw: bool) -> Result<()> {
+    if raw {
+        let mut pnpm_args = subcommand_args(&cmd);
+        pnpm_args.extend(args.iter().cloned());
+        return run_raw(
+            Command::new("pnpm").args(&pnpm_args),
+            &format!("pnpm {}", pnpm_args.join(" ")),
+        );
+    }
+
     match cmd {
         PnpmCommand::List { depth } => run_list(depth, args, verbose),
         PnpmCommand::Outdated => run_outdated(args, verbose),
         PnpmCommand::Install { packages } => run_install(&packages, args, verbose),
+        PnpmCommand::Run { script } => run_script(&script, args, verbose),
+        PnpmCommand::Why { package } => run_why(&package, args, verbose),
+    }
+}
+
+/// The pnpm subcommand (and implied args) a `PnpmCommand` maps to
+fn subcommand_args(cmd: &PnpmCommand) -> Vec<String> {
+    match cmd {
+        PnpmCommand::List { depth } => vec!["list".to_string(), format!("--depth={}", depth)],
+        PnpmCommand::Outdated => vec!["outdated".to_string()],
+        PnpmCommand::Install { packages } => {
+            let mut args = vec!["install".to_string()];
+            args.extend(packages.iter().cloned());
+            args
+        }
+        PnpmCommand::Run { script } => vec!["run".to_string(), script.clone()],
+        PnpmCommand::Why { package } => vec!["why".to_string(), package.clone()],
     }
 }
 
@@ -304,14 +356,19 @@ fn run_list(depth: usize, args: &[String], verbose: u8) -> Result<()> {
     }
 
     let output = cmd.output().context("Failed to run pnpm list")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("pnpm list failed: {}", stderr);
+        return Err(pnpm_failure(
+            &timer,
+            &format!("list --depth={}", depth),
+            &stdout,
+            &stderr,
+            output.status.code(),
+        ));
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-
     // Parse output using PnpmListParser
     let parse_result = PnpmListParser::parse(&stdout);
     let mode = FormatMode::from_verbosity(verbose);
@@ -335,7 +392,7 @@ fn run_list(depth: usize, args: &[String], verbose: u8) -> Result<()> {
         }
     };
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track(
         &format!("pnpm list --depth={}", depth),
@@ -347,9 +404,50 @@ fn run_list(depth: usize, args: &[String], verbose: u8) -> Result<()> {
     Ok(())
 }
 
+/// Outdated packages by name, printed by `rtk pnpm outdated --format json`
+#[derive(Debug, PartialEq, Serialize)]
+struct OutdatedSummary {
+    outdated: Vec<OutdatedEntry>,
+    /// pnpm's output couldn't be parsed; rerun without `--format json` to see it
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    unparsed: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct OutdatedEntry {
+    name: String,
+    current: String,
+    wanted: Option<String>,
+    latest: Option<String>,
+    dev: bool,
+}
+
+fn outdated_summary(state: &DependencyState) -> OutdatedSummary {
+    let mut outdated: Vec<OutdatedEntry> = state
+        .dependencies
+        .iter()
+        .filter(|d| d.latest_version.as_deref() != Some(d.current_version.as_str()))
+        .map(|d| OutdatedEntry {
+            name: d.name.clone(),
+            current: d.current_version.clone(),
+            wanted: d.wanted_version.clone(),
+            latest: d.latest_version.clone(),
+            dev: d.dev_dependency,
+        })
+        .collect();
+    outdated.sort_by(|a, b| a.name.cmp(&b.name));
+    OutdatedSummary {
+        outdated,
+        unparsed: false,
+    }
+}
+
 fn run_outdated(args: &[String], verbose: u8) -> Result<()> {
     let timer = tracking::TimedExecution::start();
 
+    // pnpm's own JSON is always requested below; `--format json` asks for ours
+    let (json, args) = extract_format_json(args);
+
     let mut cmd = Command::new("pnpm");
     cmd.arg("outdated");
     cmd.arg("--format");
@@ -364,11 +462,23 @@ fn run_outdated(args: &[String], verbose: u8) -> Result<()> {
     let stderr = String::from_utf8_lossy(&output.stderr);
     let combined = format!("{}{}", stdout, stderr);
 
+    // Exit code 1 means "has outdated packages", not an error
+    if !output.status.success() && output.status.code() != Some(1) {
+        return Err(pnpm_failure(
+            &timer,
+            "outdated",
+            &stdout,
+            &stderr,
+            output.status.code(),
+        ));
+    }
+
     // Parse output using PnpmOutdatedParser
     let parse_result = PnpmOutdatedParser::parse(&stdout);
     let mode = FormatMode::from_verbosity(verbose);
 
     let filtered = match parse_result {
+        ParseResult::Full(data) if json => serde_json::to_string(&outdated_summary(&data))?,
         ParseResult::Full(data) => {
             if verbose > 0 {
                 eprintln!("pnpm outdated (Tier 1: Full JSON parse)");
@@ -379,18 +489,31 @@ fn run_outdated(args: &[String], verbose: u8) -> Result<()> {
             if verbose > 0 {
                 emit_degradation_warning("pnpm outdated", &warnings.join(", "));
             }
-            data.format(mode)
+            if json {
+                serde_json::to_string(&outdated_summary(&data))?
+            } else {
+                data.format(mode)
+            }
         }
         ParseResult::Passthrough(raw) => {
             emit_passthrough_warning("pnpm outdated", "All parsing tiers failed");
-            raw
+            if json {
+                serde_json::to_string(&OutdatedSummary {
+                    outdated: Vec::new(),
+                    unparsed: true,
+                })?
+            } else {
+                raw
+            }
         }
     };
 
-    if filtered.trim().is_empty() {
-        println!("All packages up-to-date ✓");
+    if json {
+        emit_json(&filtered);
+    } else if filtered.trim().is_empty() {
+        emit("All packages up-to-date ✓");
     } else {
-        println!("{}", filtered);
+        emit(&filtered);
     }
 
     timer.track("pnpm outdated", "rtk pnpm outdated", &combined, &filtered);
@@ -431,13 +554,20 @@ fn run_install(packages: &[String], args: &[String], verbose: u8) -> Result<()>
     let stderr = String::from_utf8_lossy(&output.stderr);
 
     if !output.status.success() {
-        anyhow::bail!("pnpm install failed: {}", stderr);
+        return Err(pnpm_failure(
+            &timer,
+            &format!("install {}", packages.join(" ")),
+            &stdout,
+            &stderr,
+            output.status.code(),
+        ));
     }
 
     let combined = format!("{}{}", stdout, stderr);
-    let filtered = filter_pnpm_install(&combined);
+    let (filtered, drops) = filter_pnpm_install(&combined);
 
-    println!("{}", filtered);
+    emit(&filtered);
+    explain::report(&drops);
 
     timer.track(
         &format!("pnpm install {}", packages.join(" ")),
@@ -449,19 +579,253 @@ fn run_install(packages: &[String], args: &[String], verbose: u8) -> Result<()>
     Ok(())
 }
 
-/// Filter pnpm install output - remove progress bars, keep summary
-fn filter_pnpm_install(output: &str) -> String {
+/// Maximum dependency paths shown by `pnpm why`
+const WHY_MAX_PATHS: usize = 10;
+
+fn run_why(package: &str, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    if !is_valid_package_name(package) || package.starts_with('-') {
+        anyhow::bail!(
+            "Invalid package name: '{}' (contains unsafe characters)",
+            package
+        );
+    }
+
+    if verbose > 0 {
+        eprintln!("pnpm why {} {}", package, args.join(" "));
+    }
+
+    let output = Command::new("pnpm")
+        .arg("why")
+        .arg(package)
+        .args(args)
+        .output()
+        .context("Failed to run pnpm why")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    if !output.status.success() {
+        return Err(pnpm_failure(
+            &timer,
+            &format!("why {}", package),
+            &stdout,
+            &stderr,
+            output.status.code(),
+        ));
+    }
+
+    let filtered = filter_pnpm_why(&stdout, package);
+    emit(&filtered);
+
+    timer.track(
+        &format!("pnpm why {}", package),
+        &format!("rtk pnpm why {}", package),
+        &stdout,
+        &filtered,
+    );
+
+    Ok(())
+}
+
+/// Flatten the `pnpm why` tree into deduplicated "a > b > pkg" chains
+fn filter_pnpm_why(output: &str, package: &str) -> String {
+    let mut chain: Vec<&str> = Vec::new();
+    let mut paths: Vec<String> = Vec::new();
+
+    for line in output.lines() {
+        if is_tree_metadata(line) {
+            continue;
+        }
+
+        // Top-level lines are direct dependencies; skip section headers and
+        // project lines ("app@1.0.0 /home/me/app")
+        if !is_tree_line(line)
+            && (line.trim_end().ends_with(':')
+                || line.split_whitespace().any(|w| w.starts_with('/')))
+        {
+            continue;
+        }
+
+        // Each tree level is two columns after the "├── " connector
+        let prefix = line
+            .chars()
+            .take_while(|c| !c.is_alphanumeric() && *c != '@')
+            .count();
+        let depth = if prefix == 0 {
+            0
+        } else {
+            prefix.saturating_sub(2) / 2
+        };
+        let Some(name) = line
+            .trim_start_matches(|c: char| !c.is_alphanumeric() && c != '@')
+            .split_whitespace()
+            .next()
+        else {
+            continue;
+        };
+
+        chain.truncate(depth);
+        chain.push(name);
+
+        if name == package && chain.len() > 1 {
+            let path = chain.join(" > ");
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+    }
+
+    if paths.is_empty() {
+        return format!("{}: no dependency paths found", package);
+    }
+
+    let mut result = vec![format!(
+        "{}: {} dependency path{}",
+        package,
+        paths.len(),
+        if paths.len() == 1 { "" } else { "s" }
+    )];
+    result.extend(paths.iter().take(WHY_MAX_PATHS).cloned());
+    if paths.len() > WHY_MAX_PATHS {
+        result.push(format!("... +{} more paths", paths.len() - WHY_MAX_PATHS));
+    }
+    result.join("\n")
+}
+
+/// Track a pnpm failure and build the error carrying pnpm's own exit code
+fn pnpm_failure(
+    timer: &tracking::TimedExecution,
+    subcommand: &str,
+    stdout: &str,
+    stderr: &str,
+    code: Option<i32>,
+) -> anyhow::Error {
+    let raw = format!("{}{}", stdout, stderr);
+    let err = CommandError::with_excerpt("pnpm", subcommand, code, compact_pnpm_error(&raw));
+    timer.track(
+        &format!("pnpm {}", subcommand),
+        &format!("rtk pnpm {}", subcommand),
+        &raw,
+        &err.to_string(),
+    );
+    err.into()
+}
+
+/// Keep pnpm's ERR_PNPM_* / error lines; fall back to the first few lines
+fn compact_pnpm_error(output: &str) -> String {
+    // --frozen-lockfile against a stale lockfile: say so plainly, since the
+    // raw message buries it in specifier dumps
+    if output.contains("ERR_PNPM_OUTDATED_LOCKFILE") {
+        return "lockfile out of date: pnpm-lock.yaml doesn't match package.json \
+                (--frozen-lockfile)\n\
+                run pnpm install without --frozen-lockfile and commit pnpm-lock.yaml"
+            .to_string();
+    }
+    let errors: Vec<&str> = output
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| l.contains("ERR_PNPM") || l.starts_with("ERR!") || l.starts_with("error"))
+        .collect();
+    if !errors.is_empty() {
+        return errors.join("\n");
+    }
+    output
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .take(5)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// What an install did to the lockfile and the direct dependencies
+#[derive(Debug, Default, PartialEq)]
+struct InstallChanges {
+    /// pnpm skipped resolution: "Lockfile is up to date"
+    lockfile_up_to_date: bool,
+    added: usize,
+    removed: usize,
+    updated: usize,
+}
+
+/// Direct dependency changes come from the "+ zod 3.23.8" / "- zod 3.22.0"
+/// lines (a name on both sides is an update); without them, from the
+/// "Packages: +3 -1" totals
+fn parse_install_changes(output: &str) -> InstallChanges {
+    let mut changes = InstallChanges {
+        lockfile_up_to_date: output.contains("Lockfile is up to date"),
+        ..Default::default()
+    };
+    let mut plus: Vec<&str> = Vec::new();
+    let mut minus: Vec<&str> = Vec::new();
+    let mut packages = (0, 0);
+
+    for line in output.lines().map(str::trim) {
+        if let Some(counts) = line.strip_prefix("Packages:") {
+            for token in counts.split_whitespace() {
+                if let Some(n) = token.strip_prefix('+').and_then(|n| n.parse().ok()) {
+                    packages.0 = n;
+                } else if let Some(n) = token.strip_prefix('-').and_then(|n| n.parse().ok()) {
+                    packages.1 = n;
+                }
+            }
+        } else if let Some(dep) = line.strip_prefix("+ ") {
+            plus.extend(dep.split_whitespace().next());
+        } else if let Some(dep) = line.strip_prefix("- ") {
+            minus.extend(dep.split_whitespace().next());
+        }
+    }
+
+    if plus.is_empty() && minus.is_empty() {
+        changes.added = packages.0;
+        changes.removed = packages.1;
+    } else {
+        changes.updated = plus.iter().filter(|p| minus.contains(p)).count();
+        changes.added = plus.len() - changes.updated;
+        changes.removed = minus.len() - changes.updated;
+    }
+    changes
+}
+
+/// "ok ✓ lockfile changed: 1 added, 0 removed, 1 updated" / "ok ✓ lockfile unchanged"
+fn format_install_changes(changes: &InstallChanges) -> String {
+    let any = changes.added + changes.removed + changes.updated > 0;
+    let state = if changes.lockfile_up_to_date || !any {
+        "unchanged"
+    } else {
+        "changed"
+    };
+    if any {
+        format!(
+            "ok ✓ lockfile {}: {} added, {} removed, {} updated",
+            state, changes.added, changes.removed, changes.updated
+        )
+    } else {
+        format!("ok ✓ lockfile {}", state)
+    }
+}
+
+/// Filter pnpm install output - remove progress bars, keep dependency
+/// changes and end with the lockfile summary.
+/// Also returns why each dropped line went, for `--explain`.
+fn filter_pnpm_install(output: &str) -> (String, DropTally) {
     let mut result = Vec::new();
-    let mut saw_progress = false;
+    let mut drops = DropTally::default();
 
     for line in output.lines() {
-        // Skip progress bars
-        if line.contains("Progress") || line.contains('│') || line.contains('%') {
-            saw_progress = true;
+        // Skip progress bars, incl. the "+++--" bar under "Packages:"
+        if line.contains("Progress")
+            || line.contains('│')
+            || line.contains('%')
+            || (!line.trim().is_empty() && line.trim().chars().all(|c| c == '+' || c == '-'))
+        {
+            drops.add("progress");
             continue;
         }
 
-        if saw_progress && line.trim().is_empty() {
+        if line.trim().is_empty() {
+            drops.add("blank");
             continue;
         }
 
@@ -478,14 +842,143 @@ fn filter_pnpm_install(output: &str) -> String {
             || line.starts_with('-')
         {
             result.push(line.trim().to_string());
+        } else {
+            drops.add("info");
         }
     }
 
-    if result.is_empty() {
-        "ok ✓".to_string()
-    } else {
-        result.join("\n")
+    result.push(format_install_changes(&parse_install_changes(output)));
+    (result.join("\n"), drops)
+}
+
+fn run_script(script: &str, args: &[String], verbose: u8) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    // Validate script name to prevent command injection
+    if !is_valid_script_name(script) {
+        anyhow::bail!(
+            "Invalid script name: '{}' (contains unsafe characters)",
+            script
+        );
+    }
+
+    let mut cmd = Command::new("pnpm");
+    cmd.arg("run").arg(script);
+
+    for arg in args {
+        cmd.arg(arg);
     }
+
+    if verbose > 0 {
+        eprintln!("pnpm run {} running...", script);
+    }
+
+    // `dev`/`start`/`watch` scripts run until stopped: filter as lines arrive
+    let mut banner = RunBannerFilter::new(script);
+    let streamed =
+        run_streaming(&mut cmd, |line| banner.keep(line)).context("Failed to run pnpm run")?;
+    if streamed.status.success() && streamed.filtered.trim().is_empty() {
+        emit("ok ✓");
+    }
+
+    timer.track(
+        &format!("pnpm run {} {}", script, args.join(" ")),
+        &format!("rtk pnpm run {} {}", script, args.join(" ")),
+        &streamed.raw,
+        &streamed.filtered,
+    );
+
+    if !streamed.status.success() {
+        let subcommand = format!("run {}", script);
+        return Err(CommandError::with_excerpt(
+            "pnpm",
+            &subcommand,
+            streamed.status.code(),
+            String::new(),
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Line filter for `pnpm run` output: drops the lifecycle banner
+/// ("> project@1.0.0 build /path" followed by the echoed "> command") and
+/// collapses the blank lines around it. Stateful so it can run while streaming.
+struct RunBannerFilter<'a> {
+    script: &'a str,
+    in_banner: bool,
+    last_blank: bool,
+}
+
+impl<'a> RunBannerFilter<'a> {
+    fn new(script: &'a str) -> Self {
+        Self {
+            script,
+            in_banner: false,
+            last_blank: true,
+        }
+    }
+
+    fn keep(&mut self, line: &str) -> bool {
+        let trimmed = line.trim();
+
+        if let Some(rest) = trimmed.strip_prefix("> ") {
+            let mut words = rest.split_whitespace();
+            let is_header = words.next().is_some_and(|pkg| pkg.contains('@'))
+                && words.next() == Some(self.script);
+            if is_header || self.in_banner {
+                self.in_banner = is_header;
+                return false;
+            }
+        }
+        self.in_banner = false;
+
+        if trimmed.is_empty() && self.last_blank {
+            return false;
+        }
+        self.last_blank = trimmed.is_empty();
+        true
+    }
+}
+
+/// `pnpm <script>` shorthand: the first argument names a package.json script
+/// (in the current directory) that is not also a pnpm command.
+pub fn script_shorthand(args: &[OsString]) -> Option<(String, Vec<String>)> {
+    let (first, rest) = args.split_first()?;
+    let name = first.to_str()?;
+    if PNPM_COMMANDS.contains(&name) || !is_valid_script_name(name) {
+        return None;
+    }
+    if !package_scripts(Path::new(".")).iter().any(|s| s == name) {
+        return None;
+    }
+    let rest = rest
+        .iter()
+        .map(|a| a.to_string_lossy().into_owned())
+        .collect();
+    Some((name.to_string(), rest))
+}
+
+/// pnpm commands that win over a script of the same name.
+const PNPM_COMMANDS: &[&str] = &[
+    "add", "audit", "bin", "config", "create", "dedupe", "deploy", "dlx", "env", "exec", "fetch",
+    "i", "import", "init", "install", "licenses", "link", "list", "ls", "outdated", "pack",
+    "patch", "prune", "publish", "rebuild", "remove", "rm", "root", "run", "store", "unlink", "up",
+    "update", "why",
+];
+
+/// Script names declared in `dir/package.json` (empty when unreadable).
+fn package_scripts(dir: &Path) -> Vec<String> {
+    std::fs::read_to_string(dir.join("package.json"))
+        .ok()
+        .and_then(|raw| serde_json::from_str::<serde_json::Value>(&raw).ok())
+        .and_then(|json| {
+            json.get("scripts")?
+                .as_object()
+                .map(|scripts| scripts.keys().cloned().collect())
+        })
+        .unwrap_or_default()
 }
 
 /// Runs an unsupported pnpm subcommand by passing it through directly
@@ -507,7 +1000,7 @@ pub fn run_passthrough(args: &[OsString], verbose: u8) -> Result<()> {
     );
 
     if !status.success() {
-        std::process::exit(status.code().unwrap_or(1));
+        return Err(CommandError::with_excerpt("pnpm", "", status.code(), String::new()).into());
     }
     Ok(())
 }
@@ -515,6 +1008,7 @@ pub fn run_passthrough(args: &[OsString], verbose: u8) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::VersionBump;
 
     #[test]
     fn test_pnpm_list_parser_json() {
@@ -556,6 +1050,175 @@ mod tests {
         assert_eq!(data.dependencies[0].name, "express");
     }
 
+    #[test]
+    fn test_outdated_summary_json_shape() {
+        let data = outdated(
+            r#"{
+                "vitest": {"current": "1.6.0", "latest": "2.0.1", "wanted": "1.6.0", "dependencyType": "devDependencies"},
+                "express": {"current": "4.18.2", "latest": "4.19.0", "wanted": "4.19.0"},
+                "zod": {"current": "3.23.8", "latest": "3.23.8"}
+            }"#,
+        );
+        assert_eq!(
+            serde_json::to_string(&outdated_summary(&data)).unwrap(),
+            r#"{"outdated":[{"name":"express","current":"4.18.2","wanted":"4.19.0","latest":"4.19.0","dev":false},{"name":"vitest","current":"1.6.0","wanted":"1.6.0","latest":"2.0.1","dev":true}]}"#
+        );
+    }
+
+    #[test]
+    fn test_outdated_summary_unparsed_is_json() {
+        let summary = OutdatedSummary {
+            outdated: Vec::new(),
+            unparsed: true,
+        };
+        let json = serde_json::to_string(&summary).unwrap();
+        assert_eq!(json, r#"{"outdated":[],"unparsed":true}"#);
+
+        // Never capped: a truncation marker would break the document
+        let out = crate::utils::capture::emitted(Some(1), Some(10), || {
+            emit_json(&json);
+        });
+        assert!(serde_json::from_str::<serde_json::Value>(&out).is_ok());
+    }
+
+    fn outdated(json: &str) -> DependencyState {
+        PnpmOutdatedParser::parse(json).unwrap()
+    }
+
+    #[test]
+    fn test_pnpm_outdated_major_bump_listed_first() {
+        let data = outdated(
+            r#"{
+            "express": {"current": "4.18.2", "latest": "4.19.0", "wanted": "4.18.2"},
+            "react": {"current": "^17.0.2", "latest": "18.2.0", "wanted": "17.0.2"}
+        }"#,
+        );
+        let react = data
+            .dependencies
+            .iter()
+            .find(|d| d.name == "react")
+            .unwrap();
+        assert_eq!(react.bump(), Some(VersionBump::Major));
+
+        let output = data.format_compact();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[1], "major react: ^17.0.2 → 18.2.0");
+        assert_eq!(lines[2], "minor express: 4.18.2 → 4.19.0");
+    }
+
+    #[test]
+    fn test_pnpm_outdated_minor_and_patch_bumps() {
+        let data = outdated(
+            r#"{
+            "zod": {"current": "~3.22.4", "latest": "3.23.0-beta.1", "wanted": "3.22.4"},
+            "vite": {"current": "5.0.10", "latest": "5.0.12", "wanted": "5.0.12"}
+        }"#,
+        );
+        let output = data.format_compact();
+        assert!(output.contains("minor zod: ~3.22.4 → 3.23.0-beta.1"));
+        assert!(output.contains("patch vite: 5.0.10 → 5.0.12"));
+        assert!(output.find("minor zod").unwrap() < output.find("patch vite").unwrap());
+    }
+
+    #[test]
+    fn test_pnpm_outdated_unparseable_version_degrades() {
+        let data = outdated(
+            r#"{
+            "local-lib": {"current": "link:../lib", "latest": "2.0.0", "wanted": "2.0.0"},
+            "lodash": {"current": "4.17.20", "latest": "4.17.21", "wanted": "4.17.21"}
+        }"#,
+        );
+        let local = data
+            .dependencies
+            .iter()
+            .find(|d| d.name == "local-lib")
+            .unwrap();
+        assert_eq!(local.bump(), None);
+
+        let output = data.format_compact();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[1], "patch lodash: 4.17.20 → 4.17.21");
+        assert_eq!(lines[2], "?     local-lib: link:../lib → 2.0.0");
+    }
+
+    #[test]
+    fn test_filter_pnpm_install_drop_reasons() {
+        let output = "\
+Packages: +3
++++
+Progress: resolved 1, reused 0, downloaded 0, added 0
+Progress: resolved 120, reused 118, downloaded 2, added 0
+Progress: resolved 120, reused 118, downloaded 2, added 3, done
+
+dependencies:
++ zod 3.23.8
+
+Done in 1.2s
+";
+        let (filtered, drops) = filter_pnpm_install(output);
+        assert_eq!(
+            filtered,
+            "dependencies:\n+ zod 3.23.8\nok ✓ lockfile changed: 1 added, 0 removed, 0 updated"
+        );
+        assert_eq!(drops.count("progress"), 4);
+        assert_eq!(drops.count("blank"), 2);
+        assert_eq!(drops.count("info"), 2);
+        assert_eq!(
+            drops.summary(),
+            "suppressed: 2 info lines, 4 progress lines, 2 blank lines"
+        );
+    }
+
+    #[test]
+    fn test_parse_install_changes() {
+        let output = "\
+Packages: +2 -1
+++-
+dependencies:
++ zod 3.23.8
+- lodash 4.17.20
++ lodash 4.17.21
++ dayjs 1.11.10
+";
+        let changes = parse_install_changes(output);
+        assert_eq!(
+            changes,
+            InstallChanges {
+                lockfile_up_to_date: false,
+                added: 2,
+                removed: 0,
+                updated: 1,
+            }
+        );
+
+        let frozen = "Lockfile is up to date, resolution step is skipped\nPackages: +120\n";
+        let (filtered, _) = filter_pnpm_install(frozen);
+        assert_eq!(
+            filtered,
+            "ok ✓ lockfile unchanged: 120 added, 0 removed, 0 updated"
+        );
+
+        let (filtered, _) = filter_pnpm_install("Already up to date\nDone in 0.4s\n");
+        assert_eq!(filtered, "ok ✓ lockfile unchanged");
+    }
+
+    #[test]
+    fn test_frozen_lockfile_mismatch_error() {
+        let output = "\
+ ERR_PNPM_OUTDATED_LOCKFILE  Cannot install with \"frozen-lockfile\" because pnpm-lock.yaml is not up to date with <ROOT>/package.json
+
+Note that in CI environments this setting is true by default. If you still need to run install in such cases, use \"pnpm install --no-frozen-lockfile\"
+
+  Failure reason:
+  specifiers in the lockfile ({\"zod\":\"^3.22.0\"}) don't match specs in package.json ({\"zod\":\"^3.23.0\"})
+";
+        assert_eq!(
+            compact_pnpm_error(output),
+            "lockfile out of date: pnpm-lock.yaml doesn't match package.json (--frozen-lockfile)\n\
+             run pnpm install without --frozen-lockfile and commit pnpm-lock.yaml"
+        );
+    }
+
     #[test]
     fn test_package_name_validation() {
         assert!(is_valid_package_name("lodash"));
@@ -570,4 +1233,165 @@ mod tests {
         let _args: Vec<OsString> = vec![OsString::from("help")];
         // Compile-time verification that the function exists with correct signature
     }
+
+    #[test]
+    fn test_is_valid_script_name() {
+        assert!(is_valid_script_name("build"));
+        assert!(is_valid_script_name("test:unit"));
+        assert!(!is_valid_script_name("build; rm -rf /"));
+        assert!(!is_valid_script_name("../x"));
+        assert!(!is_valid_script_name("--filter"));
+    }
+
+    fn filter_pnpm_run(output: &str, script: &str) -> String {
+        let mut banner = RunBannerFilter::new(script);
+        let kept: Vec<&str> = output.lines().filter(|l| banner.keep(l)).collect();
+        kept.join("\n").trim().to_string()
+    }
+
+    #[test]
+    fn test_filter_pnpm_run_strips_banner() {
+        let output = r#"
+> my-app@1.0.0 build /home/user/my-app
+> tsc -p . && vite build
+
+vite v5.0.0 building for production...
+✓ 42 modules transformed.
+dist/index.html   0.46 kB
+dist/assets/index.js   143.2 kB
+✓ built in 1.23s
+"#;
+        let result = filter_pnpm_run(output, "build");
+        assert!(!result.contains("my-app@1.0.0"));
+        assert!(!result.contains("> tsc"));
+        assert!(result.starts_with("vite v5.0.0 building"));
+        assert!(result.contains("dist/assets/index.js"));
+        assert!(result.contains("✓ built in 1.23s"));
+    }
+
+    #[test]
+    fn test_filter_pnpm_run_keeps_errors() {
+        let output = r#"
+> my-app@1.0.0 lint /home/user/my-app
+> eslint src
+
+src/app.ts
+  3:7  error  'x' is assigned a value but never used  no-unused-vars
+
+ ELIFECYCLE  Command failed with exit code 1.
+"#;
+        let result = filter_pnpm_run(output, "lint");
+        assert!(!result.contains("> eslint"));
+        assert!(result.contains("no-unused-vars"));
+        assert!(result.contains("ELIFECYCLE"));
+    }
+
+    #[test]
+    fn test_run_banner_filter_streams_line_by_line() {
+        let mut banner = RunBannerFilter::new("dev");
+        let kept: Vec<&str> = [
+            "",
+            "> my-app@1.0.0 dev /home/user/my-app",
+            "> vite",
+            "",
+            "  VITE v5.0.0  ready in 312 ms",
+            "",
+            "",
+            "  ➜  Local:   http://localhost:5173/",
+        ]
+        .into_iter()
+        .filter(|l| banner.keep(l))
+        .collect();
+        assert_eq!(
+            kept,
+            vec![
+                "  VITE v5.0.0  ready in 312 ms",
+                "",
+                "  ➜  Local:   http://localhost:5173/"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_script_shorthand_reads_package_scripts() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("package.json"),
+            r#"{"name":"app","scripts":{"dev":"vite","add":"echo"}}"#,
+        )
+        .unwrap();
+        let mut scripts = package_scripts(dir.path());
+        scripts.sort();
+        assert_eq!(scripts, vec!["add", "dev"]);
+        assert!(package_scripts(&dir.path().join("missing")).is_empty());
+
+        // pnpm commands win over scripts of the same name
+        assert!(script_shorthand(&["add".into(), "dev".into()]).is_none());
+        assert!(script_shorthand(&[]).is_none());
+    }
+
+    #[test]
+    fn test_filter_pnpm_why() {
+        let output = r#"Legend: production dependency, optional only, dev only
+
+my-app@1.0.0 /home/me/my-app
+
+dependencies:
+express 4.18.2
+├─┬ body-parser 1.20.1
+│ └── debug 2.6.9
+├── debug 2.6.9
+└─┬ send 0.18.0
+  ├── debug 2.6.9
+  └─┬ finalhandler 1.2.0
+    └── debug 2.6.9
+
+devDependencies:
+@types/express 4.17.21
+└─┬ body-parser 1.20.1
+  └── debug 2.6.9
+"#;
+        let result = filter_pnpm_why(output, "debug");
+        assert_eq!(
+            result,
+            "debug: 5 dependency paths\n\
+             express > body-parser > debug\n\
+             express > debug\n\
+             express > send > debug\n\
+             express > send > finalhandler > debug\n\
+             @types/express > body-parser > debug"
+        );
+        assert!(!result.contains('│'));
+
+        assert_eq!(
+            filter_pnpm_why("Legend: production dependency\n\n", "left-pad"),
+            "left-pad: no dependency paths found"
+        );
+    }
+
+    #[test]
+    fn test_filter_pnpm_why_dedup_and_cap() {
+        let mut output = String::from("dependencies:\n");
+        for i in 0..12 {
+            output.push_str(&format!("pkg-{} 1.0.0\n└── ms 2.1.3\n", i));
+        }
+        output.push_str("pkg-0 1.0.0\n└── ms 2.1.3\n");
+        let result = filter_pnpm_why(&output, "ms");
+        assert!(result.starts_with("ms: 12 dependency paths\npkg-0 > ms\n"));
+        assert!(result.ends_with("... +2 more paths"));
+        assert_eq!(result.lines().count(), 12);
+    }
+
+    #[test]
+    fn test_compact_pnpm_error() {
+        let output = "Progress: resolved 12, reused 10\n\
+ ERR_PNPM_FETCH_404  GET https://registry.npmjs.org/nope: Not Found - 404\n\
+\n\
+This error happened while installing a direct dependency\n";
+        assert_eq!(
+            compact_pnpm_error(output),
+            "ERR_PNPM_FETCH_404  GET https://registry.npmjs.org/nope: Not Found - 404"
+        );
+        assert_eq!(compact_pnpm_error("\nsomething broke\n"), "something broke");
+    }
 }
@@ -1,5 +1,5 @@
 use crate::tracking;
-use crate::utils::package_manager_exec;
+use crate::utils::{emit, package_manager_exec};
 use anyhow::{Context, Result};
 
 pub fn run(args: &[String], verbose: u8) -> Result<()> {
@@ -26,7 +26,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
 
     let filtered = filter_prettier_output(&raw);
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track(
         &format!("prettier {}", args.join(" ")),
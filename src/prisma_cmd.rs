@@ -1,4 +1,5 @@
 use crate::tracking;
+use crate::utils::emit;
 use anyhow::{Context, Result};
 use std::process::Command;
 
@@ -69,7 +70,7 @@ fn run_generate(args: &[String], verbose: u8) -> Result<()> {
     let raw = format!("{}\n{}", stdout, stderr);
     let filtered = filter_prisma_generate(&raw);
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track("prisma generate", "rtk prisma generate", &raw, &filtered);
 
@@ -125,7 +126,7 @@ fn run_migrate(subcommand: MigrateSubcommand, args: &[String], verbose: u8) -> R
         MigrateSubcommand::Deploy => filter_migrate_deploy(&raw),
     };
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track(cmd_name, &format!("rtk {}", cmd_name), &raw, &filtered);
 
@@ -158,7 +159,7 @@ fn run_db_push(args: &[String], verbose: u8) -> Result<()> {
     let raw = format!("{}\n{}", stdout, stderr);
     let filtered = filter_db_push(&raw);
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track("prisma db push", "rtk prisma db push", &raw, &filtered);
 
@@ -1,5 +1,5 @@
 use crate::tracking;
-use crate::utils::truncate;
+use crate::utils::{emit, truncate};
 use anyhow::{Context, Result};
 use std::process::Command;
 
@@ -53,7 +53,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
 
     let filtered = filter_pytest_output(&stdout);
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     // Include stderr if present (import errors, etc.)
     if !stderr.trim().is_empty() {
@@ -1,5 +1,6 @@
 use crate::filter::{self, FilterLevel, Language};
 use crate::tracking;
+use crate::utils::emit;
 use anyhow::{Context, Result};
 use std::fs;
 use std::path::Path;
@@ -60,7 +61,7 @@ pub fn run(
     } else {
         filtered.clone()
     };
-    println!("{}", rtk_output);
+    emit(&rtk_output);
     timer.track(
         &format!("cat {}", file.display()),
         "rtk read",
@@ -126,7 +127,7 @@ pub fn run_stdin(
     } else {
         filtered.clone()
     };
-    println!("{}", rtk_output);
+    emit(&rtk_output);
 
     timer.track("cat - (stdin)", "rtk read -", &content, &rtk_output);
     Ok(())
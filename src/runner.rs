@@ -1,4 +1,5 @@
 use crate::tracking;
+use crate::utils::emit;
 use anyhow::{Context, Result};
 use regex::Regex;
 use std::process::{Command, Stdio};
@@ -49,7 +50,7 @@ pub fn run_err(command: &str, verbose: u8) -> Result<()> {
         rtk.push_str(&filtered);
     }
 
-    println!("{}", rtk);
+    emit(&rtk);
     timer.track(command, "rtk run-err", &raw, &rtk);
     Ok(())
 }
@@ -82,7 +83,7 @@ pub fn run_test(command: &str, verbose: u8) -> Result<()> {
     let raw = format!("{}\n{}", stdout, stderr);
 
     let summary = extract_test_summary(&raw, command);
-    println!("{}", summary);
+    emit(&summary);
     timer.track(command, "rtk run-test", &raw, &summary);
     Ok(())
 }
@@ -1,5 +1,5 @@
 use crate::tracking;
-use crate::utils::truncate;
+use crate::utils::{emit, truncate};
 use anyhow::{Context, Result};
 use regex::Regex;
 use std::process::{Command, Stdio};
@@ -32,7 +32,7 @@ pub fn run(command: &str, verbose: u8) -> Result<()> {
     let raw = format!("{}\n{}", stdout, stderr);
 
     let summary = summarize_output(&raw, command, output.status.success());
-    println!("{}", summary);
+    emit(&summary);
     timer.track(command, "rtk summary", &raw, &summary);
     Ok(())
 }
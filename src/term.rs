@@ -0,0 +1,76 @@
+//! Terminal capability detection.
+//!
+//! Centralizes the "may we emit color?" decision for filters and passthrough
+//! commands:
+//! - `NO_COLOR` (any non-empty value) disables color
+//! - `FORCE_COLOR` / `CLICOLOR_FORCE` (non-"0") force color
+//! - otherwise color only when stdout is a terminal
+//! - never when `--out` sends the output to a file
+//!
+//! Glyph selection ([`crate::glyphs::ascii_mode`]) deliberately does not use
+//! [`should_colorize`]: it shares only the explicit `NO_COLOR` opt-out
+//! ([`no_color`]). Piped output keeps its emoji, so the same command gives
+//! the same text whether or not stdout is a TTY (and tests don't depend on it).
+
+use std::io::IsTerminal;
+
+/// Returns true when ANSI color may be emitted on stdout.
+pub fn should_colorize() -> bool {
+    if crate::utils::out_path().is_some() {
+        return false;
+    }
+    colorize_from(
+        env_flag("NO_COLOR"),
+        env_flag("FORCE_COLOR").or_else(|| env_flag("CLICOLOR_FORCE")),
+        std::io::stdout().is_terminal(),
+    )
+}
+
+/// Returns true when output goes straight to a terminal: stdout is a TTY and
+/// `--out` is not redirecting it. Passthroughs then keep inherited stdio.
+pub fn stdout_is_terminal() -> bool {
+    crate::utils::out_path().is_none() && std::io::stdout().is_terminal()
+}
+
+/// Returns true when `NO_COLOR` asks for plain output. Besides disabling
+/// color this also selects ASCII glyphs (see [`crate::glyphs::ascii_mode`]).
+pub fn no_color() -> bool {
+    env_flag("NO_COLOR").is_some()
+}
+
+fn env_flag(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.is_empty())
+}
+
+fn colorize_from(no_color: Option<String>, force: Option<String>, is_tty: bool) -> bool {
+    if no_color.is_some() {
+        return false;
+    }
+    if let Some(force) = force {
+        return force != "0";
+    }
+    is_tty
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_colorize_follows_tty() {
+        assert!(colorize_from(None, None, true));
+        assert!(!colorize_from(None, None, false));
+    }
+
+    #[test]
+    fn test_no_color_wins() {
+        assert!(!colorize_from(Some("1".into()), None, true));
+        assert!(!colorize_from(Some("1".into()), Some("1".into()), true));
+    }
+
+    #[test]
+    fn test_force_color() {
+        assert!(colorize_from(None, Some("1".into()), false));
+        assert!(!colorize_from(None, Some("0".into()), true));
+    }
+}
@@ -0,0 +1,110 @@
+//! Token count estimation for savings tracking.
+//!
+//! A flat ~4 chars/token ratio undercounts code (dense punctuation, short
+//! identifiers) and overcounts prose (long common words). Instead the text is
+//! walked in runs of word, punctuation and whitespace characters, and each run
+//! is priced roughly the way BPE tokenizers split it.
+
+#[derive(Clone, Copy, PartialEq)]
+enum CharClass {
+    Word,
+    Punct,
+    Space,
+    Other,
+}
+
+fn classify(c: char) -> CharClass {
+    if c.is_ascii_alphanumeric() || c == '_' {
+        CharClass::Word
+    } else if c.is_whitespace() {
+        CharClass::Space
+    } else if c.is_ascii_punctuation() {
+        CharClass::Punct
+    } else {
+        CharClass::Other
+    }
+}
+
+/// Estimate the token count of `text`.
+///
+/// - words: one token per 5 chars (the single space before a word is folded in)
+/// - punctuation: one token per 2 chars (`();`, `->`, `::` usually merge)
+/// - whitespace: one token per newline, plus one for an indentation run
+/// - anything else (CJK, emoji, symbols): one token per char
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(estimate_tokens(""), 0);
+/// assert_eq!(estimate_tokens("hello world"), 2);
+/// assert_eq!(estimate_tokens("a.b()"), 4);
+/// ```
+pub fn estimate_tokens(text: &str) -> usize {
+    let mut tokens = 0;
+    let mut chars = text.chars().peekable();
+
+    while let Some(first) = chars.next() {
+        let class = classify(first);
+        let mut len: usize = 1;
+        let mut newlines = usize::from(first == '\n');
+        let mut trailing = usize::from(first != '\n');
+        while let Some(&c) = chars.peek() {
+            if classify(c) != class {
+                break;
+            }
+            chars.next();
+            len += 1;
+            if c == '\n' {
+                newlines += 1;
+                trailing = 0;
+            } else {
+                trailing += 1;
+            }
+        }
+
+        tokens += match class {
+            CharClass::Word => len.div_ceil(5),
+            CharClass::Punct => len.div_ceil(2),
+            CharClass::Space => newlines + usize::from(trailing > 1),
+            CharClass::Other => len,
+        };
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_tokens_basics() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("hello"), 1);
+        assert_eq!(estimate_tokens("hello world"), 2);
+        assert_eq!(estimate_tokens("a.b()"), 4);
+        assert_eq!(estimate_tokens("a\n    b"), 4);
+        assert_eq!(estimate_tokens("日本語"), 3);
+    }
+
+    #[test]
+    fn test_code_costs_more_than_prose_of_equal_length() {
+        let code = "fn f(x: &[u8]) -> i32 { x.iter().map(|b| *b as i32).sum::<i32>() }";
+        let prose = "The quick brown fox jumps over the lazy dog while everyone watches \
+                     quietly from the garden";
+        let prose = &prose[..code.len()];
+        assert_eq!(code.len(), prose.len());
+
+        // A flat chars/4 ratio prices both the same
+        let code_tokens = estimate_tokens(code);
+        let prose_tokens = estimate_tokens(prose);
+        assert!(
+            code_tokens > prose_tokens,
+            "code {} vs prose {}",
+            code_tokens,
+            prose_tokens
+        );
+        assert!(prose_tokens < code.len().div_ceil(4));
+        assert!(code_tokens > code.len().div_ceil(4));
+    }
+}
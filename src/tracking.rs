@@ -29,10 +29,12 @@
 //!
 //! See [docs/tracking.md](../docs/tracking.md) for full documentation.
 
+use crate::tokens::estimate_tokens;
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use rusqlite::{params, Connection};
 use serde::Serialize;
+use std::collections::HashMap;
 use std::ffi::OsString;
 use std::path::PathBuf;
 use std::time::Instant;
@@ -84,6 +86,27 @@ pub struct CommandRecord {
     pub savings_pct: f64,
 }
 
+/// One tracked command execution, as exported by `rtk tracking export`.
+///
+/// Token counts are the same estimates stored by [`Tracker::record`].
+#[derive(Debug, Serialize)]
+pub struct TrackedEvent {
+    /// RFC 3339 UTC timestamp of the execution
+    pub timestamp: String,
+    /// Standard command that was replaced (e.g., "git status")
+    pub command: String,
+    /// RTK command that was executed (e.g., "rtk git status")
+    pub rtk_cmd: String,
+    /// Estimated tokens of the raw command output
+    pub input_tokens: usize,
+    /// Estimated tokens of the filtered output
+    pub output_tokens: usize,
+    /// Tokens saved (input - output)
+    pub saved_tokens: usize,
+    /// Execution time in milliseconds
+    pub exec_time_ms: u64,
+}
+
 /// Aggregated statistics across all recorded commands.
 ///
 /// Provides overall metrics and breakdowns by command and by day.
@@ -675,9 +698,166 @@ impl Tracker {
 
         Ok(rows.collect::<Result<Vec<_>, _>>()?)
     }
+
+    /// Visit raw, unaggregated events recorded at or after `since` (all when
+    /// `None`), oldest first. Returns the number of events visited.
+    ///
+    /// Each row is handed to `f` as SQLite yields it, so an export never holds
+    /// the whole table in memory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rtk::tracking::Tracker;
+    ///
+    /// let tracker = Tracker::new()?;
+    /// tracker.for_each_event(None, |event| {
+    ///     println!("{} {}", event.timestamp, event.command);
+    ///     Ok(())
+    /// })?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn for_each_event(
+        &self,
+        since: Option<DateTime<Utc>>,
+        mut f: impl FnMut(TrackedEvent) -> Result<()>,
+    ) -> Result<usize> {
+        let since = since.map(|s| s.to_rfc3339()).unwrap_or_default();
+        let mut stmt = self.conn.prepare(
+            "SELECT timestamp, original_cmd, rtk_cmd, input_tokens, output_tokens,
+                    saved_tokens, exec_time_ms
+             FROM commands
+             WHERE timestamp >= ?1
+             ORDER BY timestamp ASC",
+        )?;
+
+        let rows = stmt.query_map(params![since], |row| {
+            Ok(TrackedEvent {
+                timestamp: row.get(0)?,
+                command: row.get(1)?,
+                rtk_cmd: row.get(2)?,
+                input_tokens: row.get::<_, i64>(3)? as usize,
+                output_tokens: row.get::<_, i64>(4)? as usize,
+                saved_tokens: row.get::<_, i64>(5)? as usize,
+                exec_time_ms: row.get::<_, Option<i64>>(6)?.unwrap_or(0) as u64,
+            })
+        })?;
+
+        let mut count = 0;
+        for row in rows {
+            f(row?)?;
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    /// Get the commands that saved the most tokens cumulatively.
+    ///
+    /// Records are grouped by canonical command name (`git diff`, `cargo test`)
+    /// rather than full argv, so `git diff --stat` and `git diff HEAD~1` count
+    /// toward the same entry. Results are ordered by tokens saved (highest first).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use rtk::tracking::Tracker;
+    ///
+    /// let tracker = Tracker::new()?;
+    /// for (cmd, saved) in tracker.top_commands(5)? {
+    ///     println!("{}: {} tokens saved", cmd, saved);
+    /// }
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn top_commands(&self, limit: usize) -> Result<Vec<(String, usize)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT original_cmd, SUM(saved_tokens)
+             FROM commands
+             GROUP BY original_cmd",
+        )?;
+
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })?;
+
+        // Summed signed: rows written by older versions may hold negative
+        // savings, which must not wrap around when converted
+        let mut totals: HashMap<String, i64> = HashMap::new();
+        for row in rows {
+            let (cmd, saved) = row?;
+            *totals.entry(canonical_command(&cmd)).or_insert(0) += saved;
+        }
+
+        let mut result: Vec<(String, usize)> = totals
+            .into_iter()
+            .filter_map(|(cmd, saved)| Some((cmd, usize::try_from(saved).ok()?)))
+            .filter(|(_, saved)| *saved > 0)
+            .collect();
+        result.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        result.truncate(limit);
+        Ok(result)
+    }
+}
+
+/// Reduce a recorded command line to its canonical `tool subcommand` key.
+///
+/// Keeps the program name plus the first argument when it looks like a
+/// subcommand (`git diff`, `cargo test`); flags, paths and other operands
+/// are dropped (`ls -la src` → `ls`).
+fn canonical_command(cmd: &str) -> String {
+    let mut parts = cmd.split_whitespace();
+    let program = match parts.next() {
+        Some(p) => p,
+        None => return String::new(),
+    };
+
+    match parts.next() {
+        Some(sub)
+            if !sub.starts_with('-')
+                && sub.chars().all(|c| {
+                    c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == ':'
+                }) =>
+        {
+            format!("{} {}", program, sub)
+        }
+        _ => program.to_string(),
+    }
+}
+
+/// Check that the tracking database can be opened and written.
+///
+/// Takes (and rolls back) a write lock so read-only files or directories are
+/// reported. Returns the database path on success.
+pub fn check_db_writable() -> Result<PathBuf> {
+    let db_path = get_db_path()?;
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let conn = Connection::open(&db_path)?;
+    conn.execute_batch("BEGIN IMMEDIATE; ROLLBACK;")?;
+    Ok(db_path)
+}
+
+#[cfg(test)]
+thread_local! {
+    static TEST_DB_PATH: std::cell::RefCell<Option<PathBuf>> = const { std::cell::RefCell::new(None) };
+}
+
+/// Run `f` with this thread's tracking database at `path`, so handler tests
+/// can check what was recorded without touching the real history.
+#[cfg(test)]
+pub fn with_db_path<T>(path: &std::path::Path, f: impl FnOnce() -> T) -> T {
+    TEST_DB_PATH.with(|p| *p.borrow_mut() = Some(path.to_path_buf()));
+    let result = f();
+    TEST_DB_PATH.with(|p| *p.borrow_mut() = None);
+    result
 }
 
 fn get_db_path() -> Result<PathBuf> {
+    #[cfg(test)]
+    if let Some(path) = TEST_DB_PATH.with(|p| p.borrow().clone()) {
+        return Ok(path);
+    }
+
     // Priority 1: Environment variable RTK_DB_PATH
     if let Ok(custom_path) = std::env::var("RTK_DB_PATH") {
         return Ok(PathBuf::from(custom_path));
@@ -695,28 +875,26 @@ fn get_db_path() -> Result<PathBuf> {
     Ok(data_dir.join("rtk").join("history.db"))
 }
 
-/// Estimate token count from text using ~4 chars = 1 token heuristic.
-///
-/// This is a fast approximation suitable for tracking purposes.
-/// For precise counts, integrate with your LLM's tokenizer API.
-///
-/// # Formula
-///
-/// `tokens = ceil(chars / 4)`
-///
-/// # Examples
-///
-/// ```
-/// use rtk::tracking::estimate_tokens;
-///
-/// assert_eq!(estimate_tokens(""), 0);
-/// assert_eq!(estimate_tokens("abcd"), 1);  // 4 chars = 1 token
-/// assert_eq!(estimate_tokens("abcde"), 2); // 5 chars = ceil(1.25) = 2
-/// assert_eq!(estimate_tokens("hello world"), 3); // 11 chars = ceil(2.75) = 3
-/// ```
-pub fn estimate_tokens(text: &str) -> usize {
-    // ~4 chars per token on average
-    (text.len() as f64 / 4.0).ceil() as usize
+/// Write one event as a line of newline-delimited JSON.
+pub fn write_event_jsonl<W: std::io::Write>(event: &TrackedEvent, out: &mut W) -> Result<()> {
+    serde_json::to_writer(&mut *out, event)?;
+    out.write_all(b"\n")?;
+    Ok(())
+}
+
+/// Parse an export `--since` bound: "2024-01-15" (midnight UTC) or RFC 3339.
+pub fn parse_since(since: &str) -> Result<DateTime<Utc>> {
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(since, "%Y-%m-%d") {
+        return Ok(date.and_time(chrono::NaiveTime::MIN).and_utc());
+    }
+    DateTime::parse_from_rfc3339(since)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid --since '{}' (expected YYYY-MM-DD or RFC 3339)",
+                since
+            )
+        })
 }
 
 /// Helper struct for timing command execution
@@ -740,6 +918,43 @@ pub struct TimedExecution {
     start: Instant,
 }
 
+/// Whether `--stats` asked for a per-invocation savings trailer (`RTK_STATS`).
+fn stats_enabled() -> bool {
+    std::env::var("RTK_STATS")
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+/// Savings trailer for `--stats`: "rtk: 4,210 → 180 chars (-95.7%)"
+fn format_stats_trailer(raw: &str, filtered: &str) -> String {
+    let raw_len = raw.chars().count();
+    let filtered_len = filtered.chars().count();
+    let change = if raw_len > 0 {
+        (filtered_len as f64 - raw_len as f64) / raw_len as f64 * 100.0
+    } else {
+        0.0
+    };
+    format!(
+        "rtk: {} → {} chars ({}{:.1}%)",
+        group_thousands(raw_len),
+        group_thousands(filtered_len),
+        if change > 0.0 { "+" } else { "" },
+        change
+    )
+}
+
+fn group_thousands(n: usize) -> String {
+    let digits = n.to_string();
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
 impl TimedExecution {
     /// Start timing a command execution.
     ///
@@ -791,6 +1006,10 @@ impl TimedExecution {
         let input_tokens = estimate_tokens(input);
         let output_tokens = estimate_tokens(output);
 
+        if stats_enabled() {
+            eprintln!("{}", format_stats_trailer(input, output));
+        }
+
         if let Ok(tracker) = Tracker::new() {
             let _ = tracker.record(
                 original_cmd,
@@ -891,14 +1110,21 @@ pub fn track(original_cmd: &str, rtk_cmd: &str, input: &str, output: &str) {
 mod tests {
     use super::*;
 
-    // 1. estimate_tokens — verify ~4 chars/token ratio
     #[test]
-    fn test_estimate_tokens() {
-        assert_eq!(estimate_tokens(""), 0);
-        assert_eq!(estimate_tokens("abcd"), 1); // 4 chars = 1 token
-        assert_eq!(estimate_tokens("abcde"), 2); // 5 chars = ceil(1.25) = 2
-        assert_eq!(estimate_tokens("a"), 1); // 1 char = ceil(0.25) = 1
-        assert_eq!(estimate_tokens("12345678"), 2); // 8 chars = 2 tokens
+    fn test_format_stats_trailer() {
+        let raw = "x".repeat(4210);
+        let filtered = "y".repeat(180);
+        assert_eq!(
+            format_stats_trailer(&raw, &filtered),
+            "rtk: 4,210 → 180 chars (-95.7%)"
+        );
+        assert_eq!(format_stats_trailer("", ""), "rtk: 0 → 0 chars (0.0%)");
+        assert_eq!(
+            format_stats_trailer("ab", "abcd"),
+            "rtk: 2 → 4 chars (+100.0%)"
+        );
+        assert_eq!(group_thousands(1234567), "1,234,567");
+        assert_eq!(group_thousands(999), "999");
     }
 
     // 2. args_display — format OsString vec
@@ -1037,4 +1263,105 @@ mod tests {
         let db_path = get_db_path().expect("Failed to get db path");
         assert!(db_path.ends_with("rtk/history.db"));
     }
+
+    // 9. canonical_command groups argv variants under tool + subcommand
+    #[test]
+    fn test_canonical_command() {
+        assert_eq!(canonical_command("git diff --stat HEAD~1"), "git diff");
+        assert_eq!(canonical_command("git diff"), "git diff");
+        assert_eq!(canonical_command("cargo test -- --nocapture"), "cargo test");
+        assert_eq!(canonical_command("ls -la src"), "ls");
+        assert_eq!(canonical_command("read src/main.rs"), "read");
+        assert_eq!(canonical_command("npm run:build"), "npm run:build");
+        assert_eq!(canonical_command(""), "");
+    }
+
+    // 10. for_each_event + write_event_jsonl — one JSON object per line
+    #[test]
+    fn test_events_export_jsonl() {
+        let tracker = Tracker::new().expect("Failed to create tracker");
+        let test_cmd = format!("rtk export test_{}", std::process::id());
+        let since = Utc::now() - chrono::Duration::seconds(5);
+
+        for (input, output) in [(400, 40), (100, 100), (50, 10)] {
+            tracker
+                .record("git status", &test_cmd, input, output, 12)
+                .expect("Failed to record");
+        }
+
+        let mut out = Vec::new();
+        tracker
+            .for_each_event(Some(since), |event| {
+                if event.rtk_cmd == test_cmd {
+                    write_event_jsonl(&event, &mut out)?;
+                }
+                Ok(())
+            })
+            .expect("Failed to export events");
+
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.lines().count(), 3);
+        let saved: Vec<u64> = text
+            .lines()
+            .map(|line| {
+                let json: serde_json::Value = serde_json::from_str(line).expect("valid JSON line");
+                for key in [
+                    "timestamp",
+                    "command",
+                    "rtk_cmd",
+                    "input_tokens",
+                    "output_tokens",
+                    "saved_tokens",
+                    "exec_time_ms",
+                ] {
+                    assert!(json.get(key).is_some(), "missing {}", key);
+                }
+                json["saved_tokens"].as_u64().unwrap()
+            })
+            .collect();
+        assert_eq!(saved, vec![360, 0, 40]);
+    }
+
+    // 11. top_commands groups by canonical command and ignores net-negative totals
+    #[test]
+    fn test_top_commands_negative_savings() {
+        let dir = tempfile::tempdir().unwrap();
+        with_db_path(&dir.path().join("history.db"), || {
+            let tracker = Tracker::new().expect("Failed to create tracker");
+            tracker
+                .record("git diff HEAD", "rtk git diff", 500, 100, 1)
+                .unwrap();
+            tracker
+                .record("git diff --stat", "rtk git diff", 200, 100, 1)
+                .unwrap();
+            tracker.record("ls -la", "rtk ls", 100, 50, 1).unwrap();
+            tracker
+                .conn
+                .execute(
+                    "INSERT INTO commands (timestamp, original_cmd, rtk_cmd, input_tokens,
+                     output_tokens, saved_tokens, savings_pct, exec_time_ms)
+                     VALUES (?1, 'ls -a', 'rtk ls', 10, 500, -490, 0.0, 1)",
+                    params![Utc::now().to_rfc3339()],
+                )
+                .unwrap();
+
+            let top = tracker.top_commands(5).expect("Failed to get top commands");
+            assert_eq!(top, vec![("git diff".to_string(), 500)]);
+        });
+    }
+
+    #[test]
+    fn test_parse_since() {
+        assert_eq!(
+            parse_since("2024-01-15").unwrap().to_rfc3339(),
+            "2024-01-15T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_since("2024-01-15T10:30:00+02:00")
+                .unwrap()
+                .to_rfc3339(),
+            "2024-01-15T08:30:00+00:00"
+        );
+        assert!(parse_since("last week").is_err());
+    }
 }
@@ -1,5 +1,5 @@
 use crate::tracking;
-use crate::utils::truncate;
+use crate::utils::{emit, truncate};
 use anyhow::{Context, Result};
 use regex::Regex;
 use std::collections::HashMap;
@@ -41,7 +41,7 @@ pub fn run(args: &[String], verbose: u8) -> Result<()> {
 
     let filtered = filter_tsc_output(&raw);
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track(
         &format!("tsc {}", args.join(" ")),
@@ -3,33 +3,379 @@
 //! Provides common helpers used across rtk commands:
 //! - ANSI color code stripping
 //! - Text truncation
+//! - Global output-size caps (`RTK_MAX_OUTPUT_LINES`, `RTK_MAX_OUTPUT` bytes)
+//! - Redirecting filtered output to a file (`--out`, `RTK_OUT`)
 //! - Command execution with error context
+//! - Uniform failure reporting for wrapped tools (`CommandError`)
 
+use crate::tracking;
 use anyhow::{Context, Result};
 use regex::Regex;
-use std::process::Command;
+use std::fmt;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
 
-/// Tronque une chaîne à `max_len` caractères avec "..." si nécessaire.
+/// Tronque une chaîne à `max_len` colonnes d'affichage avec "..." si nécessaire.
+///
+/// Les caractères CJK pleine largeur comptent pour deux colonnes ; la coupe
+/// se fait toujours sur une frontière de caractère.
 ///
 /// # Arguments
 /// * `s` - La chaîne à tronquer
-/// * `max_len` - Longueur maximale avant troncature (minimum 3 pour inclure "...")
+/// * `max_len` - Largeur maximale avant troncature (minimum 3 pour inclure "...")
 ///
 /// # Examples
 /// ```
 /// use rtk::utils::truncate;
 /// assert_eq!(truncate("hello world", 8), "hello...");
 /// assert_eq!(truncate("hi", 10), "hi");
+/// assert_eq!(truncate("你好世界", 7), "你好...");
 /// ```
 pub fn truncate(s: &str, max_len: usize) -> String {
-    let char_count = s.chars().count();
-    if char_count <= max_len {
-        s.to_string()
-    } else if max_len < 3 {
+    if display_width(s) <= max_len {
+        return s.to_string();
+    }
+    if max_len < 3 {
         // If max_len is too small, just return "..."
-        "...".to_string()
-    } else {
-        format!("{}...", s.chars().take(max_len - 3).collect::<String>())
+        return "...".to_string();
+    }
+
+    let budget = max_len - 3;
+    let mut width = 0;
+    let mut cut = 0;
+    for (idx, c) in s.char_indices() {
+        let w = char_width(c);
+        if width + w > budget {
+            break;
+        }
+        width += w;
+        cut = idx + c.len_utf8();
+    }
+    format!("{}...", &s[..cut])
+}
+
+/// Terminal column width of `s`, counting fullwidth CJK characters as two.
+///
+/// # Examples
+/// ```
+/// use rtk::utils::display_width;
+/// assert_eq!(display_width("abc"), 3);
+/// assert_eq!(display_width("中文"), 4);
+/// ```
+pub fn display_width(s: &str) -> usize {
+    s.chars().map(char_width).sum()
+}
+
+/// Approximate East Asian Width: 0 for combining marks, 2 for CJK wide, else 1.
+///
+/// Emoji are deliberately counted as one column: their rendered width varies
+/// between terminals, and callers budget them per character.
+fn char_width(c: char) -> usize {
+    match c as u32 {
+        0x0300..=0x036F // Combining diacritics
+        | 0x0E31 | 0x0E34..=0x0E3A | 0x0E47..=0x0E4E // Thai vowel/tone marks
+        | 0x200B..=0x200F // Zero-width space/joiners
+        | 0xFE00..=0xFE0F => 0, // Variation selectors
+        0x1100..=0x115F // Hangul Jamo
+        | 0x2E80..=0x303E // CJK radicals, punctuation
+        | 0x3041..=0x33FF // Kana, CJK compatibility
+        | 0x3400..=0x4DBF // CJK extension A
+        | 0x4E00..=0x9FFF // CJK unified ideographs
+        | 0xA000..=0xA4CF // Yi
+        | 0xAC00..=0xD7A3 // Hangul syllables
+        | 0xF900..=0xFAFF // CJK compatibility ideographs
+        | 0xFE30..=0xFE4F // CJK compatibility forms
+        | 0xFF00..=0xFF60 // Fullwidth forms
+        | 0xFFE0..=0xFFE6
+        | 0x20000..=0x3FFFD => 2, // CJK extensions B+
+        _ => 1,
+    }
+}
+
+/// Lines already emitted by this process via [`emit`].
+static EMITTED_LINES: AtomicUsize = AtomicUsize::new(0);
+
+/// Read the global output cap from `RTK_MAX_OUTPUT_LINES` (set by `--max-output`),
+/// else the config's `max_output_lines`.
+/// Returns None when unset, invalid, or 0 (no cap).
+pub fn max_output_lines() -> Option<usize> {
+    match std::env::var("RTK_MAX_OUTPUT_LINES") {
+        Ok(v) => v.trim().parse::<usize>().ok(),
+        Err(_) => crate::config::defaults().max_output_lines,
+    }
+    .filter(|&n| n > 0)
+}
+
+/// Cap text to `max` lines, appending the uniform rtk marker when truncated.
+///
+/// # Examples
+/// ```
+/// use rtk::utils::cap_lines;
+/// assert_eq!(cap_lines("a\nb\nc", 2), "a\nb\n... (rtk capped at 2 lines)");
+/// assert_eq!(cap_lines("a\nb", 2), "a\nb");
+/// ```
+#[cfg(test)]
+pub fn cap_lines(text: &str, max: usize) -> String {
+    cap_lines_at(text, max, max)
+}
+
+/// Keep the first `keep` lines and append the marker for a global cap of `max`.
+fn cap_lines_at(text: &str, keep: usize, max: usize) -> String {
+    if text.lines().count() <= keep {
+        return text.to_string();
+    }
+    let mut capped: Vec<String> = text.lines().take(keep).map(String::from).collect();
+    capped.push(format!("... (rtk capped at {} lines)", max));
+    capped.join("\n")
+}
+
+/// Bytes already emitted by this process via [`emit`].
+static EMITTED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Byte guard applied when neither `RTK_MAX_OUTPUT` nor the config sets one.
+const DEFAULT_MAX_OUTPUT_BYTES: usize = 50 * 1024;
+
+/// Read the byte guard from `RTK_MAX_OUTPUT`, else the config's
+/// `max_output_bytes` (default 50 KB).
+/// Returns None when set to 0 (guard disabled); invalid values use the default.
+pub fn max_output_bytes() -> Option<usize> {
+    let max = match std::env::var("RTK_MAX_OUTPUT") {
+        Ok(v) => v
+            .trim()
+            .parse::<usize>()
+            .unwrap_or(DEFAULT_MAX_OUTPUT_BYTES),
+        Err(_) => crate::config::defaults()
+            .max_output_bytes
+            .unwrap_or(DEFAULT_MAX_OUTPUT_BYTES),
+    };
+    (max > 0).then_some(max)
+}
+
+/// Keep at most `keep` bytes of `text`, cut at the last line boundary that
+/// fits (or a char boundary for a single huge line), and append the footer.
+fn cap_bytes_at(text: &str, keep: usize) -> String {
+    if text.len() <= keep {
+        return text.to_string();
+    }
+    let mut cut = keep;
+    while !text.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    if let Some(newline) = text[..cut].rfind('\n') {
+        cut = newline;
+    }
+    format!(
+        "{}\n... [output truncated: {} bytes hidden, re-run raw tool to see all]",
+        &text[..cut],
+        text.len() - cut
+    )
+}
+
+/// Bytes written to the `--out` file by this process via [`emit`].
+static OUT_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Set once [`emit`] has created (truncated) the `--out` file.
+static OUT_STARTED: AtomicBool = AtomicBool::new(false);
+
+/// File that receives filtered output instead of stdout (`RTK_OUT`, set by `--out`).
+pub fn out_path() -> Option<PathBuf> {
+    std::env::var_os("RTK_OUT")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Write `text` plus a newline (like `println!`) to `path`, truncating it
+/// first unless `append`. Returns the number of bytes written.
+fn write_out(path: &Path, text: &str, append: bool) -> std::io::Result<usize> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append)
+        .truncate(!append)
+        .open(path)?;
+    writeln!(file, "{}", text)?;
+    Ok(text.len() + 1)
+}
+
+/// The one line printed to stdout for `--out`
+fn out_pointer(bytes: usize, path: &Path) -> String {
+    format!("wrote {} bytes to {}", bytes, path.display())
+}
+
+/// Finish `--out`: make sure the file exists and print where the output
+/// went. Call once, after the command ran.
+pub fn report_out() {
+    let Some(path) = out_path() else {
+        return;
+    };
+    if !OUT_STARTED.load(Ordering::Relaxed) {
+        if let Err(err) = std::fs::write(&path, "") {
+            eprintln!("rtk: cannot write {}: {}", path.display(), err);
+            return;
+        }
+    }
+    println!("{}", out_pointer(OUT_BYTES.load(Ordering::Relaxed), &path));
+}
+
+/// Print command output to stdout, enforcing the global output caps.
+/// The caps apply across all calls in this process, so tools that print
+/// in several steps are capped as a whole; each marker is printed once.
+/// The byte guard runs last, so even passthrough branches that return the
+/// raw tool output can't flood the context.
+///
+/// With `--out`, the output goes to that file uncapped instead, and
+/// [`report_out`] prints a pointer line.
+pub fn emit(text: &str) {
+    #[cfg(test)]
+    if capture::push(text, true) {
+        return;
+    }
+
+    if emit_to_out_file(text) {
+        return;
+    }
+
+    if let Some(text) = apply_caps(
+        text,
+        max_output_lines(),
+        max_output_bytes(),
+        &EMITTED_LINES,
+        &EMITTED_BYTES,
+    ) {
+        println!("{}", text);
+    }
+}
+
+/// Print one JSON document like [`emit`] (including `--out`), but never
+/// truncated: a cut or a marker would leave invalid JSON. Callers print typed
+/// summaries rather than raw tool output, so the size stays bounded.
+pub fn emit_json(json: &str) {
+    #[cfg(test)]
+    if capture::push(json, false) {
+        return;
+    }
+
+    if !emit_to_out_file(json) {
+        println!("{}", json);
+    }
+}
+
+/// Append `text` to the `--out` file if one is set; false means print it
+fn emit_to_out_file(text: &str) -> bool {
+    let Some(path) = out_path() else {
+        return false;
+    };
+    let append = OUT_STARTED.swap(true, Ordering::Relaxed);
+    match write_out(&path, text, append) {
+        Ok(bytes) => {
+            OUT_BYTES.fetch_add(bytes, Ordering::Relaxed);
+            true
+        }
+        // Don't lose the output: fall through to stdout
+        Err(err) => {
+            eprintln!("rtk: cannot write {}: {}", path.display(), err);
+            false
+        }
+    }
+}
+
+/// The part of `text` that still fits under the line and byte caps, given
+/// what earlier calls already used; None once a cap was hit before.
+fn apply_caps(
+    text: &str,
+    max_lines: Option<usize>,
+    max_bytes: Option<usize>,
+    lines_used: &AtomicUsize,
+    bytes_used: &AtomicUsize,
+) -> Option<String> {
+    let text = match max_lines {
+        Some(max) => {
+            let lines = text.lines().count().max(1);
+            let used = lines_used.fetch_add(lines, Ordering::Relaxed);
+            if used >= max {
+                // Already capped by an earlier call
+                return None;
+            }
+            cap_lines_at(text, max - used, max)
+        }
+        None => text.to_string(),
+    };
+
+    match max_bytes {
+        Some(max) => {
+            let used = bytes_used.fetch_add(text.len(), Ordering::Relaxed);
+            if used >= max {
+                return None;
+            }
+            Some(cap_bytes_at(&text, max - used))
+        }
+        None => Some(text),
+    }
+}
+
+/// Test hook: collect what [`emit`] would print on this thread, under
+/// explicit caps instead of the env vars and process-wide counters.
+#[cfg(test)]
+pub mod capture {
+    use std::cell::RefCell;
+    use std::sync::atomic::AtomicUsize;
+
+    struct Sink {
+        max_lines: Option<usize>,
+        max_bytes: Option<usize>,
+        lines: AtomicUsize,
+        bytes: AtomicUsize,
+        out: String,
+    }
+
+    thread_local! {
+        static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+    }
+
+    /// Run `f` and return everything it emitted, one `println!` per call.
+    pub fn emitted(max_lines: Option<usize>, max_bytes: Option<usize>, f: impl FnOnce()) -> String {
+        SINK.with(|sink| {
+            *sink.borrow_mut() = Some(Sink {
+                max_lines,
+                max_bytes,
+                lines: AtomicUsize::new(0),
+                bytes: AtomicUsize::new(0),
+                out: String::new(),
+            })
+        });
+        f();
+        SINK.with(|sink| sink.borrow_mut().take().map(|s| s.out).unwrap_or_default())
+    }
+
+    /// Record `text` (through the caps when `capped`) if a capture is
+    /// active on this thread.
+    pub(super) fn push(text: &str, capped: bool) -> bool {
+        SINK.with(|sink| {
+            let mut sink = sink.borrow_mut();
+            let Some(sink) = sink.as_mut() else {
+                return false;
+            };
+            let capped = if capped {
+                super::apply_caps(
+                    text,
+                    sink.max_lines,
+                    sink.max_bytes,
+                    &sink.lines,
+                    &sink.bytes,
+                )
+            } else {
+                Some(text.to_string())
+            };
+            if let Some(capped) = capped {
+                sink.out.push_str(&capped);
+                sink.out.push('\n');
+            }
+            true
+        })
     }
 }
 
@@ -80,6 +426,198 @@ pub fn execute_command(cmd: &str, args: &[&str]) -> Result<(String, String, i32)
     Ok((stdout, stderr, exit_code))
 }
 
+/// Run `cmd` with no filtering at all (`rtk --raw ...`).
+///
+/// stdout/stderr are forwarded byte-for-byte (the global output cap does not
+/// apply) and the run is tracked with zero savings under `display`
+/// (e.g. "git diff --stat"). Exits with the tool's code on failure.
+pub fn run_raw(cmd: &mut Command, display: &str) -> Result<()> {
+    let timer = tracking::TimedExecution::start();
+
+    let output = cmd
+        .output()
+        .with_context(|| format!("Failed to run {}", display))?;
+
+    std::io::stdout().write_all(&output.stdout)?;
+    std::io::stderr().write_all(&output.stderr)?;
+
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    timer.track(
+        display,
+        &format!("rtk {} (raw)", display),
+        &combined,
+        &combined,
+    );
+
+    if !output.status.success() {
+        std::process::exit(output.status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+/// A wrapped tool exited unsuccessfully.
+///
+/// `run` functions return this as `Err` instead of exiting themselves; `main`
+/// prints it and exits with `code`, so rtk's exit code is always the tool's.
+#[derive(Debug)]
+pub struct CommandError {
+    pub tool: String,
+    pub subcommand: String,
+    pub code: i32,
+    pub stderr_excerpt: String,
+}
+
+impl CommandError {
+    /// Build from the tool's raw error output (excerpted with [`stderr_excerpt`]).
+    pub fn new(tool: &str, subcommand: &str, code: Option<i32>, stderr: &str) -> Self {
+        Self::with_excerpt(tool, subcommand, code, stderr_excerpt(stderr))
+    }
+
+    /// Build with an already-compacted excerpt (empty when output was shown).
+    pub fn with_excerpt(tool: &str, subcommand: &str, code: Option<i32>, excerpt: String) -> Self {
+        Self {
+            tool: tool.to_string(),
+            subcommand: subcommand.trim().to_string(),
+            // Killed by a signal: no code, report generic failure
+            code: code.unwrap_or(1),
+            stderr_excerpt: excerpt,
+        }
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FAILED: {}", self.tool)?;
+        if !self.subcommand.is_empty() {
+            write!(f, " {}", self.subcommand)?;
+        }
+        if !self.stderr_excerpt.is_empty() {
+            write!(f, "\n{}", self.stderr_excerpt)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+/// Max lines kept by [`stderr_excerpt`]
+const EXCERPT_LINES: usize = 10;
+
+/// Compact a tool's error output: non-empty lines without `hint:` noise,
+/// capped at a few lines.
+pub fn stderr_excerpt(stderr: &str) -> String {
+    let lines: Vec<&str> = stderr
+        .lines()
+        .map(|l| l.trim_end())
+        .filter(|l| !l.trim().is_empty() && !l.trim_start().starts_with("hint:"))
+        .collect();
+    let mut result: Vec<String> = lines
+        .iter()
+        .take(EXCERPT_LINES)
+        .map(|l| l.to_string())
+        .collect();
+    if lines.len() > EXCERPT_LINES {
+        result.push(format!("... +{} more lines", lines.len() - EXCERPT_LINES));
+    }
+    result.join("\n")
+}
+
+/// Exit code for an error returned from a command: the wrapped tool's own
+/// code for [`CommandError`], 1 otherwise.
+pub fn exit_code(err: &anyhow::Error) -> i32 {
+    err.downcast_ref::<CommandError>()
+        .map(|e| e.code)
+        .unwrap_or(1)
+}
+
+/// Output collected by [`run_streaming`].
+#[derive(Debug)]
+pub struct StreamedOutput {
+    /// Every line received on stdout and stderr, in arrival order
+    pub raw: String,
+    /// Lines that passed the filter (already printed)
+    pub filtered: String,
+    pub status: ExitStatus,
+}
+
+/// Spawn `cmd` and filter its stdout/stderr line by line while it runs.
+///
+/// Lines accepted by `keep` are printed through [`emit`] as soon as they
+/// arrive, so long-running servers (`serve`, `dev`) show progress instead of
+/// staying silent until exit. Batch commands should keep using `output()`.
+///
+/// # Examples
+/// ```no_run
+/// use rtk::utils::run_streaming;
+/// use std::process::Command;
+/// let out = run_streaming(&mut Command::new("deno").arg("serve"), |l| !l.is_empty())?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn run_streaming<F>(cmd: &mut Command, mut keep: F) -> Result<StreamedOutput>
+where
+    F: FnMut(&str) -> bool,
+{
+    let mut child = cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to spawn command")?;
+
+    // One reader thread per pipe so neither can block the other
+    let (tx, rx) = mpsc::channel::<String>();
+    let readers: Vec<_> = [
+        child
+            .stdout
+            .take()
+            .map(|r| Box::new(r) as Box<dyn Read + Send>),
+        child
+            .stderr
+            .take()
+            .map(|r| Box::new(r) as Box<dyn Read + Send>),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|pipe| {
+        let tx = tx.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(pipe).lines().map_while(|l| l.ok()) {
+                if tx.send(line).is_err() {
+                    break;
+                }
+            }
+        })
+    })
+    .collect();
+    drop(tx);
+
+    let mut raw = String::new();
+    let mut filtered = String::new();
+    for line in rx {
+        raw.push_str(&line);
+        raw.push('\n');
+        if keep(&line) {
+            emit(&line);
+            filtered.push_str(&line);
+            filtered.push('\n');
+        }
+    }
+
+    for reader in readers {
+        let _ = reader.join();
+    }
+    let status = child.wait().context("Failed to wait for command")?;
+
+    Ok(StreamedOutput {
+        raw,
+        filtered,
+        status,
+    })
+}
+
 /// Formate un nombre de tokens avec suffixes K/M pour lisibilité.
 ///
 /// # Arguments
@@ -155,6 +693,42 @@ pub fn format_cpt(cpt: f64) -> String {
     format!("${:.2}/MTok", cpt_per_million)
 }
 
+/// Render values as a block sparkline, one glyph per value.
+///
+/// Values are bucketed linearly between the series min and max. A flat series
+/// (including a single value) renders at mid height, or at the floor when it is
+/// all zeros; an empty series renders as "".
+///
+/// # Examples
+/// ```
+/// use rtk::utils::sparkline;
+/// assert_eq!(sparkline(&[0.0, 7.0]), "▁█");
+/// assert_eq!(sparkline(&[]), "");
+/// ```
+pub fn sparkline(values: &[f64]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    values
+        .iter()
+        .map(|&v| {
+            if range <= 0.0 || !range.is_finite() {
+                if max > 0.0 {
+                    BLOCKS[3]
+                } else {
+                    BLOCKS[0]
+                }
+            } else {
+                let idx = ((v - min) / range * (BLOCKS.len() - 1) as f64).round() as usize;
+                BLOCKS[idx.min(BLOCKS.len() - 1)]
+            }
+        })
+        .collect()
+}
+
 /// Format a confirmation message: "ok \<action\> \<detail\>"
 /// Used for write operations (merge, create, comment, edit, etc.)
 ///
@@ -172,6 +746,32 @@ pub fn ok_confirmation(action: &str, detail: &str) -> String {
     }
 }
 
+/// Split rtk's `--format json` / `--format=json` out of wrapper args.
+/// Any other `--format` value belongs to the wrapped tool (`git log --format=%h`).
+///
+/// # Examples
+/// ```
+/// use rtk::utils::extract_format_json;
+/// let args = vec!["--format".to_string(), "json".to_string(), "-v".to_string()];
+/// assert_eq!(extract_format_json(&args), (true, vec!["-v".to_string()]));
+/// ```
+pub fn extract_format_json(args: &[String]) -> (bool, Vec<String>) {
+    let mut json = false;
+    let mut rest = Vec::with_capacity(args.len());
+    let mut iter = args.iter().peekable();
+    while let Some(arg) = iter.next() {
+        if arg == "--format=json" {
+            json = true;
+        } else if arg == "--format" && iter.peek().is_some_and(|v| *v == "json") {
+            iter.next();
+            json = true;
+        } else {
+            rest.push(arg.clone());
+        }
+    }
+    (json, rest)
+}
+
 /// Detect the package manager used in the current directory.
 /// Returns "pnpm", "yarn", or "npm" based on lockfile presence.
 ///
@@ -255,6 +855,64 @@ mod tests {
         assert_eq!(truncate("hello world", 3), "...");
     }
 
+    #[test]
+    fn test_cap_lines_under_limit() {
+        assert_eq!(cap_lines("a\nb", 5), "a\nb");
+    }
+
+    #[test]
+    fn test_cap_lines_over_limit() {
+        let text = (0..20)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let result = cap_lines(&text, 3);
+        assert_eq!(result, "0\n1\n2\n... (rtk capped at 3 lines)");
+    }
+
+    #[test]
+    fn test_emit_caps_across_calls() {
+        let out = capture::emitted(Some(3), None, || {
+            emit("a\nb");
+            emit("c\nd");
+            emit("e");
+        });
+        // One marker, and nothing after the cap is reached
+        assert_eq!(out, "a\nb\nc\n... (rtk capped at 3 lines)\n");
+    }
+
+    #[test]
+    fn test_cap_bytes_boundary() {
+        let text = "aaaa\nbbbb\ncccc"; // 14 bytes
+        assert_eq!(cap_bytes_at(text, 14), text);
+        assert_eq!(
+            cap_bytes_at(text, 13),
+            "aaaa\nbbbb\n... [output truncated: 5 bytes hidden, re-run raw tool to see all]"
+        );
+        // A single oversized line is cut on a char boundary
+        assert_eq!(
+            cap_bytes_at("ééé", 3),
+            "é\n... [output truncated: 4 bytes hidden, re-run raw tool to see all]"
+        );
+    }
+
+    #[test]
+    fn test_out_file_gets_filtered_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.txt");
+        std::fs::write(&path, "stale\n").unwrap();
+
+        let mut bytes = write_out(&path, "ok ✓ main", false).unwrap();
+        bytes += write_out(&path, "  +3 -1", true).unwrap();
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(written, "ok ✓ main\n  +3 -1\n");
+        assert_eq!(bytes, written.len());
+        assert_eq!(
+            out_pointer(bytes, &path),
+            format!("wrote 20 bytes to {}", path.display())
+        );
+    }
+
     #[test]
     fn test_strip_ansi_simple() {
         let input = "\x1b[31mError\x1b[0m";
@@ -293,6 +951,57 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_sparkline_buckets() {
+        assert_eq!(
+            sparkline(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]),
+            "▁▂▃▄▅▆▇█"
+        );
+        assert_eq!(sparkline(&[10.0, 40.0, 25.0]), "▁█▅");
+    }
+
+    #[test]
+    fn test_sparkline_degenerate() {
+        assert_eq!(sparkline(&[]), "");
+        assert_eq!(sparkline(&[3.5]), "▄");
+        assert_eq!(sparkline(&[2.0, 2.0, 2.0]), "▄▄▄");
+        assert_eq!(sparkline(&[0.0, 0.0]), "▁▁");
+    }
+
+    #[test]
+    fn test_command_error_format_and_code() {
+        let err = CommandError::new(
+            "git",
+            "push",
+            Some(1),
+            "To github.com:o/r.git\n ! [rejected] main -> main (fetch first)\nhint: Updates were rejected\n",
+        );
+        assert_eq!(err.code, 1);
+        assert_eq!(
+            err.to_string(),
+            "FAILED: git push\nTo github.com:o/r.git\n ! [rejected] main -> main (fetch first)"
+        );
+
+        let err = CommandError::with_excerpt("deno", "test", Some(2), String::new());
+        assert_eq!(err.to_string(), "FAILED: deno test");
+        assert_eq!(exit_code(&anyhow::Error::new(err)), 2);
+
+        // No code (signal) and non-tool errors both map to 1
+        assert_eq!(CommandError::new("nx", "", None, "").code, 1);
+        assert_eq!(exit_code(&anyhow::anyhow!("config error")), 1);
+    }
+
+    #[test]
+    fn test_stderr_excerpt_caps_lines() {
+        let stderr = (1..=15)
+            .map(|n| format!("line {}", n))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let excerpt = stderr_excerpt(&stderr);
+        assert_eq!(excerpt.lines().count(), 11);
+        assert!(excerpt.ends_with("... +5 more lines"));
+    }
+
     #[test]
     fn test_format_tokens_millions() {
         assert_eq!(format_tokens(1_234_567), "1.2M");
@@ -349,6 +1058,17 @@ mod tests {
         assert_eq!(ok_confirmation("commented", ""), "ok commented");
     }
 
+    #[test]
+    fn test_extract_format_json() {
+        let args: Vec<String> = vec!["--format".into(), "json".into(), "src".into()];
+        assert_eq!(extract_format_json(&args), (true, vec!["src".to_string()]));
+        let args: Vec<String> = vec!["-a".into(), "--format=json".into()];
+        assert_eq!(extract_format_json(&args), (true, vec!["-a".to_string()]));
+        // Tool formats pass through untouched
+        let args: Vec<String> = vec!["--format=%h %s".into(), "--format".into(), "oneline".into()];
+        assert_eq!(extract_format_json(&args), (false, args.clone()));
+    }
+
     #[test]
     fn test_format_cpt_normal() {
         assert_eq!(format_cpt(0.000003), "$3.00/MTok");
@@ -396,4 +1116,44 @@ mod tests {
         assert!(result.ends_with("..."));
     }
 
+    #[test]
+    fn test_run_streaming_filters_both_pipes() {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", "echo keep-out; echo drop; echo keep-err >&2; exit 3"]);
+        let out = run_streaming(&mut cmd, |l| l.starts_with("keep")).unwrap();
+
+        assert_eq!(out.raw.lines().count(), 3);
+        assert!(out.filtered.contains("keep-out"));
+        assert!(out.filtered.contains("keep-err"));
+        assert!(!out.filtered.contains("drop"));
+        assert_eq!(out.status.code(), Some(3));
+    }
+
+    #[test]
+    fn test_truncate_emoji_at_boundary() {
+        // 🎉 (4 bytes) sits exactly at the cut point: must not be split
+        let title = "Fix login 🎉 flow";
+        assert_eq!(truncate(title, 13), "Fix login ...");
+        assert_eq!(truncate(title, 14), "Fix login 🎉...");
+    }
+
+    #[test]
+    fn test_truncate_chinese_at_boundary() {
+        // Each ideograph is two columns wide
+        let title = "修复登录页面的错误";
+        assert_eq!(truncate(title, 9), "修复登...");
+        // Odd budget: a wide char that only half-fits is dropped
+        assert_eq!(truncate(title, 8), "修复...");
+        assert_eq!(truncate("ab修复", 6), "ab修复");
+        assert_eq!(truncate("ab修复x", 6), "ab...");
+    }
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("hello"), 5);
+        assert_eq!(display_width("你好"), 4);
+        assert_eq!(display_width("🎉 ok"), 4);
+        assert_eq!(display_width("e\u{0301}"), 1); // combining accent
+    }
 }
@@ -7,7 +7,7 @@ use crate::parser::{
     FormatMode, OutputParser, ParseResult, TestFailure, TestResult, TokenFormatter,
 };
 use crate::tracking;
-use crate::utils::{package_manager_exec, strip_ansi};
+use crate::utils::{emit, package_manager_exec, strip_ansi};
 
 /// Vitest JSON output structures (tool-specific format)
 #[derive(Debug, Deserialize)]
@@ -260,7 +260,7 @@ fn run_vitest(args: &[String], verbose: u8) -> Result<()> {
         }
     };
 
-    println!("{}", filtered);
+    emit(&filtered);
 
     timer.track("vitest run", "rtk vitest run", &combined, &filtered);
 
@@ -1,4 +1,5 @@
 use crate::tracking;
+use crate::utils::emit;
 use anyhow::{Context, Result};
 use std::process::Command;
 
@@ -38,12 +39,12 @@ pub fn run(url: &str, args: &[String], verbose: u8) -> Result<()> {
             filename,
             format_size(size)
         );
-        println!("{}", msg);
+        emit(&msg);
         timer.track(&format!("wget {}", url), "rtk wget", &raw_output, &msg);
     } else {
         let error = parse_error(&stderr, &stdout);
         let msg = format!("⬇️ {} FAILED: {}", compact_url(url), error);
-        println!("{}", msg);
+        emit(&msg);
         timer.track(&format!("wget {}", url), "rtk wget", &raw_output, &msg);
     }
 
@@ -105,7 +106,7 @@ pub fn run_stdout(url: &str, args: &[String], verbose: u8) -> Result<()> {
         let stderr = String::from_utf8_lossy(&output.stderr);
         let error = parse_error(&stderr, "");
         let msg = format!("⬇️ {} FAILED: {}", compact_url(url), error);
-        println!("{}", msg);
+        emit(&msg);
         timer.track(&format!("wget -O - {}", url), "rtk wget -o", &stderr, &msg);
     }
 